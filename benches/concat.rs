@@ -0,0 +1,43 @@
+//! Measures the time to render large queries, run with `cargo bench --bench concat`
+
+use sql_query_builder as sql;
+use std::time::{Duration, Instant};
+
+const ROWS: usize = 10_000;
+const SAMPLES: usize = 50;
+const ITERATIONS: usize = 100;
+
+fn main() {
+  let insert_values = (0..ROWS).fold(sql::Insert::new().insert_into("users (id, login)"), |insert, id| {
+    insert.values(&format!("({id}, 'user_{id}')"))
+  });
+  bench("insert with 10k values rows", || insert_values.as_string());
+
+  let rows = (0..ROWS)
+    .map(|id| format!("({id}, 'user_{id}')"))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let select = sql::Select::new()
+    .select("id, login")
+    .from(&format!("(values {rows}) as imported (id, login)"));
+  let insert_select = sql::Insert::new().insert_into("users (id, login)").select(select);
+  bench("insert select with 10k rows in the from clause", || {
+    insert_select.as_string()
+  });
+}
+
+/// Prints the best time of the samples, each sample is the mean of the iterations
+fn bench(name: &str, render: impl Fn() -> String) {
+  let best = (0..SAMPLES)
+    .map(|_| {
+      let start = Instant::now();
+      for _ in 0..ITERATIONS {
+        std::hint::black_box(render());
+      }
+      start.elapsed() / ITERATIONS as u32
+    })
+    .min()
+    .unwrap_or(Duration::ZERO);
+
+  println!("{name}: {best:?}");
+}
@@ -6,6 +6,7 @@ clear
 cargo test $test_names
 cargo test $test_names --features postgresql
 cargo test $test_names --features sqlite
+cargo test $test_names --features mssql
 
 # run only one test
 # cargo test --features sqlite --test name_of_the_test_file name_of_the_test -- --nocapture --color always
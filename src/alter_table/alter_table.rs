@@ -3,7 +3,7 @@ use crate::{
   concat::Concat,
   fmt,
   structure::{AlterTable, AlterTableAction, AlterTableActionItem, AlterTableOrderedAction},
-  utils::push_unique,
+  utils::{push_unique, terminate},
 };
 
 impl TransactionQuery for AlterTable {}
@@ -40,6 +40,38 @@ impl AlterTable {
     self
   }
 
+  /// Adds a column, the `column_def` is the name of the column followed by its type and constraints.
+  /// Same as calling the [add](AlterTable::add) method with the `column` keyword
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int not null")
+  ///   .add_column("email varchar(100)")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int not null, ADD COLUMN email varchar(100)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users
+  ///   ADD COLUMN age int not null,
+  ///   ADD COLUMN email varchar(100)
+  /// ```
+  pub fn add_column(self, column_def: &str) -> Self {
+    let column_def = column_def.trim();
+    if column_def.is_empty() {
+      return self;
+    }
+    self.add(&format!("COLUMN {column_def}"))
+  }
+
   /// Defines the name of the table to be altered, this method overrides the previous value
   ///
   /// ### Example
@@ -89,7 +121,12 @@ impl AlterTable {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Prints the current state of the [AlterTable] to the standard output in a more ease to read version.
@@ -120,7 +157,10 @@ impl AlterTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -150,6 +190,34 @@ impl AlterTable {
     self
   }
 
+  /// Drops a column, same as calling the [drop](AlterTable::drop) method with the `column` keyword
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN login";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN login
+  /// ```
+  pub fn drop_column(self, column_name: &str) -> Self {
+    let column_name = column_name.trim();
+    if column_name.is_empty() {
+      return self;
+    }
+    self.drop(&format!("COLUMN {column_name}"))
+  }
+
   /// Creates instance of the [AlterTable] command
   pub fn new() -> Self {
     Self::default()
@@ -159,7 +227,10 @@ impl AlterTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -215,6 +286,35 @@ impl AlterTable {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table_query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop("legacy_column")
+  ///   .raw_append("/* end of the alter table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP legacy_column /* end of the alter table command */";
+  /// # assert_eq!(alter_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP legacy_column /* end of the alter table command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified parameter.
   ///
   /// ### Example
@@ -241,6 +341,62 @@ impl AlterTable {
     self._raw_before.push((action, raw_sql.trim().to_string()));
     self
   }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](AlterTable::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table_query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop("legacy_column")
+  ///   .raw_prepend("/* alter table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* alter table command */ ALTER TABLE users DROP legacy_column";
+  /// # assert_eq!(alter_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* alter table command */ ALTER TABLE users DROP legacy_column
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add("COLUMN age int")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int;";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
 }
 
 #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
@@ -248,7 +404,8 @@ impl AlterTable {
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl AlterTable {
   /// Changes the column names or table constraints.
-  /// Multiples call of this method will build the SQL respecting the order of the calls
+  /// Multiples call of this method will build the SQL respecting the order of the calls.
+  /// This method replaces the previous [rename_column](AlterTable::rename_column) action
   ///
   /// ### Example
   ///
@@ -273,11 +430,54 @@ impl AlterTable {
   /// ```
   pub fn rename(mut self, rename_exp: &str) -> Self {
     let action = AlterTableActionItem(AlterTableOrderedAction::Rename, rename_exp.trim().to_string());
+    if action.1.is_empty() == false {
+      self.remove_rename_column();
+    }
     push_unique(&mut self._ordered_actions, action);
     self
   }
 
-  /// Changes the name of the table
+  /// Changes the name of a column, the `from_to` argument is the current name followed by `to` and the new name.
+  /// A statement accepts only one rename, so this method replaces the previous actions of the
+  /// [rename](AlterTable::rename), [rename_to](AlterTable::rename_to) and `rename_column` methods, and a later call
+  /// of one of these methods replaces the `rename column` action. The last call wins
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_column("address TO city")
+  ///   .to_string();
+  ///
+  /// # let expected = "ALTER TABLE users RENAME COLUMN address TO city";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users RENAME COLUMN address TO city
+  /// ```
+  pub fn rename_column(mut self, from_to: &str) -> Self {
+    let from_to = from_to.trim();
+    if from_to.is_empty() {
+      return self;
+    }
+    self._ordered_actions.retain(|AlterTableActionItem(action, _)| {
+      *action != AlterTableOrderedAction::Rename && *action != AlterTableOrderedAction::RenameColumn
+    });
+    self._rename_to.clear();
+    let action = AlterTableActionItem(AlterTableOrderedAction::RenameColumn, from_to.to_string());
+    self._ordered_actions.push(action);
+    self
+  }
+
+  /// Changes the name of the table. This method replaces the previous [rename_column](AlterTable::rename_column) action
   ///
   /// ### Example
   ///
@@ -302,7 +502,16 @@ impl AlterTable {
   /// ```
   pub fn rename_to(mut self, table_name: &str) -> Self {
     self._rename_to = table_name.trim().to_string();
+    if self._rename_to.is_empty() == false {
+      self.remove_rename_column();
+    }
+    self
+  }
+
+  fn remove_rename_column(&mut self) {
     self
+      ._ordered_actions
+      .retain(|AlterTableActionItem(action, _)| *action != AlterTableOrderedAction::RenameColumn);
   }
 }
 
@@ -337,6 +546,45 @@ impl AlterTable {
     push_unique(&mut self._ordered_actions, action);
     self
   }
+
+  /// Alters a column, the `column_spec` is the name of the column followed by the change.
+  /// Same as calling the [alter](AlterTable::alter) method with the `column` keyword
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(any(feature = "postgresql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column("created_at SET DEFAULT now()")
+  ///   .alter_column("login TYPE varchar(100)")
+  ///   .to_string();
+  ///
+  /// # let expected = "\
+  /// #   ALTER TABLE users \
+  /// #   ALTER COLUMN created_at SET DEFAULT now(), \
+  /// #   ALTER COLUMN login TYPE varchar(100)\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users
+  ///   ALTER COLUMN created_at SET DEFAULT now(),
+  ///   ALTER COLUMN login TYPE varchar(100)
+  /// ```
+  pub fn alter_column(self, column_spec: &str) -> Self {
+    let column_spec = column_spec.trim();
+    if column_spec.is_empty() {
+      return self;
+    }
+    self.alter(&format!("COLUMN {column_spec}"))
+  }
 }
 
 impl std::fmt::Display for AlterTable {
@@ -348,6 +596,10 @@ impl std::fmt::Display for AlterTable {
 impl std::fmt::Debug for AlterTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
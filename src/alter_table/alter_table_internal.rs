@@ -8,6 +8,7 @@ impl Concat for AlterTable {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
     query = self.concat_alter_table(query, &fmts);
 
@@ -17,6 +18,8 @@ impl Concat for AlterTable {
     }
     query = self.concat_ordered_actions(query, &fmts);
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -26,7 +29,7 @@ impl AlterTable {
     let fmt::Formatter { space, .. } = fmts;
     let sql = if self._alter_table.is_empty() == false {
       let table_name = &self._alter_table;
-      format!("ALTER TABLE{space}{table_name}{space}")
+      format!("{}{space}{table_name}{space}", fmts.kw("ALTER TABLE"))
     } else {
       "".to_string()
     };
@@ -57,12 +60,14 @@ impl AlterTable {
       .map(|item| {
         let AlterTableActionItem(action, content) = item;
         match action {
-          AlterTableOrderedAction::Add => format!("{lb}{indent}ADD {content}"),
-          AlterTableOrderedAction::Drop => format!("{lb}{indent}DROP {content}"),
+          AlterTableOrderedAction::Add => format!("{lb}{indent}{} {content}", fmts.kw("ADD")),
+          AlterTableOrderedAction::Drop => format!("{lb}{indent}{} {content}", fmts.kw("DROP")),
+          #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+          AlterTableOrderedAction::Rename => format!("{lb}{indent}{} {content}", fmts.kw("RENAME")),
           #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-          AlterTableOrderedAction::Rename => format!("{lb}{indent}RENAME {content}"),
+          AlterTableOrderedAction::RenameColumn => format!("{lb}{indent}{} {content}", fmts.kw("RENAME COLUMN")),
           #[cfg(any(feature = "postgresql"))]
-          AlterTableOrderedAction::Alter => format!("{lb}{indent}ALTER {content}"),
+          AlterTableOrderedAction::Alter => format!("{lb}{indent}{} {content}", fmts.kw("ALTER")),
         }
       })
       .collect::<Vec<_>>()
@@ -79,7 +84,7 @@ impl AlterTable {
       let table_name = &self._rename_to;
       let space_or_comma = if self._ordered_actions.is_empty() { space } else { comma };
 
-      format!("RENAME TO{space}{table_name}{space_or_comma}")
+      format!("{}{space}{table_name}{space_or_comma}", fmts.kw("RENAME TO"))
     } else {
       "".to_string()
     };
@@ -1,8 +1,72 @@
-use crate::concat::Concat;
+use crate::{
+  concat::Concat,
+  structure::ClauseItem,
+  utils::{push_unique, without_on_condition},
+};
+
+/// Maps a value to a row of the `values` clause, used by the [Insert::values_iter](crate::Insert::values_iter) method
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::IntoRow;
+///
+/// struct User {
+///   login: String,
+///   active: bool,
+/// }
+///
+/// impl IntoRow for User {
+///   fn into_row(&self) -> String {
+///     format!("({}, {})", sql::escape::quote_literal(&self.login), self.active)
+///   }
+/// }
+///
+/// let user = User { login: "foo".to_string(), active: true };
+///
+/// assert_eq!(user.into_row(), "('foo', true)");
+/// ```
+pub trait IntoRow {
+  /// Returns the row with the values between parentheses, like the argument of the [Insert::values](crate::Insert::values) method
+  #[allow(clippy::wrong_self_convention)]
+  fn into_row(&self) -> String;
+}
+
+impl<T: IntoRow> IntoRow for &T {
+  fn into_row(&self) -> String {
+    (*self).into_row()
+  }
+}
 
 /// Represents all commands that can be used in a transaction
 pub trait TransactionQuery: Concat {}
 
 /// Represents all commands that can be used inside the with method
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-pub trait WithQuery: Concat + Send + Sync {}
+pub trait WithQuery: Concat + Send + Sync {
+  /// Returns the values bound with the `bind` method, including the values of the nested builders,
+  /// in the order of the `?` placeholders
+  #[cfg(feature = "postgresql")]
+  fn bound_params(&self) -> Vec<crate::structure::ParamValue> {
+    vec![]
+  }
+}
+
+/// Adds the join clauses to the builders, the builder only exposes the list where the joins are stored
+pub(crate) trait Join {
+  fn join_items(&mut self) -> &mut Vec<ClauseItem>;
+
+  /// A cross join has no join condition, so an `on` condition is not added
+  fn push_cross_join(&mut self, table: &str) {
+    self.push_join("CROSS JOIN", without_on_condition(table.trim()));
+  }
+
+  fn push_join(&mut self, keyword: &str, table: &str) {
+    let table = table.trim();
+    if table.is_empty() == false {
+      let join = ClauseItem::default().keyword(keyword).expr(&format!(" {table}"));
+      push_unique(self.join_items(), join);
+    }
+  }
+}
@@ -0,0 +1,521 @@
+use crate::{
+  behavior::WithQuery,
+  concat::Concat,
+  fmt,
+  structure::{Combinator, CompoundSelect, CompoundSelectClause, Select, WithBody},
+  utils::{push_unique, remove_raw_of_clause, terminate},
+};
+use std::sync::Arc;
+
+impl CompoundSelect {
+  /// Gets the current state of the [CompoundSelect] and returns it as string
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [CompoundSelect] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Removes the clause passed as argument and the raw SQL queries added with [raw_before](CompoundSelect::raw_before)
+  /// and [raw_after](CompoundSelect::raw_after) bound to it, clearing a clause that was never defined has no effect.
+  /// Clearing the `Select` clause keeps the set operations and clearing the `Combinator` clause keeps the first query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let template = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .order_by("login")
+  ///   .limit("10");
+  ///
+  /// let query = template.clear(sql::CompoundSelectClause::Limit).as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins ORDER BY login";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins ORDER BY login
+  /// ```
+  pub fn clear(mut self, clause: CompoundSelectClause) -> Self {
+    match clause {
+      CompoundSelectClause::Combinator => self._combinators.clear(),
+      CompoundSelectClause::Limit => self._limit.clear(),
+      CompoundSelectClause::Offset => self._offset.clear(),
+      CompoundSelectClause::OrderBy => self._order_by.clear(),
+      CompoundSelectClause::Select => self._select = Select::new(),
+      CompoundSelectClause::With => self._with.clear(),
+    }
+    remove_raw_of_clause(&mut self._raw_before, &clause);
+    remove_raw_of_clause(&mut self._raw_after, &clause);
+    self
+  }
+
+  /// Prints the current state of the [CompoundSelect] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// The `except` set operation, combines the queries of the compound with the select passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .except(admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users EXCEPT SELECT login FROM admins";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users EXCEPT SELECT login FROM admins
+  /// ```
+  pub fn except(mut self, select: Select) -> Self {
+    self._combinators.push((Combinator::Except, select));
+    self
+  }
+
+  /// The `intersect` set operation, combines the queries of the compound with the select passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .intersect(admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users INTERSECT SELECT login FROM admins";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users INTERSECT SELECT login FROM admins
+  /// ```
+  pub fn intersect(mut self, select: Select) -> Self {
+    self._combinators.push((Combinator::Intersect, select));
+    self
+  }
+
+  /// The `limit` clause applied to the whole compound. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .limit("10")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins LIMIT 10";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins LIMIT 10
+  /// ```
+  pub fn limit(mut self, num: &str) -> Self {
+    self._limit = num.trim().to_string();
+    self
+  }
+
+  /// Creates instance of the CompoundSelect builder, the select passed as argument is the first query of the compound
+  pub fn new(select: Select) -> Self {
+    Self {
+      _select: select,
+      ..Default::default()
+    }
+  }
+
+  /// The `offset` clause applied to the whole compound. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .offset("20")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins OFFSET 20";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins OFFSET 20
+  /// ```
+  pub fn offset(mut self, num: &str) -> Self {
+    self._offset = num.trim().to_string();
+    self
+  }
+
+  /// The `order by` clause applied to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .order_by("login desc")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins ORDER BY login desc";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins ORDER BY login desc
+  /// ```
+  pub fn order_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._order_by, column.trim().to_string());
+    self
+  }
+
+  /// Prints the current state of the [CompoundSelect] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause, the `Combinator` clause is the block of all set operations
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .raw_after(sql::CompoundSelectClause::Combinator, "/* end of the set operations */")
+  ///   .order_by("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT login FROM users \
+  /// #   UNION \
+  /// #   SELECT login FROM admins \
+  /// #   /* end of the set operations */ \
+  /// #   ORDER BY login\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users
+  /// UNION
+  /// SELECT login FROM admins
+  /// /* end of the set operations */
+  /// ORDER BY login
+  /// ```
+  pub fn raw_after(mut self, clause: CompoundSelectClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause, the `Select` clause is the first query of the compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .raw_before(sql::CompoundSelectClause::Select, "/* all logins */")
+  ///   .union(admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "/* all logins */ SELECT login FROM users UNION SELECT login FROM admins";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* all logins */ SELECT login FROM users UNION SELECT login FROM admins
+  /// ```
+  pub fn raw_before(mut self, clause: CompoundSelectClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins;";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// The `union` set operation, combines the queries of the compound with the select passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union(admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION SELECT login FROM admins";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION SELECT login FROM admins
+  /// ```
+  pub fn union(mut self, select: Select) -> Self {
+    self._combinators.push((Combinator::Union, select));
+    self
+  }
+
+  /// The `union all` set operation, combines the queries of the compound with the select passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Select::new().select("login").from("users");
+  /// let admins = sql::Select::new().select("login").from("admins");
+  ///
+  /// let query = sql::CompoundSelect::new(users)
+  ///   .union_all(admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users UNION ALL SELECT login FROM admins";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login FROM users UNION ALL SELECT login FROM admins
+  /// ```
+  pub fn union_all(mut self, select: Select) -> Self {
+    self._combinators.push((Combinator::UnionAll, select));
+    self
+  }
+
+  /// The `with` clause applied to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new().select("login").from("users").where_clause("active = true");
+  /// let recent = sql::Select::new().select("login").from("active_users").where_clause("created_at > $1");
+  /// let admins = sql::Select::new().select("login").from("active_users").where_clause("admin = true");
+  ///
+  /// let query = sql::CompoundSelect::new(recent)
+  ///   .union(admins)
+  ///   .with("active_users", active_users)
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   WITH active_users AS (SELECT login FROM users WHERE active = true) \
+  /// #   SELECT login FROM active_users WHERE created_at > $1 \
+  /// #   UNION \
+  /// #   SELECT login FROM active_users WHERE admin = true\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH active_users AS (SELECT login FROM users WHERE active = true)
+  /// SELECT login FROM active_users WHERE created_at > $1
+  /// UNION
+  /// SELECT login FROM active_users WHERE admin = true
+  /// ```
+  pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(Arc::new(query))));
+    self
+  }
+
+  /// The `with` clause with the query written as SQL, the query is wrapped in `name AS (...)` in the original
+  /// order with the queries added by the `with` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CompoundSelect::new(sql::Select::new().select("login").from("active_users"))
+  ///   .with_raw("active_users", "select login from users where active = true")
+  ///   .union(sql::Select::new().select("login").from("guests"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WITH active_users AS (select login from users where active = true) SELECT login FROM active_users UNION SELECT login FROM guests";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH active_users AS (select login from users where active = true) SELECT login FROM active_users UNION SELECT login FROM guests
+  /// ```
+  pub fn with_raw(mut self, name: &str, sql: &str) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Raw(sql.trim().to_string())));
+    self
+  }
+}
+
+impl WithQuery for CompoundSelect {
+  #[cfg(feature = "postgresql")]
+  fn bound_params(&self) -> Vec<crate::structure::ParamValue> {
+    let nested_with = self._with.iter().flat_map(|(_, query)| query.bound_params());
+    let nested_selects = std::iter::once(&self._select)
+      .chain(self._combinators.iter().map(|(_, select)| select))
+      .flat_map(|select| select.bound_params());
+
+    nested_with.chain(nested_selects).collect()
+  }
+}
+
+impl From<Select> for CompoundSelect {
+  fn from(select: Select) -> Self {
+    Self::new(select)
+  }
+}
+
+impl std::fmt::Display for CompoundSelect {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CompoundSelect {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
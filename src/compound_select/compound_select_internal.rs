@@ -0,0 +1,176 @@
+use crate::{
+  concat::{concat_raw_before_after, non_standard::with_queries, Concat},
+  fmt,
+  structure::{Combinator, CompoundSelect, CompoundSelectClause, Select},
+};
+
+impl Concat for CompoundSelect {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_string();
+
+    query = self.concat_with(query, &fmts);
+    query = self.concat_select(query, &fmts);
+    query = self.concat_combinators(query, &fmts);
+    query = self.concat_order_by(query, &fmts);
+    query = self.concat_limit(query, &fmts);
+    query = self.concat_offset(query, &fmts);
+
+    query.trim_end().to_string()
+  }
+}
+
+impl CompoundSelect {
+  fn concat_combinators(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = self
+      ._combinators
+      .iter()
+      .map(|(combinator, select)| {
+        let operator = fmts.kw(match combinator {
+          Combinator::Except => "EXCEPT",
+          Combinator::Intersect => "INTERSECT",
+          Combinator::Union => "UNION",
+          Combinator::UnionAll => "UNION ALL",
+        });
+        let select = branch(select, select.concat(fmts));
+        format!("{operator}{space}{lb}{select}{space}{lb}")
+      })
+      .collect::<String>();
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::Combinator,
+      sql,
+    )
+  }
+
+  fn concat_limit(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._limit.is_empty() == false {
+      format!("{}{space}{}{space}{lb}", fmts.kw("LIMIT"), self._limit)
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::Limit,
+      sql,
+    )
+  }
+
+  fn concat_offset(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._offset.is_empty() == false {
+      format!("{}{space}{}{space}{lb}", fmts.kw("OFFSET"), self._offset)
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::Offset,
+      sql,
+    )
+  }
+
+  fn concat_order_by(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let columns = self
+      ._order_by
+      .iter()
+      .filter(|column| column.is_empty() == false)
+      .map(|column| column.as_str())
+      .collect::<Vec<_>>()
+      .join(comma);
+    let sql = if columns.is_empty() == false {
+      format!("{}{space}{columns}{space}{lb}", fmts.kw("ORDER BY"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::OrderBy,
+      sql,
+    )
+  }
+
+  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let select = self._select.concat(fmts);
+    let sql = if select.is_empty() {
+      "".to_string()
+    } else if self._combinators.is_empty() {
+      format!("{select}{space}{lb}")
+    } else {
+      format!("{}{space}{lb}", branch(&self._select, select))
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::Select,
+      sql,
+    )
+  }
+
+  fn concat_with(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._with.is_empty() == false {
+      let with = with_queries(fmts, &self._with, |_| "".to_string());
+      format!("{}{space}{lb}{with}{space}{lb}", fmts.kw("WITH"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CompoundSelectClause::With,
+      sql,
+    )
+  }
+}
+
+/// Wraps the branch in parentheses when the select has clauses that would otherwise be applied to the whole
+/// compound, like its own `order by` and `limit`, or when the raw SQL makes it impossible to know
+fn branch(select: &Select, query: String) -> String {
+  let has_own_tail = select._order_by.is_empty() == false
+    || select._limit.is_empty() == false
+    || select._offset.is_empty() == false
+    || select._offset_rows.is_empty() == false
+    || select._fetch.is_empty() == false
+    || select._with.is_empty() == false;
+  let has_own_combinators = select._except.is_empty() == false
+    || select._intersect.is_empty() == false
+    || select._union.is_empty() == false
+    || select._union_all.is_empty() == false;
+  let has_raw_sql =
+    select._raw.is_empty() == false || select._raw_before.is_empty() == false || select._raw_after.is_empty() == false;
+
+  #[cfg(feature = "postgresql")]
+  let has_own_tail = has_own_tail || select._for.is_empty() == false;
+
+  if has_own_tail || has_own_combinators || has_raw_sql {
+    format!("({query})")
+  } else {
+    query
+  }
+}
@@ -0,0 +1,2 @@
+mod compound_select;
+mod compound_select_internal;
@@ -1,4 +1,7 @@
-use crate::fmt;
+use crate::{
+  fmt,
+  structure::{ClauseItem, ClauseItemPart, Explain},
+};
 
 pub(crate) mod non_standard;
 pub(crate) mod sql_standard;
@@ -7,14 +10,91 @@ pub(crate) mod sqlite;
 pub trait Concat {
   fn concat(&self, fmts: &fmt::Formatter) -> String;
 
-  fn concat_raw(&self, query: String, fmts: &fmt::Formatter, items: &Vec<String>) -> String {
+  /// Appends the query to the end of the buffer, the content already in the buffer is kept as is. Used to render the
+  /// nested builders straight into the query of the outer builder instead of an intermediate string
+  fn concat_into(&self, fmts: &fmt::Formatter, out: &mut String) {
+    out.push_str(&self.concat(fmts));
+  }
+
+  fn concat_raw(&self, mut query: String, fmts: &fmt::Formatter, items: &Vec<String>) -> String {
     if items.is_empty() {
       return query;
     }
     let fmt::Formatter { lb, space, .. } = fmts;
-    let raw_sql = items.join(space).trim().to_string();
+    let raw_sql = items.join(space);
+
+    query.push_str(raw_sql.trim());
+    query.push_str(space);
+    query.push_str(lb);
+    query
+  }
+}
+
+/// Renders the `explain` prefix of the query
+pub(crate) fn concat_explain(mut query: String, fmts: &fmt::Formatter, explain: &Explain) -> String {
+  let fmt::Formatter { lb, space, .. } = fmts;
+  let sql = match explain {
+    Explain::None => return query,
+    Explain::Plain => fmts.kw("EXPLAIN"),
+    Explain::Analyze => fmts.kw("EXPLAIN ANALYZE"),
+    #[cfg(feature = "postgresql")]
+    Explain::Options(options) => format!("{}{space}({options})", fmts.kw("EXPLAIN")),
+  };
+
+  query.push_str(&sql);
+  query.push_str(space);
+  query.push_str(lb);
+  query
+}
 
-    format!("{query}{raw_sql}{space}{lb}")
+impl ClauseItem {
+  /// Appends the expression as is, the parts are concatenated without separator
+  pub(crate) fn expr(mut self, expr: &str) -> Self {
+    self.0.push(ClauseItemPart::Expr(expr.to_string()));
+    self
+  }
+
+  /// Appends the keyword rendered with the keyword case of the formatter
+  pub(crate) fn keyword(mut self, keyword: &str) -> Self {
+    self.0.push(ClauseItemPart::Keyword(keyword.to_string()));
+    self
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.0.iter().all(|part| match part {
+      ClauseItemPart::Expr(text) | ClauseItemPart::Keyword(text) => text.is_empty(),
+    })
+  }
+}
+
+impl Concat for ClauseItem {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    self
+      .0
+      .iter()
+      .map(|part| match part {
+        ClauseItemPart::Expr(expr) => expr.to_string(),
+        ClauseItemPart::Keyword(keyword) => fmts.kw(keyword),
+      })
+      .collect()
+  }
+}
+
+impl PartialEq for ClauseItem {
+  fn eq(&self, other: &Self) -> bool {
+    self.to_string() == other.to_string()
+  }
+}
+
+/// The item as it was added, the keywords are kept in upper case
+impl std::fmt::Display for ClauseItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    for part in &self.0 {
+      match part {
+        ClauseItemPart::Expr(text) | ClauseItemPart::Keyword(text) => f.write_str(text)?,
+      }
+    }
+    Ok(())
   }
 }
 
@@ -25,14 +105,38 @@ pub(crate) fn concat_raw_before_after<Clause: PartialEq>(
   fmts: &fmt::Formatter,
   clause: Clause,
   sql: String,
+) -> String {
+  push_raw_before_after(items_before, items_after, query, fmts, clause, |query| {
+    query.push_str(&sql)
+  })
+}
+
+/// Same as [concat_raw_before_after] but the clause is written directly into the query buffer,
+/// used by the clauses that can be large enough to make the copy noticeable
+pub(crate) fn push_raw_before_after<Clause: PartialEq>(
+  items_before: &Vec<(Clause, String)>,
+  items_after: &Vec<(Clause, String)>,
+  mut query: String,
+  fmts: &fmt::Formatter,
+  clause: Clause,
+  push_sql: impl FnOnce(&mut String),
 ) -> String {
   let fmt::Formatter { space, .. } = fmts;
-  let raw_before = raw_queries(items_before, &clause).join(space).trim().to_string();
-  let raw_after = raw_queries(items_after, &clause).join(space).trim().to_string();
-  let space_after = if raw_after.is_empty() == false { space } else { "" };
-  let space_before = if raw_before.is_empty() == false { space } else { "" };
+  let raw_before = raw_queries(items_before, &clause).join(space);
+  let raw_after = raw_queries(items_after, &clause).join(space);
+  let raw_before = raw_before.trim();
+  let raw_after = raw_after.trim();
 
-  format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}")
+  query.push_str(raw_before);
+  if raw_before.is_empty() == false {
+    query.push_str(space);
+  }
+  push_sql(&mut query);
+  query.push_str(raw_after);
+  if raw_after.is_empty() == false {
+    query.push_str(space);
+  }
+  query
 }
 
 pub(crate) fn raw_queries<Clause: PartialEq>(raw_list: &Vec<(Clause, String)>, clause: &Clause) -> Vec<String> {
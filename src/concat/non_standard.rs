@@ -1,5 +1,9 @@
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::{behavior::WithQuery, concat::concat_raw_before_after, fmt};
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{ClauseItem, WithBody},
+};
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 pub(crate) trait ConcatLimit<Clause: PartialEq> {
@@ -14,7 +18,7 @@ pub(crate) trait ConcatLimit<Clause: PartialEq> {
   ) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if limit.is_empty() == false {
-      format!("LIMIT{space}{limit}{space}{lb}")
+      format!("{}{space}{limit}{space}{lb}", fmts.kw("LIMIT"))
     } else {
       "".to_string()
     };
@@ -32,17 +36,17 @@ pub(crate) trait ConcatReturning<Clause: PartialEq> {
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &Vec<ClauseItem>,
   ) -> String {
     let fmt::Formatter { lb, space, comma, .. } = fmts;
     let sql = if items.is_empty() == false {
       let output_names = items
         .iter()
         .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
+        .map(|item| item.concat(fmts))
         .collect::<Vec<_>>()
         .join(comma);
-      format!("RETURNING{space}{output_names}{space}{lb}")
+      format!("{}{space}{output_names}{space}{lb}", fmts.kw("RETURNING"))
     } else {
       "".to_string()
     };
@@ -53,6 +57,9 @@ pub(crate) trait ConcatReturning<Clause: PartialEq> {
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 pub(crate) trait ConcatWith<Clause: PartialEq> {
+  /// Returns true when any query of the `with` clause was added as recursive
+  fn is_recursive(&self) -> bool;
+
   fn concat_with(
     &self,
     items_raw_before: &Vec<(Clause, String)>,
@@ -60,37 +67,13 @@ pub(crate) trait ConcatWith<Clause: PartialEq> {
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(String, std::sync::Arc<dyn WithQuery>)>,
+    items: &Vec<(String, WithBody)>,
   ) -> String {
-    let fmt::Formatter {
-      comma,
-      lb,
-      indent,
-      space,
-      ..
-    } = fmts;
+    let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if items.is_empty() == false {
-      let with = items.iter().fold("".to_string(), |acc, item| {
-        let (name, query) = item;
-        let inner_lb = format!("{lb}{indent}");
-        let inner_fmts = fmt::Formatter {
-          comma,
-          lb: inner_lb.as_str(),
-          indent,
-          space,
-          ..*fmts
-        };
-        let query_string = query.concat(&inner_fmts);
-
-        if query_string.is_empty() == false {
-          format!("{acc}{name}{space}AS{space}({lb}{indent}{query_string}{lb}){comma}{lb}")
-        } else {
-          acc
-        }
-      });
-      let with = &with[..with.len() - comma.len() - lb.len()];
-
-      format!("WITH{space}{lb}{with}{space}{lb}")
+      let with = with_queries(fmts, items, |_| "".to_string());
+      let keyword = fmts.kw(if self.is_recursive() { "WITH RECURSIVE" } else { "WITH" });
+      format!("{keyword}{space}{lb}{with}{space}{lb}")
     } else {
       "".to_string()
     };
@@ -98,3 +81,50 @@ pub(crate) trait ConcatWith<Clause: PartialEq> {
     concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
   }
 }
+
+/// Renders the list of queries of the `with` clause, the `after_body` function receives the name of the query
+/// and returns the SQL placed after its body
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+pub(crate) fn with_queries(
+  fmts: &fmt::Formatter,
+  items: &Vec<(String, WithBody)>,
+  after_body: impl Fn(&str) -> String,
+) -> String {
+  let fmt::Formatter {
+    comma,
+    lb,
+    indent,
+    space,
+    ..
+  } = fmts;
+  let line_comma = fmts.line_comma();
+  let with = items.iter().fold("".to_string(), |acc, item| {
+    let (name, query) = item;
+    let inner_lb = format!("{lb}{indent}");
+    let inner_fmts = fmt::Formatter {
+      comma,
+      lb: inner_lb.as_str(),
+      indent,
+      space,
+      ..*fmts
+    };
+    let query_string = query.concat(&inner_fmts);
+
+    if query_string.is_empty() == false {
+      let after_body = after_body(name);
+      let after_body = if after_body.is_empty() {
+        "".to_string()
+      } else {
+        format!("{space}{after_body}")
+      };
+      format!(
+        "{acc}{name}{space}{}{space}({lb}{indent}{query_string}{lb}){after_body}{line_comma}",
+        fmts.kw("AS")
+      )
+    } else {
+      acc
+    }
+  });
+
+  with[..with.len() - line_comma.len()].to_string()
+}
@@ -1,4 +1,8 @@
-use crate::{concat::concat_raw_before_after, fmt, structure::LogicalOperator};
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{ClauseItem, LogicalOperator},
+};
 
 pub(crate) trait ConcatFrom<Clause: PartialEq> {
   fn concat_from(
@@ -18,7 +22,7 @@ pub(crate) trait ConcatFrom<Clause: PartialEq> {
         .map(|item| item.as_str())
         .collect::<Vec<_>>()
         .join(comma);
-      format!("FROM{space}{tables}{space}{lb}")
+      format!("{}{space}{tables}{space}{lb}", fmts.kw("FROM"))
     } else {
       "".to_string()
     };
@@ -35,11 +39,15 @@ pub(crate) trait ConcatJoin<Clause: PartialEq> {
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &Vec<ClauseItem>,
   ) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if items.is_empty() == false {
-      let joins = items.join(format!("{space}{lb}").as_str());
+      let joins = items
+        .iter()
+        .map(|join| join.concat(fmts))
+        .collect::<Vec<_>>()
+        .join(format!("{space}{lb}").as_str());
       format!("{joins}{space}{lb}")
     } else {
       "".to_string()
@@ -57,18 +65,18 @@ pub(crate) trait ConcatOrderBy<Clause: PartialEq> {
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &Vec<ClauseItem>,
   ) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if items.is_empty() == false {
       let columns = items
         .iter()
         .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
+        .map(|item| item.concat(fmts))
         .collect::<Vec<_>>()
         .join(comma);
 
-      format!("ORDER BY{space}{columns}{space}{lb}")
+      format!("{}{space}{columns}{space}{lb}", fmts.kw("ORDER BY"))
     } else {
       "".to_string()
     };
@@ -85,21 +93,24 @@ pub(crate) trait ConcatWhere<Clause: PartialEq> {
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(LogicalOperator, String)>,
+    items: &Vec<(LogicalOperator, ClauseItem)>,
   ) -> String {
     let fmt::Formatter { lb, space, indent, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let filtered_items = items
-        .iter()
-        .filter(|item| item.1.is_empty() == false)
-        .collect::<Vec<_>>();
-      let ((_, cond), tail) = filtered_items.split_first().unwrap();
-      let first_condition = format!("{indent}{cond}");
+    let filtered_items = items
+      .iter()
+      .filter(|item| item.1.is_empty() == false)
+      .collect::<Vec<_>>();
+    let sql = if let Some(((_, cond), tail)) = filtered_items.split_first() {
+      let first_condition = format!("{indent}{}", cond.concat(fmts));
       let conditions = tail.iter().fold(first_condition, |acc, (log_op, condition)| {
-        format!("{acc}{space}{lb}{indent}{log_op}{space}{condition}")
+        let log_op = fmts.kw(&log_op.to_string());
+        format!("{acc}{space}{lb}{indent}{log_op}{space}{}", condition.concat(fmts))
       });
 
-      format!("WHERE{lb}{space}{conditions}{space}{lb}")
+      // the conditions are placed in the next line aligned by the indent, the space only separates them
+      // from the keyword when the query is rendered in one line
+      let separator = if lb.is_empty() { space } else { lb };
+      format!("{}{separator}{conditions}{space}{lb}", fmts.kw("WHERE"))
     } else {
       "".to_string()
     };
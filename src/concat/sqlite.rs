@@ -1,11 +1,15 @@
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 use crate::{
   concat::concat_raw_before_after,
   fmt,
-  structure::{InsertClause, InsertVars, UpdateClause, UpdateVars},
+  structure::{InsertClause, InsertTarget, InsertVars},
 };
 
 #[cfg(feature = "sqlite")]
+use crate::structure::{UpdateClause, UpdateVars};
+
+/// Renders the insert clause of the SQLite and MySQL variants, like `insert or` and `replace into`
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 pub(crate) trait ConcatInsert {
   fn concat_insert(
     &self,
@@ -13,21 +17,37 @@ pub(crate) trait ConcatInsert {
     items_raw_after: &Vec<(InsertClause, String)>,
     query: String,
     fmts: &fmt::Formatter,
-    insert: &(InsertVars, String),
+    insert: &(InsertVars, InsertTarget),
   ) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
 
     let (clause, sql) = match insert {
       (InsertVars::InsertInto, exp) if exp.is_empty() => (InsertClause::InsertInto, "".to_string()),
-      (InsertVars::InsertInto, exp) => (InsertClause::InsertInto, format!("INSERT INTO{space}{exp}{space}{lb}")),
+      (InsertVars::InsertInto, exp) => (
+        InsertClause::InsertInto,
+        format!("{}{space}{exp}{space}{lb}", fmts.kw("INSERT INTO")),
+      ),
 
+      #[cfg(feature = "sqlite")]
       (InsertVars::InsertOr, exp) if exp.is_empty() => (InsertClause::InsertOr, "".to_string()),
-      (InsertVars::InsertOr, exp) => (InsertClause::InsertOr, format!("INSERT OR{space}{exp}{space}{lb}")),
+      #[cfg(feature = "sqlite")]
+      (InsertVars::InsertOr, exp) => (
+        InsertClause::InsertOr,
+        format!("{}{space}{exp}{space}{lb}", fmts.kw("INSERT OR")),
+      ),
 
       (InsertVars::ReplaceInto, exp) if exp.is_empty() => (InsertClause::ReplaceInto, "".to_string()),
       (InsertVars::ReplaceInto, exp) => (
         InsertClause::ReplaceInto,
-        format!("REPLACE INTO{space}{exp}{space}{lb}"),
+        format!("{}{space}{exp}{space}{lb}", fmts.kw("REPLACE INTO")),
+      ),
+
+      #[cfg(feature = "mysql")]
+      (InsertVars::InsertIgnore, exp) if exp.is_empty() => (InsertClause::InsertIgnore, "".to_string()),
+      #[cfg(feature = "mysql")]
+      (InsertVars::InsertIgnore, exp) => (
+        InsertClause::InsertIgnore,
+        format!("{}{space}{exp}{space}{lb}", fmts.kw("INSERT IGNORE INTO")),
       ),
     };
 
@@ -48,12 +68,15 @@ pub(crate) trait ConcatUpdate {
     let fmt::Formatter { lb, space, .. } = fmts;
     let (clause, sql) = match update {
       (UpdateVars::Update, table_name) if table_name.is_empty() => (UpdateClause::Update, "".to_string()),
-      (UpdateVars::Update, table_name) => (UpdateClause::Update, format!("UPDATE{space}{table_name}{space}{lb}")),
+      (UpdateVars::Update, table_name) => (
+        UpdateClause::Update,
+        format!("{}{space}{table_name}{space}{lb}", fmts.kw("UPDATE")),
+      ),
 
       (UpdateVars::UpdateOr, expression) if expression.is_empty() => (UpdateClause::UpdateOr, "".to_string()),
       (UpdateVars::UpdateOr, expression) => (
         UpdateClause::UpdateOr,
-        format!("UPDATE OR{space}{expression}{space}{lb}"),
+        format!("{}{space}{expression}{space}{lb}", fmts.kw("UPDATE OR")),
       ),
     };
 
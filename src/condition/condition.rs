@@ -0,0 +1,148 @@
+use crate::{
+  concat::Concat,
+  fmt,
+  structure::{Condition, ConditionItem, LogicalOperator},
+};
+
+impl Condition {
+  /// Adds a condition joined with the `and` operator, the first condition of the group don't render the operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let condition = sql::Condition::new()
+  ///   .and("active = true")
+  ///   .and("login = $1")
+  ///   .as_string();
+  ///
+  /// # let expected = "active = true AND login = $1";
+  /// # assert_eq!(condition, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// active = true AND login = $1
+  /// ```
+  pub fn and(mut self, condition: &str) -> Self {
+    self.push(LogicalOperator::And, condition);
+    self
+  }
+
+  /// Adds a parenthesized group of conditions built by the closure joined with the `and` operator,
+  /// the groups can be nested and an empty group is ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let condition = sql::Condition::new()
+  ///   .and("active = true")
+  ///   .and_group(|c| c.or("login = $1").or_group(|c| c.and("email = $1").and("verified = true")))
+  ///   .as_string();
+  ///
+  /// # let expected = "active = true AND (login = $1 OR (email = $1 AND verified = true))";
+  /// # assert_eq!(condition, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// active = true AND (login = $1 OR (email = $1 AND verified = true))
+  /// ```
+  pub fn and_group(mut self, f: impl FnOnce(Self) -> Self) -> Self {
+    self.push_group(LogicalOperator::And, f);
+    self
+  }
+
+  /// Gets the current state of the [Condition] and returns it as string
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// Creates instance of the Condition builder
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a condition joined with the `or` operator, the first condition of the group don't render the operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let condition = sql::Condition::new()
+  ///   .or("login = $1")
+  ///   .or("email = $1")
+  ///   .as_string();
+  ///
+  /// # let expected = "login = $1 OR email = $1";
+  /// # assert_eq!(condition, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// login = $1 OR email = $1
+  /// ```
+  pub fn or(mut self, condition: &str) -> Self {
+    self.push(LogicalOperator::Or, condition);
+    self
+  }
+
+  /// Adds a parenthesized group of conditions built by the closure joined with the `or` operator,
+  /// the groups can be nested and an empty group is ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let condition = sql::Condition::new()
+  ///   .or("admin = true")
+  ///   .or_group(|c| c.and("active = true").and("login = $1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "admin = true OR (active = true AND login = $1)";
+  /// # assert_eq!(condition, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// admin = true OR (active = true AND login = $1)
+  /// ```
+  pub fn or_group(mut self, f: impl FnOnce(Self) -> Self) -> Self {
+    self.push_group(LogicalOperator::Or, f);
+    self
+  }
+
+  fn push(&mut self, operator: LogicalOperator, condition: &str) {
+    let condition = condition.trim();
+    if condition.is_empty() == false {
+      self
+        ._conditions
+        .push((operator, ConditionItem::Condition(condition.to_string())));
+    }
+  }
+
+  fn push_group(&mut self, operator: LogicalOperator, f: impl FnOnce(Self) -> Self) {
+    let group = f(Self::new());
+    if group.is_empty() == false {
+      self._conditions.push((operator, ConditionItem::Group(group)));
+    }
+  }
+}
+
+impl std::fmt::Display for Condition {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Condition {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
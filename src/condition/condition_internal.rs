@@ -0,0 +1,30 @@
+use crate::{
+  concat::Concat,
+  fmt,
+  structure::{Condition, ConditionItem},
+};
+
+impl Concat for Condition {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { space, .. } = fmts;
+
+    let item = |item: &ConditionItem| match item {
+      ConditionItem::Condition(condition) => condition.to_string(),
+      ConditionItem::Group(group) => format!("({})", group.concat(fmts)),
+    };
+
+    match self._conditions.split_first() {
+      Some(((_, first_condition), tail)) => tail.iter().fold(item(first_condition), |acc, (log_op, condition)| {
+        let log_op = fmts.kw(&log_op.to_string());
+        format!("{acc}{space}{log_op}{space}{}", item(condition))
+      }),
+      None => "".to_string(),
+    }
+  }
+}
+
+impl Condition {
+  pub(crate) fn is_empty(&self) -> bool {
+    self._conditions.is_empty()
+  }
+}
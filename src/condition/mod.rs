@@ -0,0 +1,2 @@
+mod condition;
+mod condition_internal;
@@ -0,0 +1,37 @@
+use crate::{fmt, structure::ConflictTarget};
+
+impl ConflictTarget {
+  /// Creates a conflict target with the columns of an unique index
+  pub fn columns(columns: &[&str]) -> Self {
+    let columns = columns
+      .iter()
+      .map(|column| column.trim())
+      .filter(|column| column.is_empty() == false)
+      .map(|column| column.to_string())
+      .collect();
+    Self::Columns(columns)
+  }
+
+  /// Creates a conflict target with the name of an unique constraint
+  pub fn constraint(name: &str) -> Self {
+    Self::Constraint(name.trim().to_string())
+  }
+
+  /// A target without columns or without the name of the constraint, rendered as an empty string
+  pub(crate) fn is_empty(&self) -> bool {
+    match self {
+      Self::Columns(columns) => columns.is_empty(),
+      Self::Constraint(name) => name.is_empty(),
+    }
+  }
+
+  pub(crate) fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, space, .. } = fmts;
+
+    match self {
+      _ if self.is_empty() => "".to_string(),
+      Self::Columns(columns) => format!("({}){space}", columns.join(comma)),
+      Self::Constraint(name) => format!("{}{space}{name}{space}", fmts.kw("ON CONSTRAINT")),
+    }
+  }
+}
@@ -2,8 +2,8 @@ use crate::{
   behavior::TransactionQuery,
   concat::Concat,
   fmt,
-  structure::{CreateIndex, CreateIndexParams, LogicalOperator},
-  utils::push_unique,
+  structure::{ClauseItem, CreateIndex, CreateIndexParams, LogicalOperator},
+  utils::{push_unique, terminate},
 };
 
 impl TransactionQuery for CreateIndex {}
@@ -33,7 +33,12 @@ impl CreateIndex {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [CreateIndex] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Defines the column of the table used to create the index
@@ -158,7 +163,40 @@ impl CreateIndex {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds the `if not exists` modifier to the index defined by the [create_index](CreateIndex::create_index) method
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .if_not_exists()
+  ///   .on("users")
+  ///   .column("name")
+  ///   .to_string();
+  ///
+  /// # let expected = "CREATE INDEX IF NOT EXISTS users_name_idx ON users (name)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// CREATE INDEX IF NOT EXISTS users_name_idx ON users (name)
+  /// ```
+  pub fn if_not_exists(mut self) -> Self {
+    self._if_not_exists = true;
     self
   }
 
@@ -199,7 +237,10 @@ impl CreateIndex {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -271,6 +312,39 @@ impl CreateIndex {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index_query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .raw_append("/* end of the create index command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_name_idx ON users (name) /* end of the create index command */";
+  /// # assert_eq!(create_index_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx ON users (name) /* end of the create index command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified parameter.
   ///
   /// The `CreateIndexParams::CreateIndex` works both to `.create_index` and `.create_index_if_not_exists` methods.
@@ -311,6 +385,70 @@ impl CreateIndex {
     self
   }
 
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](CreateIndex::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index_query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .raw_prepend("/* create index command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create index command */ CREATE INDEX users_name_idx ON users (name)";
+  /// # assert_eq!(create_index_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* create index command */ CREATE INDEX users_name_idx ON users (name)
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_name_idx ON users (name);";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx ON users (name);
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
   /// Defines the `unique` parameter
   ///
   /// ### Example
@@ -407,7 +545,10 @@ impl CreateIndex {
   /// WHERE status = 'active'
   /// ```
   pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::And, ClauseItem::default().expr(condition.trim())),
+    );
     self
   }
 
@@ -449,7 +590,10 @@ impl CreateIndex {
   ///   OR status = 'active'
   /// ```
   pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::Or, ClauseItem::default().expr(condition.trim())),
+    );
     self
   }
 }
@@ -579,6 +723,10 @@ impl std::fmt::Display for CreateIndex {
 impl std::fmt::Debug for CreateIndex {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
@@ -10,6 +10,7 @@ impl Concat for CreateIndex {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
 
     #[cfg(feature = "postgresql")]
@@ -41,6 +42,8 @@ impl Concat for CreateIndex {
       &self._where,
     );
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -90,14 +93,14 @@ impl CreateIndex {
         "".to_string(),
         fmts,
         CreateIndexParams::Unique,
-        format!("UNIQUE{space}"),
+        format!("{}{space}", fmts.kw("UNIQUE")),
       )
     } else {
       "".to_string()
     };
 
     let if_not_exists = if self._if_not_exists {
-      format!("IF NOT EXISTS{space}")
+      format!("{}{space}", fmts.kw("IF NOT EXISTS"))
     } else {
       "".to_string()
     };
@@ -115,7 +118,7 @@ impl CreateIndex {
         "".to_string(),
         fmts,
         CreateIndexParams::Concurrently,
-        format!("CONCURRENTLY{space}"),
+        format!("{}{space}", fmts.kw("CONCURRENTLY")),
       )
     } else {
       "".to_string()
@@ -127,7 +130,11 @@ impl CreateIndex {
     let sql = if modifiers_not_called || if_not_exists_without_index_name {
       "".to_string()
     } else {
-      format!("CREATE{space}{unique}INDEX{space}{concurrently}{if_not_exists}{index_name}{lb}")
+      format!(
+        "{}{space}{unique}{}{space}{concurrently}{if_not_exists}{index_name}{lb}",
+        fmts.kw("CREATE"),
+        fmts.kw("INDEX")
+      )
     };
 
     concat_raw_before_after(
@@ -153,7 +160,7 @@ impl CreateIndex {
         .join(comma);
 
       if column_names.is_empty() == false {
-        format!("INCLUDE{space}({column_names}){space}{lb}")
+        format!("{}{space}({column_names}){space}{lb}", fmts.kw("INCLUDE"))
       } else {
         "".to_string()
       }
@@ -184,13 +191,13 @@ impl CreateIndex {
           "".to_string(),
           fmts,
           CreateIndexParams::Only,
-          format!("ONLY{space}"),
+          format!("{}{space}", fmts.kw("ONLY")),
         )
       } else {
         "".to_string()
       };
 
-      format!("ON{space}{only}{table_name}{space}")
+      format!("{}{space}{only}{table_name}{space}", fmts.kw("ON"))
     } else {
       "".to_string()
     };
@@ -210,7 +217,7 @@ impl CreateIndex {
 
     let sql = if self._using.is_empty() == false {
       let index_method = &self._using;
-      format!("USING{space}{index_method}{space}")
+      format!("{}{space}{index_method}{space}", fmts.kw("USING"))
     } else {
       "".to_string()
     };
@@ -238,14 +245,14 @@ impl CreateIndex {
         "".to_string(),
         fmts,
         CreateIndexParams::Unique,
-        format!("UNIQUE{space}"),
+        format!("{}{space}", fmts.kw("UNIQUE")),
       )
     } else {
       "".to_string()
     };
 
     let if_not_exists = if self._if_not_exists {
-      format!("IF NOT EXISTS{space}")
+      format!("{}{space}", fmts.kw("IF NOT EXISTS"))
     } else {
       "".to_string()
     };
@@ -257,7 +264,11 @@ impl CreateIndex {
     };
 
     let sql = if index_name.is_empty() == false {
-      format!("CREATE{space}{unique}INDEX{space}{if_not_exists}{index_name}{lb}")
+      format!(
+        "{}{space}{unique}{}{space}{if_not_exists}{index_name}{lb}",
+        fmts.kw("CREATE"),
+        fmts.kw("INDEX")
+      )
     } else {
       "".to_string()
     };
@@ -278,7 +289,7 @@ impl CreateIndex {
     let sql = if self._on.is_empty() == false {
       let table_name = &self._on;
 
-      format!("ON{space}{table_name}{space}")
+      format!("{}{space}{table_name}{space}", fmts.kw("ON"))
     } else {
       "".to_string()
     };
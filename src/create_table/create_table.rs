@@ -3,7 +3,7 @@ use crate::{
   concat::Concat,
   fmt,
   structure::{CreateTable, CreateTableParams},
-  utils::push_unique,
+  utils::{push_unique, terminate},
 };
 
 impl TransactionQuery for CreateTable {}
@@ -37,7 +37,12 @@ impl CreateTable {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [CreateTable] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Defines a column to be passed as arguments to the create table command, multiples call will concatenates all column parameters
@@ -179,7 +184,10 @@ impl CreateTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -214,6 +222,31 @@ impl CreateTable {
     self
   }
 
+  /// Adds the `if not exists` modifier to the table defined by the [create_table](CreateTable::create_table) method
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .if_not_exists()
+  ///   .column("id serial primary key");
+  ///
+  /// # let expected = "CREATE TABLE IF NOT EXISTS users (id serial primary key)";
+  /// # assert_eq!(expected, create_table.to_string());
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// CREATE TABLE IF NOT EXISTS users (id serial primary key)
+  /// ```
+  pub fn if_not_exists(mut self) -> Self {
+    self._if_not_exists = true;
+    self
+  }
+
   /// Creates instance of the [CreateTable] command
   pub fn new() -> Self {
     Self::default()
@@ -254,7 +287,10 @@ impl CreateTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -316,6 +352,35 @@ impl CreateTable {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table_query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .raw_append("/* end of the create table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial primary key) /* end of the create table command */";
+  /// # assert_eq!(create_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial primary key) /* end of the create table command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified parameter.
   ///
   /// ### Example
@@ -342,6 +407,62 @@ impl CreateTable {
     self._raw_before.push((param, raw_sql.trim().to_string()));
     self
   }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](CreateTable::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table_query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .raw_prepend("/* create table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create table command */ CREATE TABLE users (id serial primary key)";
+  /// # assert_eq!(create_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* create table command */ CREATE TABLE users (id serial primary key)
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial primary key);";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial primary key);
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
 }
 
 impl std::fmt::Display for CreateTable {
@@ -353,6 +474,10 @@ impl std::fmt::Display for CreateTable {
 impl std::fmt::Debug for CreateTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
@@ -8,10 +8,13 @@ impl Concat for CreateTable {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
     query = self.concat_create_table(query, &fmts);
     query = self.concat_parameters(query, &fmts);
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -21,7 +24,12 @@ impl CreateTable {
     let fmt::Formatter { space, .. } = fmts;
     let sql = if self._create_table.is_empty() == false {
       let table_name = &self._create_table;
-      format!("CREATE TABLE{space}{table_name}{space}")
+      let if_not_exists = if self._if_not_exists && table_name.starts_with("IF NOT EXISTS ") == false {
+        format!("{}{space}", fmts.kw("IF NOT EXISTS"))
+      } else {
+        "".to_string()
+      };
+      format!("{}{space}{if_not_exists}{table_name}{space}", fmts.kw("CREATE TABLE"))
     } else {
       "".to_string()
     };
@@ -72,7 +80,7 @@ impl CreateTable {
       ._constraint
       .iter()
       .filter(|constraint| constraint.is_empty() == false)
-      .map(|constraint| format!("{lb}{indent}CONSTRAINT{space}{constraint}"))
+      .map(|constraint| format!("{lb}{indent}{}{space}{constraint}", fmts.kw("CONSTRAINT")))
       .collect::<Vec<_>>()
       .join(comma);
 
@@ -93,7 +101,7 @@ impl CreateTable {
       ._foreign_key
       .iter()
       .filter(|foreign_key| foreign_key.is_empty() == false)
-      .map(|foreign_key| format!("{lb}{indent}FOREIGN KEY{foreign_key}"))
+      .map(|foreign_key| format!("{lb}{indent}{}{foreign_key}", fmts.kw("FOREIGN KEY")))
       .collect::<Vec<_>>()
       .join(comma);
 
@@ -109,8 +117,8 @@ impl CreateTable {
 
   fn concat_primary_key(&self, fmts: &fmt::Formatter) -> String {
     let primary_key = match &self._primary_key {
-      pk_exp if pk_exp.find('(').is_some() => format!("PRIMARY KEY{pk_exp}"),
-      pk_exp if pk_exp.is_empty() == false => format!("PRIMARY KEY({pk_exp})"),
+      pk_exp if pk_exp.find('(').is_some() => format!("{}{pk_exp}", fmts.kw("PRIMARY KEY")),
+      pk_exp if pk_exp.is_empty() == false => format!("{}({pk_exp})", fmts.kw("PRIMARY KEY")),
       _ => "".to_string(),
     };
 
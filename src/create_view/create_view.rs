@@ -0,0 +1,357 @@
+use crate::{
+  behavior::TransactionQuery,
+  concat::Concat,
+  fmt,
+  structure::{CreateView, CreateViewParams, Select},
+  utils::{push_unique, terminate},
+};
+
+impl TransactionQuery for CreateView {}
+
+impl CreateView {
+  /// The `as` clause, defines the query of the view. The nested [Select] is rendered with the same formatter
+  /// of the [CreateView]. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .as_select(select)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins AS SELECT login FROM users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins AS SELECT login FROM users
+  /// ```
+  pub fn as_select(mut self, select: Select) -> Self {
+    self._as_select = Some(select);
+    self
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Defines the name of the view, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("users_view")
+  ///   .create_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins
+  /// ```
+  pub fn create_view(mut self, view_name: &str) -> Self {
+    self._create_view = view_name.trim().to_string();
+    self
+  }
+
+  /// Prints the current state of the [CreateView] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new().select("login").from("users");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .as_select(select)
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// CREATE VIEW user_logins
+  /// AS
+  /// SELECT login
+  /// FROM users
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Creates instance of the [CreateView] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [CreateView] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex create view command.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .raw("/* create view command */")
+  ///   .create_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create view command */ CREATE VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* create view command */ CREATE VIEW user_logins
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .raw_after(sql::CreateViewParams::CreateView, "(login)")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins (login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins (login)
+  /// ```
+  pub fn raw_after(mut self, param: CreateViewParams, raw_sql: &str) -> Self {
+    self._raw_after.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .raw_append("/* end of the create view command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins /* end of the create view command */";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins /* end of the create view command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new().select("login").from("users");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .raw_before(sql::CreateViewParams::AsSelect, "(login)")
+  ///   .as_select(select)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW user_logins (login) AS SELECT login FROM users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW user_logins (login) AS SELECT login FROM users
+  /// ```
+  pub fn raw_before(mut self, param: CreateViewParams, raw_sql: &str) -> Self {
+    self._raw_before.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](CreateView::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .raw_prepend("/* create view command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create view command */ CREATE VIEW user_logins";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* create view command */ CREATE VIEW user_logins
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("login").from("users"))
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users AS SELECT login FROM users;";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT login FROM users;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql", feature = "mysql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+impl CreateView {
+  /// Adds the `or replace` modifier, the view is replaced when it already exists
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .or_replace()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE OR REPLACE VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE OR REPLACE VIEW user_logins
+  /// ```
+  pub fn or_replace(mut self) -> Self {
+    self._or_replace = true;
+    self
+  }
+}
+
+impl std::fmt::Display for CreateView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
@@ -0,0 +1,74 @@
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{CreateView, CreateViewParams},
+};
+
+impl Concat for CreateView {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_string();
+
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_create_view(query, &fmts);
+    query = self.concat_as_select(query, &fmts);
+
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.trim_end().to_string()
+  }
+}
+
+impl CreateView {
+  fn concat_as_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if let Some(select) = &self._as_select {
+      let select_string = if fmts.paren_subqueries {
+        format!("({})", select.concat(fmts))
+      } else {
+        select.concat(fmts)
+      };
+      format!("{}{space}{lb}{select_string}{space}{lb}", fmts.kw("AS"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewParams::AsSelect,
+      sql,
+    )
+  }
+
+  fn concat_create_view(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    let sql = if self._create_view.is_empty() == false {
+      #[cfg(any(feature = "postgresql", feature = "mysql"))]
+      let create_view = if self._or_replace {
+        fmts.kw("CREATE OR REPLACE VIEW")
+      } else {
+        fmts.kw("CREATE VIEW")
+      };
+      #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
+      let create_view = fmts.kw("CREATE VIEW");
+
+      let view_name = &self._create_view;
+      format!("{create_view}{space}{view_name}{space}{lb}")
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewParams::CreateView,
+      sql,
+    )
+  }
+}
@@ -2,8 +2,8 @@ use crate::{
   behavior::TransactionQuery,
   concat::Concat,
   fmt,
-  structure::{Delete, DeleteClause, LogicalOperator},
-  utils::push_unique,
+  structure::{BuilderError, ClauseItem, Delete, DeleteClause, Explain, LogicalOperator},
+  utils::{block_comment, in_condition, push_unique, remove_raw_of_clause, terminate},
 };
 
 impl TransactionQuery for Delete {}
@@ -31,7 +31,189 @@ impl Delete {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = $1")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = $1;";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string, returns an error when a required clause is missing,
+  /// a [Delete] must define the `delete_from` clause. A [Delete] without the `where` clause returns the
+  /// [BuilderError::UnsafeDelete] error unless the [allow_full_table](Delete::allow_full_table) method was called.
+  /// The builders used with the `raw` method are not verified
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let result = sql::Delete::new()
+  ///   .where_clause("id = $1")
+  ///   .try_as_string();
+  ///
+  /// assert_eq!(result, Err(sql::BuilderError::MissingClause("delete_from")));
+  ///
+  /// let result = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .try_as_string();
+  ///
+  /// assert_eq!(result, Err(sql::BuilderError::UnsafeDelete));
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuilderError> {
+    if let Some(clause) = self.missing_clause() {
+      return Err(BuilderError::MissingClause(clause));
+    }
+    if self.is_unsafe() {
+      return Err(BuilderError::UnsafeDelete);
+    }
+    Ok(self.as_string())
+  }
+
+  /// Allows the [Delete] without the `where` clause to be rendered by the [try_as_string](Delete::try_as_string)
+  /// method, the [as_string](Delete::as_string) method always renders the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let result = sql::Delete::new()
+  ///   .delete_from("sessions")
+  ///   .allow_full_table()
+  ///   .try_as_string();
+  ///
+  /// assert_eq!(result, Ok("DELETE FROM sessions".to_string()));
+  /// ```
+  pub fn allow_full_table(mut self) -> Self {
+    self._allow_full_table = true;
+    self
+  }
+
+  /// Removes the clause passed as argument and the raw SQL queries added with [raw_before](Delete::raw_before)
+  /// and [raw_after](Delete::raw_after) bound to it, clearing a clause that was never defined has no effect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let template = sql::Delete::new()
+  ///   .delete_from("sessions")
+  ///   .where_clause("expired = true");
+  ///
+  /// let delete = template.clear(sql::DeleteClause::Where);
+  ///
+  /// # let expected = "DELETE FROM sessions";
+  /// # assert_eq!(delete.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM sessions
+  /// ```
+  pub fn clear(mut self, clause: DeleteClause) -> Self {
+    match clause {
+      DeleteClause::DeleteFrom => self._delete_from.clear(),
+      DeleteClause::Where => self._where.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      DeleteClause::Returning => self._returning.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      DeleteClause::With => {
+        self._with.clear();
+        self._with_recursive = false;
+      }
+      #[cfg(feature = "postgresql")]
+      DeleteClause::Join => self._join.clear(),
+      #[cfg(feature = "postgresql")]
+      DeleteClause::Using => self._using.clear(),
+    }
+    remove_raw_of_clause(&mut self._raw_before, &clause);
+    remove_raw_of_clause(&mut self._raw_after, &clause);
+    self
+  }
+
+  /// Adds a block comment at the start of the query, useful to trace the code that generated the query
+  /// in the database logs. The comment is rendered in a single line and a `*/` in the text can't close it early
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .comment("sessions#cleanup")
+  ///   .delete_from("sessions")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* sessions#cleanup */ DELETE FROM sessions";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* sessions#cleanup */ DELETE FROM sessions
+  /// ```
+  pub fn comment(mut self, text: &str) -> Self {
+    if text.trim().is_empty() == false {
+      push_unique(&mut self._comment, block_comment(text));
+    }
+    self
+  }
+
+  /// Adds a block comment before the clause passed as argument, the comment is escaped like the
+  /// [comment](Self::comment) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("sessions")
+  ///   .where_clause("expired = true")
+  ///   .comment_on(sql::DeleteClause::Where, "expired by the cron job")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM sessions /* expired by the cron job */ WHERE expired = true";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM sessions /* expired by the cron job */ WHERE expired = true
+  /// ```
+  pub fn comment_on(self, clause: DeleteClause, text: &str) -> Self {
+    if text.trim().is_empty() {
+      return self;
+    }
+    self.raw_before(clause, &block_comment(text))
   }
 
   /// Prints the current state of the [Delete] to the standard output in a more ease to read version.
@@ -59,7 +241,10 @@ impl Delete {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -87,6 +272,60 @@ impl Delete {
     self
   }
 
+  /// Prefixes the query with `EXPLAIN` to show its execution plan, the prefix is placed before the `with` clause.
+  /// This method overrides the previous value of the [explain_analyze](Delete::explain_analyze) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1")
+  ///   .explain()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN DELETE FROM users WHERE id = 1";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN DELETE FROM users WHERE id = 1
+  /// ```
+  pub fn explain(mut self) -> Self {
+    self._explain = Explain::Plain;
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN ANALYZE`, the query is executed to show its actual execution plan.
+  /// This method overrides the previous value of the [explain](Delete::explain) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1")
+  ///   .explain_analyze()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN ANALYZE DELETE FROM users WHERE id = 1";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN ANALYZE DELETE FROM users WHERE id = 1
+  /// ```
+  pub fn explain_analyze(mut self) -> Self {
+    self._explain = Explain::Analyze;
+    self
+  }
+
   /// Creates instance of the Delete command
   pub fn new() -> Self {
     Self::default()
@@ -96,11 +335,15 @@ impl Delete {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
-  /// Adds at the beginning a raw SQL query.
+  /// Adds at the beginning a raw SQL query, after the comment and the explain prefix.
+  /// Use the [raw_prepend](Delete::raw_prepend) method to add it at the very start
   ///
   /// # Example
   ///
@@ -154,6 +397,35 @@ impl Delete {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .raw_append("/* end of the delete command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = 'foo' /* end of the delete command */";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'foo' /* end of the delete command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified clause.
   ///
   /// # Example
@@ -181,6 +453,36 @@ impl Delete {
     self
   }
 
+  /// Adds a raw SQL query at the very start, before the comment and the explain prefix,
+  /// unlike the [raw](Delete::raw) method that is rendered after them.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .raw_prepend("/* delete command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* delete command */ DELETE FROM users WHERE login = 'foo'";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* delete command */ DELETE FROM users WHERE login = 'foo'
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
   /// The method will concatenate multiples calls using the `and` operator. This method is un alias of `where_clause`.
   ///
   /// # Example
@@ -233,7 +535,113 @@ impl Delete {
   ///   AND status = 'deactivated'
   /// ```
   pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::And, ClauseItem::default().expr(condition.trim())),
+    );
+    self
+  }
+
+  /// Same as the [where_clause](Delete::where_clause) method but receives an optional condition,
+  /// the builder is returned unchanged when the condition is `None` or empty
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login: Option<&str> = None;
+  /// let delete_query = sql::Delete::new()
+  ///   .where_clause("status = 'active'")
+  ///   .where_clause_opt(login)
+  ///   .where_clause_opt(Some("created_at >= $1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE status = 'active' AND created_at >= $1";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// WHERE
+  ///   status = 'active'
+  ///   AND created_at >= $1
+  /// ```
+  pub fn where_clause_opt(self, condition: Option<&str>) -> Self {
+    match condition {
+      Some(condition) if condition.trim().is_empty() == false => self.where_clause(condition),
+      _ => self,
+    }
+  }
+
+  /// The `where` clause with the condition `column IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always false `1 = 0` condition instead of the invalid `IN ()`.
+  /// This method is concatenated like the [where_clause](Delete::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_in("id", ["1", "2", "3"])
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id IN (1, 2, 3)";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// WHERE id IN (1, 2, 3)
+  /// ```
+  pub fn where_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, false);
+    if condition.is_empty() == false {
+      push_unique(&mut self._where, (LogicalOperator::And, condition));
+    }
+    self
+  }
+
+  /// The `where` clause with the condition `column NOT IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always true `1 = 1` condition instead of the invalid `NOT IN ()`.
+  /// This method is concatenated like the [where_clause](Delete::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let statuses = vec!["'banned'".to_string(), "'deleted'".to_string()];
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_not_in("status", &statuses)
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE status NOT IN ('banned', 'deleted')";
+  /// # assert_eq!(delete_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// WHERE status NOT IN ('banned', 'deleted')
+  /// ```
+  pub fn where_not_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, true);
+    if condition.is_empty() == false {
+      push_unique(&mut self._where, (LogicalOperator::And, condition));
+    }
     self
   }
 
@@ -261,13 +669,18 @@ impl Delete {
   ///   OR login = 'bar'
   /// ```
   pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::Or, ClauseItem::default().expr(condition.trim())),
+    );
     self
   }
 }
 
+#[cfg(feature = "postgresql")]
+use crate::behavior::Join;
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::behavior::WithQuery;
+use crate::{behavior::WithQuery, structure::WithBody, utils::push_returning};
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl WithQuery for Delete {}
@@ -276,6 +689,25 @@ impl WithQuery for Delete {}
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Delete {
+  /// Gets the output names defined in the `returning` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .returning("id")
+  ///   .returning("login");
+  ///
+  /// assert_eq!(delete.get_returning(), ["id", "login"]);
+  /// # }
+  /// ```
+  pub fn get_returning(&self) -> Vec<String> {
+    self._returning.iter().map(|item| item.to_string()).collect()
+  }
+
   /// The `returning` clause
   ///
   /// # Example
@@ -300,7 +732,76 @@ impl Delete {
   /// DELETE FROM users RETURNING id, login
   /// ```
   pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+    push_returning(&mut self._returning, ClauseItem::default().expr(output_name.trim()));
+    self
+  }
+
+  /// The `returning` clause with all the columns of the affected rows, the output names defined by the
+  /// [returning](Delete::returning) method are replaced by `*` and an output name defined after this method replaces the `*`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .returning("id")
+  ///   .returning("login")
+  ///   .returning_all()
+  ///   .as_string();
+  ///
+  /// # let expected = "RETURNING *";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// RETURNING *
+  /// ```
+  pub fn returning_all(mut self) -> Self {
+    push_returning(&mut self._returning, ClauseItem::default().expr("*"));
+    self
+  }
+
+  /// The `returning` clause with an expression and its alias, rendered as `expr AS alias`. An output name with the
+  /// same expression, like the one added by `returning("id")`, is replaced keeping its position
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .returning_as("id", "pk")
+  ///   .returning("login");
+  ///
+  /// # let expected = "DELETE FROM users RETURNING id AS pk, login";
+  /// # assert_eq!(delete.to_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users RETURNING id AS pk, login
+  /// ```
+  pub fn returning_as(mut self, expr: &str, alias: &str) -> Self {
+    let (expr, alias) = (expr.trim(), alias.trim());
+    if expr.is_empty() == false && alias.is_empty() == false {
+      let output_name = ClauseItem::default()
+        .expr(expr)
+        .expr(" ")
+        .keyword("AS")
+        .expr(&format!(" {alias}"));
+      push_returning(&mut self._returning, output_name);
+    } else {
+      push_returning(&mut self._returning, ClauseItem::default().expr(expr));
+    }
     self
   }
 
@@ -345,7 +846,294 @@ impl Delete {
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self
+  }
+
+  /// The `with` clause with the query written as SQL, the query is wrapped in `name AS (...)` in the original
+  /// order with the queries added by the `with` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Delete::new()
+  ///   .with_raw("inactive_users", "select id from users where active = false")
+  ///   .delete_from("orders")
+  ///   .where_clause("owner_id in (select id from inactive_users)")
+  ///   .as_string();
+  ///
+  /// # let expected = "WITH inactive_users AS (select id from users where active = false) DELETE FROM orders WHERE owner_id in (select id from inactive_users)";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH inactive_users AS (select id from users where active = false) DELETE FROM orders WHERE owner_id in (select id from inactive_users)
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_raw(mut self, name: &str, sql: &str) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Raw(sql.trim().to_string())));
+    self
+  }
+
+  /// The `with` clause marked as `recursive`, the query can reference itself by the `name`. When any query is
+  /// added by this method the whole `with` clause is rendered as `with recursive`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let base = sql::Select::new().select("1 as n");
+  /// let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+  ///
+  /// let query = sql::Delete::new()
+  ///   .with_recursive("t", sql::Select::recursive(base, step))
+  ///   .delete_from("numbers")
+  ///   .where_clause("n in (select n from t)")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   WITH RECURSIVE t AS (\
+  /// #     SELECT 1 as n \
+  /// #     UNION ALL \
+  /// #     SELECT n + 1 FROM t WHERE n < 10\
+  /// #   ) \
+  /// #   DELETE FROM numbers WHERE n in (select n from t)\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH RECURSIVE t AS (
+  ///   SELECT 1 as n
+  ///   UNION ALL
+  ///   SELECT n + 1 FROM t WHERE n < 10
+  /// )
+  /// DELETE FROM numbers WHERE n in (select n from t)
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_recursive(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self._with_recursive = true;
+    self
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl Delete {
+  /// The `cross join` clause, the join is placed in the `using` clause so it needs a table to join with, without a
+  /// table added with the [Delete::using] method the joined table is promoted to the `using` clause.
+  /// A cross join has no join condition so an `on` condition passed as argument is not rendered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .using("orders")
+  ///   .cross_join("products")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users USING orders CROSS JOIN products";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users USING orders CROSS JOIN products
+  /// ```
+  pub fn cross_join(mut self, table: &str) -> Self {
+    self.push_cross_join(table);
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN (options)`, the options are rendered as is.
+  /// This method overrides the previous value of the [explain](Delete::explain) and
+  /// [explain_analyze](Delete::explain_analyze) methods, an empty options is the same as the `explain` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1")
+  ///   .explain_with("FORMAT JSON, ANALYZE")
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN (FORMAT JSON, ANALYZE) DELETE FROM users WHERE id = 1";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN (FORMAT JSON, ANALYZE) DELETE FROM users WHERE id = 1
+  /// ```
+  pub fn explain_with(mut self, options: &str) -> Self {
+    let options = options.trim();
+    self._explain = if options.is_empty() {
+      Explain::Plain
+    } else {
+      Explain::Options(options.to_string())
+    };
+    self
+  }
+
+  /// The `inner join` clause, the join is placed in the `using` clause so it needs a table to join with, without a
+  /// table added with the [Delete::using] method the joined table is promoted to the `using` clause and its
+  /// `on` condition becomes the first condition of the `where` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .using("orders")
+  ///   .inner_join("products on products.id = orders.product_id")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users USING orders INNER JOIN products on products.id = orders.product_id";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users USING orders INNER JOIN products on products.id = orders.product_id
+  /// ```
+  pub fn inner_join(mut self, table: &str) -> Self {
+    self.push_join("INNER JOIN", table);
+    self
+  }
+
+  /// The `left join` clause, the join is placed in the `using` clause so it needs a table to join with, without a
+  /// table added with the [Delete::using] method the joins are not rendered since an outer join can't be promoted
+  /// to the `using` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .using("orders")
+  ///   .left_join("products on products.id = orders.product_id")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users USING orders LEFT JOIN products on products.id = orders.product_id";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users USING orders LEFT JOIN products on products.id = orders.product_id
+  /// ```
+  pub fn left_join(mut self, table: &str) -> Self {
+    self.push_join("LEFT JOIN", table);
+    self
+  }
+
+  /// The `right join` clause, the join is placed in the `using` clause so it needs a table to join with, without a
+  /// table added with the [Delete::using] method the joins are not rendered since an outer join can't be promoted
+  /// to the `using` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .using("orders")
+  ///   .right_join("products on products.id = orders.product_id")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users USING orders RIGHT JOIN products on products.id = orders.product_id";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users USING orders RIGHT JOIN products on products.id = orders.product_id
+  /// ```
+  pub fn right_join(mut self, table: &str) -> Self {
+    self.push_join("RIGHT JOIN", table);
+    self
+  }
+
+  /// The `using` clause, defines the tables that can be referenced in the `where` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("orders")
+  ///   .using("users")
+  ///   .where_clause("orders.owner_id = users.id")
+  ///   .where_clause("users.active = false")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   DELETE FROM orders \
+  /// #   USING users \
+  /// #   WHERE orders.owner_id = users.id AND users.active = false\
+  /// # ";
+  /// # assert_eq!(delete_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM orders
+  /// USING users
+  /// WHERE orders.owner_id = users.id AND users.active = false
+  /// ```
+  pub fn using(mut self, tables: &str) -> Self {
+    let tables = tables.trim();
+    if tables.is_empty() == false {
+      push_unique(&mut self._using, tables.to_string());
+    }
     self
   }
 }
@@ -359,6 +1147,27 @@ impl std::fmt::Display for Delete {
 impl std::fmt::Debug for Delete {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
+
+/// Two builders are equal when they render the same SQL with the [one_line](fmt::one_line) formatter,
+/// the order the methods were called is not compared
+impl PartialEq for Delete {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_string() == other.as_string()
+  }
+}
+
+impl Eq for Delete {}
+
+/// The hash of the rendered SQL, consistent with the [PartialEq] implementation
+impl std::hash::Hash for Delete {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.as_string().hash(state);
   }
 }
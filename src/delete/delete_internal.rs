@@ -1,5 +1,5 @@
 use crate::{
-  concat::{concat_raw_before_after, sql_standard::ConcatWhere, Concat},
+  concat::{concat_explain, concat_raw_before_after, sql_standard::ConcatWhere, Concat},
   fmt,
   structure::{Delete, DeleteClause},
 };
@@ -10,6 +10,9 @@ impl Concat for Delete {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._comment);
+    query = concat_explain(query, &fmts, &self._explain);
     query = self.concat_raw(query, &fmts, &self._raw);
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
@@ -23,13 +26,36 @@ impl Concat for Delete {
       );
     }
     query = self.concat_delete_from(query, &fmts);
+
+    #[cfg(feature = "postgresql")]
+    let promoted = promote_first_join(&self._using, &self._join, &self._where);
+    #[cfg(feature = "postgresql")]
+    let (using, join, conditions) = match &promoted {
+      Some((using, join, conditions)) => (using, join, conditions),
+      None => (&self._using, &self._join, &self._where),
+    };
+    #[cfg(not(feature = "postgresql"))]
+    let conditions = &self._where;
+
+    #[cfg(feature = "postgresql")]
+    {
+      query = self.concat_using(query, &fmts, using);
+      query = self.concat_join(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        &fmts,
+        DeleteClause::Join,
+        join,
+      );
+    }
     query = self.concat_where(
       &self._raw_before,
       &self._raw_after,
       query,
       &fmts,
       DeleteClause::Where,
-      &self._where,
+      conditions,
     );
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
@@ -43,16 +69,32 @@ impl Concat for Delete {
       );
     }
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
 
 impl Delete {
+  /// Returns true when the delete has no `where` condition and the full table delete was not allowed,
+  /// the builders used with the `raw` method are not verified
+  pub(crate) fn is_unsafe(&self) -> bool {
+    let has_where = self._where.iter().any(|(_, condition)| condition.is_empty() == false);
+    self._raw.is_empty() && has_where == false && self._allow_full_table == false
+  }
+
+  pub(crate) fn missing_clause(&self) -> Option<&'static str> {
+    if self._raw.is_empty() && self._delete_from.is_empty() {
+      return Some("delete_from");
+    }
+    None
+  }
+
   fn concat_delete_from(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._delete_from.is_empty() == false {
       let table_name = &self._delete_from;
-      format!("DELETE FROM{space}{table_name}{space}{lb}")
+      format!("{}{space}{table_name}{space}{lb}", fmts.kw("DELETE FROM"))
     } else {
       "".to_string()
     };
@@ -74,4 +116,43 @@ use crate::concat::non_standard::{ConcatReturning, ConcatWith};
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl ConcatReturning<DeleteClause> for Delete {}
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl ConcatWith<DeleteClause> for Delete {}
+impl ConcatWith<DeleteClause> for Delete {
+  fn is_recursive(&self) -> bool {
+    self._with_recursive
+  }
+}
+
+#[cfg(feature = "postgresql")]
+use crate::{behavior::Join, concat::sql_standard::ConcatJoin, structure::ClauseItem, utils::promote_first_join};
+
+#[cfg(feature = "postgresql")]
+impl ConcatJoin<DeleteClause> for Delete {}
+
+#[cfg(feature = "postgresql")]
+impl Join for Delete {
+  fn join_items(&mut self) -> &mut Vec<ClauseItem> {
+    &mut self._join
+  }
+}
+
+#[cfg(feature = "postgresql")]
+impl Delete {
+  fn concat_using(&self, query: String, fmts: &fmt::Formatter, using: &Vec<String>) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if using.is_empty() == false {
+      let tables = using.join(comma);
+      format!("{}{space}{tables}{space}{lb}", fmts.kw("USING"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DeleteClause::Using,
+      sql,
+    )
+  }
+}
@@ -3,7 +3,7 @@ use crate::{
   concat::Concat,
   fmt,
   structure::{DropIndex, DropIndexParams},
-  utils::push_unique,
+  utils::{push_unique, terminate},
 };
 
 impl TransactionQuery for DropIndex {}
@@ -30,7 +30,12 @@ impl DropIndex {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [DropIndex] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Defines a drop index parameter, this method overrides the previous value
@@ -160,7 +165,10 @@ impl DropIndex {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -173,7 +181,10 @@ impl DropIndex {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -229,6 +240,34 @@ impl DropIndex {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_index_query = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx")
+  ///   .raw_append("/* end of the drop index command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX users_name_idx /* end of the drop index command */";
+  /// # assert_eq!(drop_index_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_name_idx /* end of the drop index command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified parameter.
   ///
   /// The `DropIndexParams::DropIndex` works both to `.drop_index` and `.drop_index_if_exist` methods
@@ -257,6 +296,63 @@ impl DropIndex {
     self._raw_before.push((param, raw_sql.trim().to_string()));
     self
   }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](DropIndex::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_index_query = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx")
+  ///   .raw_prepend("/* drop index command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop index command */ DROP INDEX users_name_idx";
+  /// # assert_eq!(drop_index_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop index command */ DROP INDEX users_name_idx
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX users_name_idx;";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_name_idx;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
 }
 
 impl std::fmt::Display for DropIndex {
@@ -268,6 +364,10 @@ impl std::fmt::Display for DropIndex {
 impl std::fmt::Debug for DropIndex {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
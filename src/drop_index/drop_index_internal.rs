@@ -8,9 +8,12 @@ impl Concat for DropIndex {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
     query = self.concat_drop_index(query, &fmts);
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -21,7 +24,7 @@ impl DropIndex {
 
     let sql = if self._drop_index.is_empty() == false {
       let if_exists = if self._if_exists {
-        format!("IF EXISTS{space}")
+        format!("{}{space}", fmts.kw("IF EXISTS"))
       } else {
         "".to_string()
       };
@@ -38,7 +41,7 @@ impl DropIndex {
         self._drop_index.last().unwrap().to_string()
       };
 
-      format!("DROP INDEX{space}{if_exists}{index_names}{space}{lb}")
+      format!("{}{space}{if_exists}{index_names}{space}{lb}", fmts.kw("DROP INDEX"))
     } else {
       "".to_string()
     };
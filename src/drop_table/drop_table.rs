@@ -3,7 +3,7 @@ use crate::{
   concat::Concat,
   fmt,
   structure::{DropTable, DropTableParams},
-  utils::push_unique,
+  utils::{push_unique, terminate},
 };
 
 impl TransactionQuery for DropTable {}
@@ -30,7 +30,12 @@ impl DropTable {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [DropTable] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Defines a drop table parameter, this method overrides the previous value
@@ -160,7 +165,10 @@ impl DropTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -173,7 +181,10 @@ impl DropTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -229,6 +240,34 @@ impl DropTable {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table_query = sql::DropTable::new()
+  ///   .drop_table("users_temp")
+  ///   .raw_append("/* end of the drop table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE users_temp /* end of the drop table command */";
+  /// # assert_eq!(drop_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users_temp /* end of the drop table command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified parameter.
   ///
   /// The `DropTableParams::DropTable` works both to `.drop_table` and `.drop_table_if_exist` methods
@@ -257,6 +296,60 @@ impl DropTable {
     self._raw_before.push((param, raw_sql.trim().to_string()));
     self
   }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](DropTable::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table_query = sql::DropTable::new()
+  ///   .drop_table("users_temp")
+  ///   .raw_prepend("/* drop table command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop table command */ DROP TABLE users_temp";
+  /// # assert_eq!(drop_table_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop table command */ DROP TABLE users_temp
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE users;";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
 }
 
 impl std::fmt::Display for DropTable {
@@ -268,6 +361,10 @@ impl std::fmt::Display for DropTable {
 impl std::fmt::Debug for DropTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
@@ -8,9 +8,12 @@ impl Concat for DropTable {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
     query = self.concat_drop_table(query, &fmts);
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -21,7 +24,7 @@ impl DropTable {
 
     let sql = if self._drop_table.is_empty() == false {
       let if_exists = if self._if_exists {
-        format!("IF EXISTS{space}")
+        format!("{}{space}", fmts.kw("IF EXISTS"))
       } else {
         "".to_string()
       };
@@ -38,7 +41,7 @@ impl DropTable {
         self._drop_table.last().unwrap().to_string()
       };
 
-      format!("DROP TABLE{space}{if_exists}{table_names}{space}{lb}")
+      format!("{}{space}{if_exists}{table_names}{space}{lb}", fmts.kw("DROP TABLE"))
     } else {
       "".to_string()
     };
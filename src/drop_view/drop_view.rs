@@ -0,0 +1,368 @@
+use crate::{
+  behavior::TransactionQuery,
+  concat::Concat,
+  fmt,
+  structure::{DropView, DropViewParams},
+  utils::{push_unique, terminate},
+};
+
+impl TransactionQuery for DropView {}
+
+impl DropView {
+  /// Gets the current state of the [DropView] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW user_logins
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [DropView] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Prints the current state of the [DropView] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// DROP VIEW user_logins
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Defines a drop view parameter, this method overrides the previous value
+  ///
+  /// ### Example 1
+  ///
+  ///```
+  /// # #[cfg(not(feature = "postgresql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("users_view")
+  ///   .drop_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW user_logins
+  /// ```
+  ///
+  /// ### Example 2 `crate features postgresql only`
+  ///
+  /// Multiples call will concatenates all values
+  ///
+  ///```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("users_view")
+  ///   .drop_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW users_view, user_logins";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW users_view, user_logins
+  /// ```
+  pub fn drop_view(mut self, view_name: &str) -> Self {
+    push_unique(&mut self._drop_view, view_name.trim().to_string());
+    self
+  }
+
+  /// Adds the `if exists` modifier
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .if_exists()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW IF EXISTS user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW IF EXISTS user_logins
+  /// ```
+  pub fn if_exists(mut self) -> Self {
+    self._if_exists = true;
+    self
+  }
+
+  /// Creates instance of the [DropView] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [DropView] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex drop view command.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .raw("/* drop view command */")
+  ///   .drop_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop view command */ DROP VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop view command */ DROP VIEW user_logins
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .raw_after(sql::DropViewParams::DropView, "/* end drop view */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW user_logins /* end drop view */";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW user_logins /* end drop view */
+  /// ```
+  pub fn raw_after(mut self, param: DropViewParams, raw_sql: &str) -> Self {
+    self._raw_after.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .raw_append("/* end of the drop view command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW user_logins /* end of the drop view command */";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW user_logins /* end of the drop view command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .raw_before(sql::DropViewParams::DropView, "/* drop view command */")
+  ///   .drop_view("user_logins")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop view command */ DROP VIEW user_logins";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop view command */ DROP VIEW user_logins
+  /// ```
+  pub fn raw_before(mut self, param: DropViewParams, raw_sql: &str) -> Self {
+    self._raw_before.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](DropView::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .raw_prepend("/* drop view command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop view command */ DROP VIEW user_logins";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop view command */ DROP VIEW user_logins
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users;";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl DropView {
+  /// Adds the `cascade` option, also drops the objects that depend on the view
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("user_logins")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW user_logins CASCADE";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW user_logins CASCADE
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self
+  }
+}
+
+impl std::fmt::Display for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
@@ -0,0 +1,70 @@
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{DropView, DropViewParams},
+};
+
+impl Concat for DropView {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_string();
+
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_drop_view(query, &fmts);
+
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.trim_end().to_string()
+  }
+}
+
+impl DropView {
+  fn concat_drop_view(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+
+    let sql = if self._drop_view.is_empty() == false {
+      let if_exists = if self._if_exists {
+        format!("{}{space}", fmts.kw("IF EXISTS"))
+      } else {
+        "".to_string()
+      };
+
+      let view_names = if cfg!(any(feature = "postgresql")) {
+        self
+          ._drop_view
+          .iter()
+          .filter(|item| item.is_empty() == false)
+          .map(|item| item.as_str())
+          .collect::<Vec<_>>()
+          .join(comma)
+      } else {
+        self._drop_view.last().unwrap().to_string()
+      };
+
+      #[cfg(feature = "postgresql")]
+      let cascade = if self._cascade {
+        format!("{space}{}", fmts.kw("CASCADE"))
+      } else {
+        "".to_string()
+      };
+      #[cfg(not(feature = "postgresql"))]
+      let cascade = "";
+
+      format!(
+        "{}{space}{if_exists}{view_names}{cascade}{space}{lb}",
+        fmts.kw("DROP VIEW")
+      )
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DropViewParams::DropView,
+      sql,
+    )
+  }
+}
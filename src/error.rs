@@ -0,0 +1,12 @@
+use crate::structure::BuilderError;
+
+impl std::fmt::Display for BuilderError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Self::MissingClause(clause) => write!(f, "missing the required {clause} clause"),
+      Self::UnsafeDelete => write!(f, "the delete without a where clause would delete all rows"),
+    }
+  }
+}
+
+impl std::error::Error for BuilderError {}
@@ -0,0 +1,90 @@
+//! Helpers to quote the values interpolated in the SQL passed to the builders, like the `values` and
+//! `where_clause` arguments. The rules are the same of the PostgreSQL functions `quote_literal` and `quote_ident`
+
+/// Quotes the value as a string literal, the single quotes are doubled and the value is wrapped in single quotes.
+/// When the value has backslashes they are also doubled and the literal is prefixed by `E`, so the result is
+/// the same regardless of the `standard_conforming_strings` setting. The NUL characters are removed since they
+/// cannot be stored in a text value
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::escape::quote_literal;
+///
+/// let login = "o'reilly";
+/// let select_query = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause(&format!("login = {}", quote_literal(login)))
+///   .as_string();
+///
+/// # let expected = "SELECT * FROM users WHERE login = 'o''reilly'";
+/// # assert_eq!(select_query, expected);
+/// assert_eq!(quote_literal(r"C:\tmp"), r"E'C:\\tmp'");
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM users WHERE login = 'o''reilly'
+/// ```
+pub fn quote_literal(value: &str) -> String {
+  let mut literal = String::with_capacity(value.len() + 3);
+  let has_backslash = value.contains('\\');
+
+  if has_backslash {
+    literal.push('E');
+  }
+  literal.push('\'');
+  for char in value.chars() {
+    match char {
+      '\0' => {}
+      '\'' => literal.push_str("''"),
+      '\\' => literal.push_str("\\\\"),
+      _ => literal.push(char),
+    }
+  }
+  literal.push('\'');
+
+  literal
+}
+
+/// Quotes the value as an identifier, the double quotes are doubled and the value is wrapped in double quotes.
+/// The NUL characters are removed since they cannot be part of an identifier
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::escape::quote_ident;
+///
+/// let select_query = sql::Select::new()
+///   .select(&quote_ident("order"))
+///   .from(&quote_ident("Users"))
+///   .as_string();
+///
+/// # let expected = r#"SELECT "order" FROM "Users""#;
+/// # assert_eq!(select_query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT "order" FROM "Users"
+/// ```
+pub fn quote_ident(value: &str) -> String {
+  let mut ident = String::with_capacity(value.len() + 2);
+
+  ident.push('"');
+  for char in value.chars() {
+    match char {
+      '\0' => {}
+      '"' => ident.push_str("\"\""),
+      _ => ident.push(char),
+    }
+  }
+  ident.push('"');
+
+  ident
+}
@@ -1,52 +1,351 @@
+//! Formatters used to render the builders, see the `as_string_with` method of the builders
+
+/// Defines the separators used to concatenate the clauses of a builder
 pub struct Formatter<'a> {
   pub comma: &'a str,
   pub hr: &'a str, // horizontal rule
   pub indent: &'a str,
+  pub keyword_case: KeywordCase,
   pub lb: &'a str, // line break
+  pub minify: bool,
+  pub paren_subqueries: bool,
+  pub placeholder_style: PlaceholderStyle,
   pub space: &'a str,
 }
 
+/// Defines the case of the SQL keywords of the clauses rendered by the builders, the table names, the expressions
+/// and the raw SQL passed as argument are never recased. The keywords generated by the helper methods as part of an
+/// expression, like `ONLY` of the `from_only` method, are kept in upper case
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum KeywordCase {
+  /// Renders the keywords in upper case, like `SELECT`
+  #[default]
+  Upper,
+  /// Renders the keywords in lower case, like `select`
+  Lower,
+  /// Renders the keywords as defined by the builders
+  Preserve,
+}
+
+/// Defines how the `?` placeholders are rendered by the `as_string_with` and `prepare` methods of the builders,
+/// the `?` inside single quoted strings, double quoted identifiers and comments are never rewritten.
+/// The `??` escapes the `?` operators, like the `?`, `?|` and `?&` operators of the PostgreSQL `jsonb` type,
+/// it's not counted as a placeholder and it's rendered as `?` when the placeholders are rewritten
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PlaceholderStyle {
+  /// Keeps the `?` placeholders, the style of the MySQL and SQLite drivers
+  #[default]
+  Question,
+  /// Rewrites the placeholders to `$1`, `$2`, ..., the style of the PostgreSQL drivers
+  Numbered,
+  /// Rewrites the placeholders to `:p1`, `:p2`, ...
+  Named,
+}
+
+impl<'a> Formatter<'a> {
+  /// Applies the [KeywordCase] of the formatter to the keyword passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let fmts = sql::fmt::one_line_lowercase();
+  ///
+  /// assert_eq!(fmts.kw("INSERT INTO"), "insert into");
+  /// ```
+  pub fn kw(&self, keyword: &str) -> String {
+    match self.keyword_case {
+      KeywordCase::Upper => keyword.to_uppercase(),
+      KeywordCase::Lower => keyword.to_lowercase(),
+      KeywordCase::Preserve => keyword.to_string(),
+    }
+  }
+
+  /// The comma that ends a line, used by the lists with one item per line like the rows of the `values` clause.
+  /// The trailing whitespaces of the comma are replaced by the line break, so a comma that already breaks the line,
+  /// like the one of the [pg_format_style], doesn't render an empty line. Without line break the comma is kept as is
+  pub(crate) fn line_comma(&self) -> String {
+    if self.lb.is_empty() {
+      return self.comma.to_string();
+    }
+    format!("{}{}", self.comma.trim_end(), self.lb)
+  }
+
+  /// Applies the [minify](Formatter::minify) pass and the [PlaceholderStyle] of the formatter to the query
+  /// rendered by a builder, the placeholders are only rewritten when the style is not the question style
+  pub(crate) fn finish(&self, query: String) -> String {
+    let query = if self.minify { minify(&query) } else { query };
+    match self.placeholder_style {
+      PlaceholderStyle::Question => query,
+      style => placeholders(&query, style).0,
+    }
+  }
+
+  /// Returns the formatter with the [KeywordCase] passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new().select("id").from("users");
+  /// let fmts = sql::fmt::multiline().with_keyword_case(sql::fmt::KeywordCase::Lower);
+  ///
+  /// # let expected = "select id \nfrom users";
+  /// # assert_eq!(select.as_string_with(&fmts), expected);
+  /// ```
+  pub fn with_keyword_case(self, keyword_case: KeywordCase) -> Self {
+    Self { keyword_case, ..self }
+  }
+
+  /// Returns the formatter that always wraps the nested select of the `insert` and `create view` commands in
+  /// parentheses, so the scope of a `union` or any other combinator of the nested select is explicit.
+  /// By default the nested select is rendered without parentheses, not all databases accept the parenthesized form,
+  /// SQLite for example doesn't
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .select(sql::Select::new().select("login").from("admins"));
+  /// let fmts = sql::fmt::one_line().with_paren_subqueries(true);
+  ///
+  /// # let expected = "INSERT INTO users (login) (SELECT login FROM admins)";
+  /// # assert_eq!(insert.as_string_with(&fmts), expected);
+  /// ```
+  pub fn with_paren_subqueries(self, paren_subqueries: bool) -> Self {
+    Self {
+      paren_subqueries,
+      ..self
+    }
+  }
+
+  /// Returns the formatter with the [PlaceholderStyle] passed as argument
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new().select("id").from("users").where_clause("login = ?");
+  /// let fmts = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+  ///
+  /// # let expected = ("SELECT id FROM users WHERE login = $1".to_string(), 1);
+  /// # assert_eq!(select.prepare(&fmts), expected);
+  /// ```
+  pub fn with_placeholder_style(self, placeholder_style: PlaceholderStyle) -> Self {
+    Self {
+      placeholder_style,
+      ..self
+    }
+  }
+}
+
+/// Renders the query in one line, this is the formatter used by the `as_string` method
 pub fn one_line<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
     hr: "",
     indent: "",
+    keyword_case: KeywordCase::Upper,
     lb: "",
+    minify: false,
+    paren_subqueries: false,
+    placeholder_style: PlaceholderStyle::Question,
     space: " ",
   }
 }
 
+/// Renders the query in one line without the redundant whitespaces. The runs of whitespaces are collapsed into one space,
+/// the spaces around the commas, after `(` and before `)` are removed and the trailing whitespaces are dropped.
+/// The content of the string literals, the quoted identifiers and the block comments is kept as is and the line break
+/// that ends a `--` comment is preserved
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let query = sql::Select::new()
+///   .select("id,   login")
+///   .select("'a  b' AS label")
+///   .from("users")
+///   .where_clause("login IN ( 'foo', 'bar' )")
+///   .as_string_with(&sql::fmt::minified());
+///
+/// # let expected = "SELECT id,login,'a  b' AS label FROM users WHERE login IN ('foo','bar')";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT id,login,'a  b' AS label FROM users WHERE login IN ('foo','bar')
+/// ```
+pub fn minified<'a>() -> Formatter<'a> {
+  Formatter {
+    comma: ",",
+    minify: true,
+    ..one_line()
+  }
+}
+
+/// Same as [one_line] but rendering the keywords in lower case
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let query = sql::Insert::new()
+///   .insert_into("USERS (login)")
+///   .values("('foo')")
+///   .as_string_with(&sql::fmt::one_line_lowercase());
+///
+/// # let expected = "insert into USERS (login) values ('foo')";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// insert into USERS (login) values ('foo')
+/// ```
+pub fn one_line_lowercase<'a>() -> Formatter<'a> {
+  one_line().with_keyword_case(KeywordCase::Lower)
+}
+
+/// Renders the query with one clause per line using the `\n` line break, this is the formatter used by the `debug` method
 pub fn multiline<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
     hr: "-- ------------------------------------------------------------------------------\x1b[0m",
     indent: "  ",
+    keyword_case: KeywordCase::Upper,
+    lb: "\n",
+    minify: false,
+    paren_subqueries: false,
+    placeholder_style: PlaceholderStyle::Question,
+    space: " ",
+  }
+}
+
+/// Renders the query approximating the default style of the pgFormatter tool, to minimize the diffs when the
+/// generated SQL is formatted by it. Each clause is placed in its own line, the conditions are indented
+/// and the items of a list are placed one per line with the comma at the end. The keywords are rendered in upper case by
+/// the builders, the raw SQL and the expressions passed as argument are kept as is, so the output is an
+/// approximation and not the exact output of pgFormatter
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let select = sql::Select::new()
+///   .select("id, login")
+///   .select("name")
+///   .from("users")
+///   .where_clause("login = $1");
+///
+/// let query = select.as_string_with(&sql::fmt::pg_format_style());
+///
+/// # let expected = "SELECT id, login,\n    name \nFROM users \nWHERE\n    login = $1";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT id, login,
+///     name
+/// FROM users
+/// WHERE
+///     login = $1
+/// ```
+pub fn pg_format_style<'a>() -> Formatter<'a> {
+  Formatter {
+    comma: ",\n    ",
+    hr: "",
+    indent: "    ",
+    keyword_case: KeywordCase::Upper,
     lb: "\n",
+    minify: false,
+    paren_subqueries: false,
+    placeholder_style: PlaceholderStyle::Question,
     space: " ",
   }
 }
 
-pub fn colorize(query: String) -> String {
+/// Same as [multiline] but using the `\r\n` line break
+pub fn multiline_crlf<'a>() -> Formatter<'a> {
+  Formatter {
+    lb: "\r\n",
+    ..multiline()
+  }
+}
+
+/// Same as [multiline] but using the `indent` passed as argument as the indentation unit, like four spaces or a tab.
+/// The queries nested in the `with` clause are indented one unit deeper than the query that contains them
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+/// # {
+/// # use sql_query_builder as sql;
+/// let query = sql::Select::new()
+///   .with("active_users", sql::Select::new().select("id").from("users"))
+///   .select("id")
+///   .from("active_users")
+///   .as_string_with(&sql::fmt::multiline_with_indent("\t"));
+///
+/// # let expected = "WITH \nactive_users AS (\n\tSELECT id \n\tFROM users\n) \nSELECT id \nFROM active_users";
+/// # assert_eq!(query, expected);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// WITH
+/// active_users AS (
+///   SELECT id
+///   FROM users
+/// )
+/// SELECT id
+/// FROM active_users
+/// ```
+pub fn multiline_with_indent(indent: &str) -> Formatter<'_> {
+  Formatter { indent, ..multiline() }
+}
+
+pub(crate) fn colorize(query: String) -> String {
   let sql_syntax: Vec<(fn(&str) -> String, &str, &str)> = vec![
     (blue, "ADD", "add"),
     (blue, "ALTER", "alter"),
+    (blue, "CASCADE", "cascade"),
+    (blue, "COALESCE", "coalesce"),
     (blue, "COLUMN", "column"),
     (blue, "COMMIT", "commit"),
     (blue, "COMMITTED", "committed"),
     (blue, "CONCURRENTLY", "concurrently"),
     (blue, "CONFLICT", "conflict"),
     (blue, "CONSTRAINT", "constraint"),
+    (blue, "CYCLE ", "cycle "),
     (blue, "CREATE ", "create "),
     (blue, "DEFAULT", "default"),
     (blue, "DEFERRABLE", "deferrable"),
     (blue, "DELETE ", "delete "),
     (blue, "DISTINCT", "distinct"),
     (blue, "DROP", "drop"),
+    (blue, "DUPLICATE KEY", "duplicate key"),
     (blue, "EXCEPT", "except"),
     (blue, "EXISTS", "exists"),
+    (blue, "EXPLAIN ", "explain "),
+    (blue, "FETCH", "fetch"),
     (blue, "FROM ", "from "),
     (blue, "GROUP BY", "group by"),
+    (blue, "GROUPING SETS", "grouping sets"),
     (blue, "HAVING", "having"),
+    (blue, "IDENTITY", "identity"),
+    (blue, "IGNORE", "ignore"),
     (blue, "INCLUDE", "include"),
     (blue, "INDEX", "index"),
     (blue, "INNER", "inner"),
@@ -54,36 +353,50 @@ pub fn colorize(query: String) -> String {
     (blue, "INTERSECT", "intersect"),
     (blue, "ISOLATION", "isolation"),
     (blue, "JOIN ", "join "),
+    (blue, "LATERAL ", "lateral "),
     (blue, "LEFT ", "left "),
     (blue, "LIMIT ", "limit "),
+    (blue, "MATCHED", "matched"),
+    (blue, "MERGE ", "merge "),
     (blue, "NOTHING", "nothing"),
+    (blue, "NULLS ", "nulls "),
     (blue, "OFFSET", "offset"),
     (blue, "ON ", "on "),
     (blue, "ONLY ", "only "),
     (blue, "ORDER BY", "order by"),
     (blue, "OVERRIDING", "overriding"),
+    (blue, "PARTITION BY", "partition by"),
     (blue, "PRIMARY", "primary"),
     (blue, "READ ONLY", "read only"),
     (blue, "READ WRITE", "read write"),
+    (blue, "RECURSIVE", "recursive"),
     (blue, "RELEASE", "release"),
     (blue, "REPEATABLE", "repeatable"),
     (blue, "REPLACE", "REPLACE"),
+    (blue, "RESTRICT", "restrict"),
     (blue, "RETURNING", "returning"),
     (blue, "RIGHT", "right"),
     (blue, "ROLLBACK", "rollback"),
+    (blue, "ROLLUP", "rollup"),
     (blue, "SAVEPOINT", "savepoint"),
+    (blue, "SEARCH ", "search "),
     (blue, "SELECT ", "select "),
     (blue, "SERIALIZABLE", "serializable"),
     (blue, "SET ", "set "),
     (blue, "START ", "start "),
+    (blue, "TABLESAMPLE", "tablesample"),
+    (blue, "THEN ", "then "),
     (blue, "TABLE", "table"),
     (blue, "TRANSACTION", "transaction"),
+    (blue, "TRUNCATE ", "truncate "),
     (blue, "UNCOMMITTED", "uncommitted"),
     (blue, "UNION ", "union "),
     (blue, "UNIQUE ", "unique "),
     (blue, "UPDATE ", "update "),
     (blue, "USING ", "using "),
     (blue, "VALUES ", "values "),
+    (blue, "VIEW ", "view "),
+    (blue, "WHEN ", "when "),
     (blue, "WHERE ", "where "),
     (blue, "WITH ", "with "),
     (blue, " ALL", " all"),
@@ -105,6 +418,7 @@ pub fn colorize(query: String) -> String {
     (blue, " NOT", " not"),
     (blue, " OR ", " or "),
     (blue, " OUTER", " OUTER"),
+    (blue, " OVER ", " over "),
     (blue, " UNIQUE", " unique"),
     (blue, " USING", " using"),
     (blue, " VARCHAR", " varchar"),
@@ -129,12 +443,215 @@ pub fn colorize(query: String) -> String {
   query
 }
 
-pub fn format(query: String, fmts: &Formatter) -> String {
+/// Rewrites the `?` placeholders of the query using the [PlaceholderStyle] passed as argument and returns the number
+/// of placeholders, the `?` inside single quoted strings, double quoted identifiers and comments are preserved.
+/// The escaped `??` is not a placeholder, it's kept as is by the question style and rendered as the `?` operator
+/// by the other styles, like the `?`, `?|` and `?&` operators of the PostgreSQL `jsonb` type
+pub(crate) fn placeholders(query: &str, style: PlaceholderStyle) -> (String, usize) {
+  let mut rewritten = String::with_capacity(query.len());
+  let mut chars = query.chars().peekable();
+  let mut count = 0;
+
+  while let Some(char) = chars.next() {
+    match char {
+      '\'' | '"' => {
+        rewritten.push(char);
+        for quoted in chars.by_ref() {
+          rewritten.push(quoted);
+          if quoted == char {
+            break;
+          }
+        }
+      }
+      '-' if chars.peek() == Some(&'-') => {
+        rewritten.push(char);
+        for commented in chars.by_ref() {
+          rewritten.push(commented);
+          if commented == '\n' {
+            break;
+          }
+        }
+      }
+      '/' if chars.peek() == Some(&'*') => {
+        rewritten.push(char);
+        rewritten.extend(chars.next());
+        let mut previous = ' ';
+        for commented in chars.by_ref() {
+          rewritten.push(commented);
+          if previous == '*' && commented == '/' {
+            break;
+          }
+          previous = commented;
+        }
+      }
+      '?' if chars.peek() == Some(&'?') => {
+        chars.next();
+        match style {
+          PlaceholderStyle::Question => rewritten.push_str("??"),
+          PlaceholderStyle::Numbered | PlaceholderStyle::Named => rewritten.push('?'),
+        }
+      }
+      '?' => {
+        count += 1;
+        match style {
+          PlaceholderStyle::Question => rewritten.push('?'),
+          PlaceholderStyle::Numbered => rewritten.push_str(&format!("${count}")),
+          PlaceholderStyle::Named => rewritten.push_str(&format!(":p{count}")),
+        }
+      }
+      _ => rewritten.push(char),
+    }
+  }
+
+  (rewritten, count)
+}
+
+/// Removes the redundant whitespaces of the query outside of the string literals, quoted identifiers and comments
+pub(crate) fn minify(query: &str) -> String {
+  let mut minified = String::with_capacity(query.len());
+  let mut chars = query.chars().peekable();
+  let mut pending_space = false;
+
+  let push_space = |minified: &mut String, pending_space: &mut bool| {
+    let after_separator = minified.is_empty() || minified.ends_with([',', '(', '\n']);
+    if *pending_space && after_separator == false {
+      minified.push(' ');
+    }
+    *pending_space = false;
+  };
+
+  while let Some(char) = chars.next() {
+    match char {
+      '\'' | '"' | '`' => {
+        push_space(&mut minified, &mut pending_space);
+        minified.push(char);
+        for quoted in chars.by_ref() {
+          minified.push(quoted);
+          if quoted == char {
+            break;
+          }
+        }
+      }
+      '-' if chars.peek() == Some(&'-') => {
+        push_space(&mut minified, &mut pending_space);
+        minified.push(char);
+        for commented in chars.by_ref() {
+          minified.push(commented);
+          if commented == '\n' {
+            break;
+          }
+        }
+      }
+      '/' if chars.peek() == Some(&'*') => {
+        push_space(&mut minified, &mut pending_space);
+        minified.push(char);
+        minified.extend(chars.next());
+        let mut previous = ' ';
+        for commented in chars.by_ref() {
+          minified.push(commented);
+          if previous == '*' && commented == '/' {
+            break;
+          }
+          previous = commented;
+        }
+      }
+      ',' | ')' => {
+        pending_space = false;
+        minified.push(char);
+      }
+      _ if char.is_whitespace() => pending_space = true,
+      _ => {
+        push_space(&mut minified, &mut pending_space);
+        minified.push(char);
+      }
+    }
+  }
+
+  minified.trim_end().to_string()
+}
+
+pub(crate) fn format(query: String, fmts: &Formatter) -> String {
   let template = format!("{0}{1}{0}{query}{0}{1}{0}", fmts.lb, fmts.hr);
   let template = colorize(template);
   template
 }
 
+/// Wraps the lines longer than `width` breaking after the commas of the lists, the continuation lines are indented
+/// with the indent of the formatter. Commas inside parentheses and single quoted strings don't break the line, so an
+/// item longer than `width` is kept whole in its own line, and a `width` of `0` places each item in its own line.
+/// This is the wrap used by the `pretty` method of the builders
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let fmts = sql::fmt::multiline();
+/// let query = sql::Select::new()
+///   .select("id, login, name, email, created_at")
+///   .from("users")
+///   .as_string_with(&fmts);
+///
+/// let wrapped = sql::fmt::wrap(&query, 25, &fmts);
+///
+/// # let expected = "SELECT id, login, name,\n  email, created_at \nFROM users";
+/// # assert_eq!(wrapped, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT id, login, name,
+///   email, created_at
+/// FROM users
+/// ```
+pub fn wrap(query: &str, width: usize, fmts: &Formatter) -> String {
+  query
+    .split(fmts.lb)
+    .map(|line| wrap_line(line, width, fmts))
+    .collect::<Vec<_>>()
+    .join(fmts.lb)
+}
+
+fn wrap_line(line: &str, width: usize, fmts: &Formatter) -> String {
+  let Formatter { indent, lb, .. } = fmts;
+
+  if line.chars().count() <= width {
+    return line.to_string();
+  }
+
+  let mut items = vec![];
+  let mut start = 0;
+  let mut depth = 0;
+  let mut quoted = false;
+  for (index, char) in line.char_indices() {
+    match char {
+      '\'' => quoted = !quoted,
+      '(' if quoted == false => depth += 1,
+      ')' if quoted == false => depth -= 1,
+      ',' if quoted == false && depth == 0 && line[index + 1..].starts_with(' ') => {
+        items.push(&line[start..=index]);
+        start = index + 2;
+      }
+      _ => {}
+    }
+  }
+  items.push(&line[start..]);
+
+  let mut lines: Vec<String> = vec![];
+  for item in items {
+    match lines.last_mut() {
+      Some(current) if current.chars().count() + 1 + item.chars().count() <= width => {
+        current.push(' ');
+        current.push_str(item);
+      }
+      Some(_) => lines.push(format!("{indent}{item}")),
+      None => lines.push(item.to_string()),
+    }
+  }
+
+  lines.join(lb)
+}
+
 fn blue(text: &str) -> String {
   format!("\x1b[34;1m{text}\x1b[0m")
 }
@@ -0,0 +1,257 @@
+use crate::{
+  concat::Concat,
+  fmt,
+  structure::{FromItem, Select},
+};
+
+impl FromItem {
+  /// Defines the alias of the item, rendered as `AS alias`. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("users").alias("u").as_string();
+  ///
+  /// # let expected = "users AS u";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// users AS u
+  /// ```
+  pub fn alias(mut self, alias: &str) -> Self {
+    self._alias = alias.trim().to_string();
+    self
+  }
+
+  /// Gets the current state of the [FromItem] and returns it as string
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// Adds the `*` after the table name to explicitly include the descendant tables.
+  /// This method and the [only](FromItem::only) method are mutually exclusive, the last call wins
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("measurements").descendants().as_string();
+  ///
+  /// # let expected = "measurements *";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// measurements *
+  /// ```
+  pub fn descendants(mut self) -> Self {
+    self._descendants = true;
+    self._only = false;
+    self
+  }
+
+  /// Creates an item from a function call
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::function("generate_series(1, 10)").alias("n").as_string();
+  ///
+  /// # let expected = "generate_series(1, 10) AS n";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// generate_series(1, 10) AS n
+  /// ```
+  pub fn function(call: &str) -> Self {
+    Self {
+      _source: call.trim().to_string(),
+      ..Default::default()
+    }
+  }
+
+  /// Adds the `LATERAL` keyword before the item, allowing a subquery or function to reference the previous items
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let last_order = sql::Select::new()
+  ///   .select("total")
+  ///   .from("orders")
+  ///   .where_clause("orders.user_id = u.id");
+  ///
+  /// let item = sql::FromItem::subquery(last_order)
+  ///   .lateral()
+  ///   .alias("o")
+  ///   .as_string();
+  ///
+  /// # let expected = "LATERAL (SELECT total FROM orders WHERE orders.user_id = u.id) AS o";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LATERAL (SELECT total FROM orders WHERE orders.user_id = u.id) AS o
+  /// ```
+  pub fn lateral(mut self) -> Self {
+    self._lateral = true;
+    self
+  }
+
+  /// Adds the `ONLY` keyword before the table name to exclude the descendant tables.
+  /// This method and the [descendants](FromItem::descendants) method are mutually exclusive, the last call wins
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("measurements").only().as_string();
+  ///
+  /// # let expected = "ONLY measurements";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ONLY measurements
+  /// ```
+  pub fn only(mut self) -> Self {
+    self._only = true;
+    self._descendants = false;
+    self
+  }
+
+  /// Defines the schema of the item, rendered as `schema.source`. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("users").schema("auth").as_string();
+  ///
+  /// # let expected = "auth.users";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// auth.users
+  /// ```
+  pub fn schema(mut self, schema: &str) -> Self {
+    self._schema = schema.trim().to_string();
+    self
+  }
+
+  /// Creates an item from a subquery, the select is stored and rendered between parentheses with the formatter
+  /// of the query that contains the item, the values bound to the select are included in the params of that query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let item = sql::FromItem::subquery(active_users).alias("u").as_string();
+  ///
+  /// # let expected = "(SELECT * FROM users WHERE active = true) AS u";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT * FROM users WHERE active = true) AS u
+  /// ```
+  pub fn subquery(select: Select) -> Self {
+    Self {
+      _subquery: Some(Box::new(select)),
+      ..Default::default()
+    }
+  }
+
+  /// Creates an item from a table name
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("users").as_string();
+  ///
+  /// # let expected = "users";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// users
+  /// ```
+  pub fn table(name: &str) -> Self {
+    Self {
+      _source: name.trim().to_string(),
+      ..Default::default()
+    }
+  }
+
+  /// Defines the `TABLESAMPLE` of the item, the arguments are rendered between parentheses after the sampling method.
+  /// This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let item = sql::FromItem::table("users")
+  ///   .alias("u")
+  ///   .tablesample("bernoulli", "10")
+  ///   .as_string();
+  ///
+  /// # let expected = "users AS u TABLESAMPLE bernoulli (10)";
+  /// # assert_eq!(item, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// users AS u TABLESAMPLE bernoulli (10)
+  /// ```
+  pub fn tablesample(mut self, method: &str, arguments: &str) -> Self {
+    let method = method.trim();
+    let arguments = arguments.trim();
+    self._tablesample = if method.is_empty() {
+      "".to_string()
+    } else {
+      format!("{method} ({arguments})")
+    };
+    self
+  }
+}
+
+impl std::fmt::Display for FromItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for FromItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
@@ -0,0 +1,76 @@
+use crate::{concat::Concat, fmt, structure::FromItem};
+
+impl Concat for FromItem {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter {
+      comma,
+      indent,
+      lb,
+      space,
+      ..
+    } = fmts;
+
+    if self.is_empty() {
+      return "".to_string();
+    }
+
+    let mut item = "".to_string();
+    if self._lateral {
+      item = format!("{item}{}{space}", fmts.kw("LATERAL"));
+    }
+    if self._only {
+      item = format!("{item}{}{space}", fmts.kw("ONLY"));
+    }
+    if self._schema.is_empty() == false {
+      item = format!("{item}{}.", self._schema);
+    }
+    match &self._subquery {
+      Some(select) => {
+        let inner_lb = format!("{lb}{indent}");
+        let inner_fmts = fmt::Formatter {
+          lb: inner_lb.as_str(),
+          ..*fmts
+        };
+        item = format!("{item}({lb}{indent}{}{lb})", select.concat(&inner_fmts));
+      }
+      None => item = format!("{item}{}", self._source),
+    }
+    if self._descendants {
+      item = format!("{item}{space}*");
+    }
+    if self._alias.is_empty() == false {
+      item = format!("{item}{space}{}{space}{}", fmts.kw("AS"), self._alias);
+      if self._columns.is_empty() == false {
+        item = format!("{item}{space}({})", self._columns.join(comma));
+      }
+    }
+    if self._tablesample.is_empty() == false {
+      item = format!("{item}{space}{}{space}{}", fmts.kw("TABLESAMPLE"), self._tablesample);
+    }
+
+    item
+  }
+}
+
+impl FromItem {
+  pub(crate) fn is_empty(&self) -> bool {
+    self._source.is_empty() && self._subquery.is_none()
+  }
+}
+
+#[cfg(feature = "postgresql")]
+impl FromItem {
+  /// Adds the values of the nested select, the `?` placeholders of the item itself are bound by the select
+  /// that contains the item
+  pub(crate) fn collect_params(&self, params: &mut crate::param_value::OrderedParams) {
+    use crate::behavior::WithQuery;
+
+    match &self._subquery {
+      Some(select) => {
+        params.nested(select.bound_params());
+        params.own(&self._tablesample);
+      }
+      None => params.own(&self.concat(&fmt::one_line())),
+    }
+  }
+}
@@ -0,0 +1,2 @@
+mod from_item;
+mod from_item_internal;
@@ -1,14 +1,49 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
+  behavior::{IntoRow, TransactionQuery},
   fmt,
-  structure::{Insert, InsertClause, Select},
-  utils::push_unique,
+  structure::{
+    BuilderError, ClauseItem, ConflictTarget, Explain, Insert, InsertClause, InsertTarget, OnConflict, Select,
+  },
+  utils::{block_comment, push_unique, remove_raw_of_clause, terminate},
 };
 
 impl TransactionQuery for Insert {}
 
 impl Insert {
+  /// Appends a separated statement rendered after the insert command, the statements are separated by `;` and a
+  /// line break in all formatters, so each statement of a script starts in its own line. Unlike the [raw_append](Insert::raw_append) method the appended statement is not part
+  /// of the insert command, so it's not rendered when the insert is nested in another builder, like a transaction
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .append("ANALYZE users")
+  ///   .append("SELECT count(*) FROM users")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo');\nANALYZE users;\nSELECT count(*) FROM users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo');
+  /// ANALYZE users;
+  /// SELECT count(*) FROM users
+  /// ```
+  pub fn append(mut self, raw_statement: &str) -> Self {
+    let raw_statement = raw_statement.trim();
+    if raw_statement.is_empty() == false {
+      self._append.push(raw_statement.to_string());
+    }
+    self
+  }
+
   /// Gets the current state of the [Insert] and returns it as string
   ///
   /// # Example
@@ -31,7 +66,205 @@ impl Insert {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat_statements(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat_statements(fmts)), self._terminated)
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo');";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo');
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string, returns an error when a required clause is missing,
+  /// an [Insert] must define the `insert_into` clause and one of the `values`, `select` or `default_values` clauses. The builders used with the `raw` method are not verified
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let result = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .try_as_string();
+  ///
+  /// assert_eq!(result, Err(sql::BuilderError::MissingClause("values")));
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuilderError> {
+    match self.missing_clause() {
+      Some(clause) => Err(BuilderError::MissingClause(clause)),
+      None => Ok(self.as_string()),
+    }
+  }
+
+  /// Removes the clause passed as argument and the raw SQL queries added with [raw_before](Insert::raw_before)
+  /// and [raw_after](Insert::raw_after) bound to it, clearing a clause that was never defined has no effect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let template = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .on_conflict("do nothing");
+  ///
+  /// let insert = template.clear(sql::InsertClause::OnConflict);
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(insert.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn clear(mut self, clause: InsertClause) -> Self {
+    match clause {
+      InsertClause::DefaultValues => self._default_values = false,
+      #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+      InsertClause::InsertInto => self._insert_into = InsertTarget::default(),
+      #[cfg(any(feature = "sqlite", feature = "mysql"))]
+      InsertClause::InsertInto => self.clear_insert(InsertVars::InsertInto),
+      InsertClause::OnConflict => {
+        self._on_conflict = OnConflict::None;
+        self._on_conflict_where.clear();
+      }
+      InsertClause::Overriding => self._overriding.clear(),
+      InsertClause::Select => self._select = None,
+      InsertClause::Values => self._values.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      InsertClause::Returning => self._returning.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      InsertClause::With => {
+        self._with.clear();
+        self._with_recursive = false;
+      }
+      #[cfg(feature = "sqlite")]
+      InsertClause::InsertOr => self.clear_insert(InsertVars::InsertOr),
+      #[cfg(any(feature = "sqlite", feature = "mysql"))]
+      InsertClause::ReplaceInto => self.clear_insert(InsertVars::ReplaceInto),
+      #[cfg(feature = "mysql")]
+      InsertClause::InsertIgnore => self.clear_insert(InsertVars::InsertIgnore),
+      #[cfg(feature = "mysql")]
+      InsertClause::OnDuplicateKeyUpdate => self._on_duplicate_key_update.clear(),
+    }
+    remove_raw_of_clause(&mut self._raw_before, &clause);
+    remove_raw_of_clause(&mut self._raw_after, &clause);
+    self
+  }
+
+  /// Removes all rows of the `values` clause and the raw SQL queries added with [raw_before](Insert::raw_before)
+  /// and [raw_after](Insert::raw_after) bound to the [InsertClause::Values]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .raw_after(sql::InsertClause::Values, ", ('bar', 'Bar')");
+  ///
+  /// let query = insert
+  ///   .clear_values()
+  ///   .values("('baz', 'Baz')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('baz', 'Baz')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('baz', 'Baz')
+  /// ```
+  pub fn clear_values(self) -> Self {
+    self.clear(InsertClause::Values)
+  }
+
+  /// Adds a block comment at the start of the query, useful to trace the code that generated the query
+  /// in the database logs. The comment is rendered in a single line and a `*/` in the text can't close it early
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .comment("signup")
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* signup */ INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* signup */ INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn comment(mut self, text: &str) -> Self {
+    if text.trim().is_empty() == false {
+      push_unique(&mut self._comment, block_comment(text));
+    }
+    self
+  }
+
+  /// Adds a block comment before the clause passed as argument, the comment is escaped like the
+  /// [comment](Self::comment) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .comment_on(sql::InsertClause::Values, "imported rows")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) /* imported rows */ VALUES ('foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) /* imported rows */ VALUES ('foo')
+  /// ```
+  pub fn comment_on(self, clause: InsertClause, text: &str) -> Self {
+    if text.trim().is_empty() {
+      return self;
+    }
+    self.raw_before(clause, &block_comment(text))
   }
 
   /// Prints the current state of the [Insert] to the standard output in a more ease to read version.
@@ -59,7 +292,10 @@ impl Insert {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat_statements(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -88,6 +324,60 @@ impl Insert {
     self
   }
 
+  /// Prefixes the query with `EXPLAIN` to show its execution plan, the prefix is placed before the `with` clause.
+  /// This method overrides the previous value of the [explain_analyze](Insert::explain_analyze) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .explain()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn explain(mut self) -> Self {
+    self._explain = Explain::Plain;
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN ANALYZE`, the query is executed to show its actual execution plan.
+  /// This method overrides the previous value of the [explain](Insert::explain) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .explain_analyze()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN ANALYZE INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN ANALYZE INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn explain_analyze(mut self) -> Self {
+    self._explain = Explain::Analyze;
+    self
+  }
+
   /// The `insert into` clause. This method overrides the previous value
   ///
   /// # Example
@@ -107,290 +397,1340 @@ impl Insert {
   /// # let expected = "INSERT INTO users (login, name)";
   /// # assert_eq!(insert.to_string(), expected);
   /// ```
-  #[cfg(not(feature = "sqlite"))]
-  pub fn insert_into(mut self, table_name: &str) -> Self {
-    self._insert_into = table_name.trim().to_string();
+  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+  pub fn insert_into(mut self, table_name: &str) -> Self {
+    self._insert_into = InsertTarget::from(table_name);
+    self
+  }
+
+  /// The `insert into` clause with its column list and the `select` clause in one call, the `columns` is the table
+  /// name followed by the column list, like `users (login, name)`. This method is the same as calling the
+  /// `insert_into` and the [select](Insert::select) methods and overrides the previous value of both clauses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_select(
+  ///     "users (login, name)",
+  ///     sql::Select::new().select("login, name").from("users_bk"),
+  ///   )
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) SELECT login, name FROM users_bk";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// SELECT login, name
+  /// FROM users_bk
+  /// ```
+  pub fn insert_select(self, columns: &str, query: Select) -> Self {
+    self.insert_into(columns).select(query)
+  }
+
+  /// Creates instance of the Insert command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The `on conflict` clause. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .on_conflict("do nothing")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) ON CONFLICT do nothing";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) ON CONFLICT do nothing
+  /// ```
+  pub fn on_conflict(mut self, conflict: &str) -> Self {
+    let conflict = conflict.trim();
+    self._on_conflict = if conflict.is_empty() {
+      OnConflict::None
+    } else {
+      OnConflict::Raw(conflict.to_string())
+    };
+    self
+  }
+
+  /// The `on conflict do nothing` clause. This method overrides the previous value
+  /// of the [on_conflict](Insert::on_conflict) and [on_conflict_do_update](Insert::on_conflict_do_update) methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .on_conflict_do_nothing(sql::ConflictTarget::columns(&["login"]))
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo') ON CONFLICT (login) DO NOTHING";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo') ON CONFLICT (login) DO NOTHING
+  /// ```
+  pub fn on_conflict_do_nothing(mut self, target: ConflictTarget) -> Self {
+    self._on_conflict = OnConflict::DoNothing(target);
+    self
+  }
+
+  /// The `on conflict do update set` clause, each assignment is a pair of column and expression.
+  /// This method overrides the previous value of the [on_conflict](Insert::on_conflict) and
+  /// [on_conflict_do_nothing](Insert::on_conflict_do_nothing) methods. The `do update` action requires
+  /// a conflict target, so an empty target has no effect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_do_update(
+  ///     sql::ConflictTarget::columns(&["login"]),
+  ///     [("name", "EXCLUDED.name")]
+  ///   )
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name) \
+  /// #   VALUES ('foo', 'Foo') \
+  /// #   ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo')
+  /// ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name
+  /// ```
+  pub fn on_conflict_do_update<'a>(
+    mut self,
+    target: ConflictTarget,
+    assignments: impl IntoIterator<Item = (&'a str, &'a str)>,
+  ) -> Self {
+    if target.is_empty() {
+      return self;
+    }
+    let mut set = vec![];
+    for (column, value) in assignments {
+      let (column, value) = (column.trim(), value.trim());
+      if column.is_empty() == false {
+        push_unique(&mut set, (column.to_string(), ClauseItem::default().expr(value)));
+      }
+    }
+    self._on_conflict = OnConflict::DoUpdate(target, set);
+    self
+  }
+
+  /// The `on conflict do update set` clause assigning to each column the value proposed for insertion,
+  /// accessed by the `excluded` table. This method overrides the previous value of the
+  /// [on_conflict](Insert::on_conflict) and [on_conflict_do_update](Insert::on_conflict_do_update) methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name, email)")
+  ///   .values("('foo', 'Foo', 'foo@mail.com')")
+  ///   .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name", "email"])
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name, email) \
+  /// #   VALUES ('foo', 'Foo', 'foo@mail.com') \
+  /// #   ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name, email = EXCLUDED.email\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name, email)
+  /// VALUES ('foo', 'Foo', 'foo@mail.com')
+  /// ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name, email = EXCLUDED.email
+  /// ```
+  ///
+  /// # Example `crate features sqlite only`
+  ///
+  /// The `excluded` table is rendered with the keyword case of the formatter, the
+  /// [KeywordCase::Preserve](crate::fmt::KeywordCase::Preserve) renders it in lower case as in the SQLite documentation
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name"])
+  ///   .returning("id")
+  ///   .as_string_with(&sql::fmt::one_line().with_keyword_case(sql::fmt::KeywordCase::Preserve));
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name) \
+  /// #   VALUES ('foo', 'Foo') \
+  /// #   ON CONFLICT (login) DO UPDATE SET name = excluded.name \
+  /// #   RETURNING id\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo')
+  /// ON CONFLICT (login) DO UPDATE SET name = excluded.name
+  /// RETURNING id
+  /// ```
+  pub fn on_conflict_do_update_excluded(mut self, target: ConflictTarget, columns: &[&str]) -> Self {
+    if target.is_empty() {
+      return self;
+    }
+    let mut set = vec![];
+    for column in columns {
+      let column = column.trim();
+      if column.is_empty() == false {
+        let value = ClauseItem::default().keyword("excluded").expr(&format!(".{column}"));
+        push_unique(&mut set, (column.to_string(), value));
+      }
+    }
+    self._on_conflict = OnConflict::DoUpdate(target, set);
+    self
+  }
+
+  /// The `where` predicate of the `on conflict do update` clause, only rendered with the
+  /// [on_conflict_do_update](Insert::on_conflict_do_update) method. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_do_update(
+  ///     sql::ConflictTarget::constraint("users_login_key"),
+  ///     [("name", "EXCLUDED.name")]
+  ///   )
+  ///   .on_conflict_where("users.active = true")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name) \
+  /// #   VALUES ('foo', 'Foo') \
+  /// #   ON CONFLICT ON CONSTRAINT users_login_key \
+  /// #   DO UPDATE SET name = EXCLUDED.name \
+  /// #   WHERE users.active = true\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo')
+  /// ON CONFLICT ON CONSTRAINT users_login_key
+  /// DO UPDATE SET name = EXCLUDED.name
+  /// WHERE users.active = true
+  /// ```
+  pub fn on_conflict_where(mut self, predicate: &str) -> Self {
+    self._on_conflict_where = predicate.trim().to_string();
+    self
+  }
+
+  /// The `overriding` clause. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .overriding("user value")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) OVERRIDING user value";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) OVERRIDING user value
+  /// ```
+  #[cfg(any(feature = "postgresql", not(feature = "sqlite")))]
+  pub fn overriding(mut self, option: &str) -> Self {
+    self._overriding = option.trim().to_string();
+    self
+  }
+
+  /// The `overriding system value` clause, the values of the identity columns defined as `generated always`
+  /// are used instead of the sequence. This method overrides the previous value of the `overriding` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (id, login)")
+  ///   .overriding_system()
+  ///   .values("(1, 'foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (id, login) OVERRIDING SYSTEM VALUE VALUES (1, 'foo')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (id, login) OVERRIDING SYSTEM VALUE VALUES (1, 'foo')
+  /// ```
+  #[cfg(any(feature = "postgresql", not(feature = "sqlite")))]
+  pub fn overriding_system(self) -> Self {
+    self.overriding("SYSTEM VALUE")
+  }
+
+  /// The `overriding user value` clause, the values of the identity columns defined as `generated by default`
+  /// are ignored and the sequence is used. This method overrides the previous value of the `overriding` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (id, login)")
+  ///   .overriding_user()
+  ///   .values("(1, 'foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (id, login) OVERRIDING USER VALUE VALUES (1, 'foo')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (id, login) OVERRIDING USER VALUE VALUES (1, 'foo')
+  /// ```
+  #[cfg(any(feature = "postgresql", not(feature = "sqlite")))]
+  pub fn overriding_user(self) -> Self {
+    self.overriding("USER VALUE")
+  }
+
+  /// Prints the current state of the [Insert] to the standard output like the debug method, wrapping the lines
+  /// longer than `width` after the commas of the lists. The wrap only affects the printed output, the `as_string`
+  /// method is not affected
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo'), ('bar', 'Bar'), ('baz', 'Baz')")
+  ///   .pretty(40)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// INSERT INTO users (login, name)
+  /// VALUES
+  /// ('foo', 'Foo'), ('bar', 'Bar'),
+  ///   ('baz', 'Baz')
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn pretty(self, width: usize) -> Self {
+    let fmts = fmt::multiline();
+    let query = fmt::wrap(
+      &terminate(self.concat_statements(&fmts), self._terminated),
+      width,
+      &fmts,
+    );
+    println!("{}", fmt::format(query, &fmts));
+    self
+  }
+
+  /// Prints the current state of the [Insert] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat_statements(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// The `select` clause. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .select(
+  ///     sql::Select::new()
+  ///       .select("login, name")
+  ///       .from("users_bk")
+  ///       .where_clause("active = true"),
+  ///   )
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name) \
+  /// #   SELECT login, name \
+  /// #   FROM users_bk \
+  /// #   WHERE active = true\
+  /// # ";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// SELECT login, name
+  /// FROM users_bk
+  /// WHERE active = true
+  /// ```
+  pub fn select(mut self, select: Select) -> Self {
+    self._select = Some(select);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query, after the comment and the explain prefix.
+  /// Use the [raw_prepend](Insert::raw_prepend) method to add it at the very start
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "insert into users (login, name)";
+  ///
+  /// let insert_query = sql::Insert::new()
+  ///   .raw(raw_query)
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "insert into users (login, name) VALUES ('foo', 'Foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// insert into users (login, name) VALUES ('foo', 'Foo')
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw = "values ('foo', 'Foo')";
+  ///
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .raw_after(sql::InsertClause::InsertInto, raw)
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) values ('foo', 'Foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) values ('foo', 'Foo')
+  /// ```
+  pub fn raw_after(mut self, clause: InsertClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder, the statements added with
+  /// the [append](Insert::append) method are rendered after it.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .raw_append("/* end of the insert command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo') /* end of the insert command */";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo') /* end of the insert command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw = "insert into users (login, name)";
+  ///
+  /// let insert_query = sql::Insert::new()
+  ///   .raw_before(sql::InsertClause::Values, raw)
+  ///   .values("('bar', 'Bar')")
+  ///   .as_string();
+  ///
+  /// # let expected = "insert into users (login, name) VALUES ('bar', 'Bar')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// insert into users (login, name) VALUES ('bar', 'Bar')
+  /// ```
+  pub fn raw_before(mut self, clause: InsertClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the comment and the explain prefix,
+  /// unlike the [raw](Insert::raw) method that is rendered after them.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .raw_prepend("/* insert command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* insert command */ INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(insert_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* insert command */ INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Splits the rows of the `values` clause in multiples [Insert] builders, each rendered statement will stay
+  /// under the `max_bytes` budget. All other clauses are copied to each one of the builders.
+  /// The size of the statements are estimated using the one line format, a single row greater than the budget
+  /// will be placed alone in its own builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let inserts = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .values("('bar')")
+  ///   .values("('baz')")
+  ///   .split_by_bytes(50);
+  ///
+  /// # let expected = vec![
+  /// #   "INSERT INTO users (login) VALUES ('foo'), ('bar')",
+  /// #   "INSERT INTO users (login) VALUES ('baz')",
+  /// # ];
+  /// # let queries = inserts.iter().map(|insert| insert.as_string()).collect::<Vec<_>>();
+  /// # assert_eq!(queries, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo'), ('bar')
+  /// INSERT INTO users (login) VALUES ('baz')
+  /// ```
+  pub fn split_by_bytes(&self, max_bytes: usize) -> Vec<Self> {
+    let rows = self
+      ._values
+      .iter()
+      .filter(|row| row.is_empty() == false)
+      .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+      return vec![self.clone()];
+    }
+
+    let base = self.clone_without_values();
+    let fmts = fmt::one_line();
+    let fmt::Formatter { comma, space, .. } = fmts;
+    let base_size = base.as_string().len() + format!("{space}VALUES{space}").len();
+
+    let mut inserts = vec![];
+    let mut chunk = base.clone();
+    let mut chunk_size = base_size;
+
+    for row in rows {
+      let row_size = if chunk._values.is_empty() {
+        row.len()
+      } else {
+        comma.len() + row.len()
+      };
+
+      if chunk._values.is_empty() == false && chunk_size + row_size > max_bytes {
+        inserts.push(chunk);
+        chunk = base.clone();
+        chunk_size = base_size + row.len();
+      } else {
+        chunk_size += row_size;
+      }
+
+      chunk._values.push(row.clone());
+    }
+    inserts.push(chunk);
+
+    inserts
+  }
+
+  /// Inserts the row or updates the existing one keyed on the unique columns of `conflict_columns`, each column
+  /// of `update_columns` is assigned the value proposed for insertion. It's a shorthand of the
+  /// [on_conflict_do_update_excluded](Insert::on_conflict_do_update_excluded) method, and of the
+  /// [on_conflict_do_nothing](Insert::on_conflict_do_nothing) method when `update_columns` is empty.
+  /// This method overrides the previous value of the `on conflict` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (tenant_id, login, name)")
+  ///   .values("(1, 'foo', 'Foo')")
+  ///   .upsert("tenant_id, login", &["name"])
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (tenant_id, login, name) \
+  /// #   VALUES (1, 'foo', 'Foo') \
+  /// #   ON CONFLICT (tenant_id, login) DO UPDATE SET name = EXCLUDED.name\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (tenant_id, login, name)
+  /// VALUES (1, 'foo', 'Foo')
+  /// ON CONFLICT (tenant_id, login) DO UPDATE SET name = EXCLUDED.name
+  /// ```
+  pub fn upsert(self, conflict_columns: &str, update_columns: &[&str]) -> Self {
+    let target = ConflictTarget::columns(&conflict_columns.split(',').collect::<Vec<_>>());
+    let update_columns = update_columns
+      .iter()
+      .map(|column| column.trim())
+      .filter(|column| column.is_empty() == false)
+      .collect::<Vec<_>>();
+
+    if update_columns.is_empty() {
+      return self.on_conflict_do_nothing(target);
+    }
+    self.on_conflict_do_update_excluded(target, &update_columns)
+  }
+
+  /// Checks the column list of the insert clause against the generated columns passed as argument and returns
+  /// one warning for each generated column found, an empty list means no issue was found.
+  /// The builder doesn't know the schema, so this check is useful for tools that know the generated columns of the table.
+  /// The column names are compared ignoring the case and the double quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, full_name)")
+  ///   .values("('foo', 'Foo Bar')");
+  ///
+  /// let warnings = insert.validate_against_generated(&["full_name"]);
+  ///
+  /// # assert_eq!(warnings.len(), 1);
+  /// assert_eq!(warnings[0], "the generated column full_name must not be in the column list of the insert");
+  /// ```
+  pub fn validate_against_generated(&self, generated_columns: &[&str]) -> Vec<String> {
+    let target = self.insert_target();
+
+    generated_columns
+      .iter()
+      .map(|generated| generated.trim())
+      .filter(|generated| generated.is_empty() == false && target.has_column(generated))
+      .map(|generated| format!("the generated column {generated} must not be in the column list of the insert"))
+      .collect()
+  }
+
+  /// The `values` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .values("('bar', 'Bar')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn values(mut self, value: &str) -> Self {
+    push_unique(&mut self._values, value.trim().to_string());
+    self
+  }
+
+  /// The `values` clause, accumulates each row of the iterator like consecutive calls of the
+  /// [values](Insert::values) method, empty rows are skipped
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let users = [("foo", "Foo"), ("bar", "Bar")];
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_from(users.iter().map(|(login, name)| format!("('{login}', '{name}')")))
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn values_from<I, S>(mut self, rows: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+  {
+    for row in rows {
+      let row = row.as_ref().trim();
+      if row.is_empty() == false {
+        push_unique(&mut self._values, row.to_string());
+      }
+    }
+    self
+  }
+
+  /// The `values` clause with a row for each item of the iterator, mapped by the [IntoRow] trait. The rows are
+  /// accumulated like consecutive calls of the [values](Insert::values) method, empty rows are skipped
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::{escape::quote_literal, IntoRow};
+  ///
+  /// struct User {
+  ///   login: &'static str,
+  ///   name: &'static str,
+  /// }
+  ///
+  /// impl IntoRow for User {
+  ///   fn into_row(&self) -> String {
+  ///     format!("({}, {})", quote_literal(self.login), quote_literal(self.name))
+  ///   }
+  /// }
+  ///
+  /// let users = vec![
+  ///   User { login: "foo", name: "Foo" },
+  ///   User { login: "bar", name: "Bar" },
+  /// ];
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_iter(&users)
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn values_iter<T: IntoRow>(self, items: impl IntoIterator<Item = T>) -> Self {
+    self.values_from(items.into_iter().map(|item| item.into_row()))
+  }
+
+  /// The `values` clause, wraps the row values in parentheses. Consecutive calls accumulates rows like the
+  /// [values](Insert::values) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_tuple("'foo', 'Foo'")
+  ///   .values_tuple("'bar', 'Bar'")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn values_tuple(mut self, cells: &str) -> Self {
+    let cells = cells.trim();
+    if cells.is_empty() == false {
+      push_unique(&mut self._values, format!("({cells})"));
+    }
+    self
+  }
+
+  /// The `values` clause with a row where each `None` renders the `DEFAULT` keyword and each `Some` renders the
+  /// value as is. Consecutive calls accumulates rows like the [values](Insert::values) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name, created_at)")
+  ///   .values_with_defaults(&[Some("'foo'"), None, None])
+  ///   .values_with_defaults(&[Some("'bar'"), Some("'Bar'"), None])
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name, created_at) \
+  /// #   VALUES ('foo', DEFAULT, DEFAULT), ('bar', 'Bar', DEFAULT)\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name, created_at)
+  /// VALUES ('foo', DEFAULT, DEFAULT), ('bar', 'Bar', DEFAULT)
+  /// ```
+  pub fn values_with_defaults(self, values: &[Option<&str>]) -> Self {
+    let cells = values
+      .iter()
+      .map(|value| match value {
+        Some(value) => value.trim(),
+        None => "DEFAULT",
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    self.values_tuple(&cells)
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+use crate::{behavior::WithQuery, structure::WithBody, utils::push_returning};
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+impl WithQuery for Insert {
+  #[cfg(feature = "postgresql")]
+  fn bound_params(&self) -> Vec<ParamValue> {
+    self.ordered_params()
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl Insert {
+  /// Gets the output names defined in the `returning` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .returning("id")
+  ///   .returning("login");
+  ///
+  /// assert_eq!(insert.get_returning(), ["id", "login"]);
+  /// # }
+  /// ```
+  pub fn get_returning(&self) -> Vec<String> {
+    self._returning.iter().map(|item| item.to_string()).collect()
+  }
+
+  /// The `returning` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users")
+  ///   .returning("id")
+  ///   .returning("login")
+  ///   .to_string();
+  ///
+  /// # let expected = "INSERT INTO users RETURNING id, login";
+  /// # assert_eq!(insert_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users RETURNING id, login
+  /// ```
+  pub fn returning(mut self, output_name: &str) -> Self {
+    push_returning(&mut self._returning, ClauseItem::default().expr(output_name.trim()));
     self
   }
 
-  /// Creates instance of the Insert command
-  pub fn new() -> Self {
-    Self::default()
-  }
-
-  /// The `on conflict` clause. This method overrides the previous value
+  /// The `returning` clause with all the columns of the affected rows, the output names defined by the
+  /// [returning](Insert::returning) method are replaced by `*` and an output name defined after this method replaces the `*`
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
   /// # use sql_query_builder as sql;
-  /// let query = sql::Insert::new()
-  ///   .insert_into("users (login)")
-  ///   .on_conflict("do nothing")
+  /// let insert_query = sql::Insert::new()
+  ///   .returning("id")
+  ///   .returning("login")
+  ///   .returning_all()
   ///   .as_string();
   ///
-  /// # let expected = "INSERT INTO users (login) ON CONFLICT do nothing";
-  /// # assert_eq!(query, expected);
+  /// # let expected = "RETURNING *";
+  /// # assert_eq!(insert_query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users (login) ON CONFLICT do nothing
+  /// RETURNING *
   /// ```
-  pub fn on_conflict(mut self, conflict: &str) -> Self {
-    self._on_conflict = conflict.trim().to_string();
+  pub fn returning_all(mut self) -> Self {
+    push_returning(&mut self._returning, ClauseItem::default().expr("*"));
     self
   }
 
-  /// The `overriding` clause. This method overrides the previous value
+  /// The `returning` clause with an expression and its alias, rendered as `expr AS alias`. An output name with the
+  /// same expression, like the one added by `returning("id")`, is replaced keeping its position
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
   /// # use sql_query_builder as sql;
-  /// let query = sql::Insert::new()
+  /// let insert = sql::Insert::new()
   ///   .insert_into("users (login)")
-  ///   .overriding("user value")
-  ///   .as_string();
+  ///   .values("('foo')")
+  ///   .returning_as("id", "pk")
+  ///   .returning("login");
   ///
-  /// # let expected = "INSERT INTO users (login) OVERRIDING user value";
-  /// # assert_eq!(query, expected);
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo') RETURNING id AS pk, login";
+  /// # assert_eq!(insert.to_string(), expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users (login) OVERRIDING user value
+  /// INSERT INTO users (login) VALUES ('foo') RETURNING id AS pk, login
   /// ```
-  #[cfg(not(feature = "sqlite"))]
-  pub fn overriding(mut self, option: &str) -> Self {
-    self._overriding = option.trim().to_string();
-    self
-  }
-
-  /// Prints the current state of the [Insert] to the standard output similar to debug method,
-  /// the difference is that this method prints in one line.
-  pub fn print(self) -> Self {
-    let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+  pub fn returning_as(mut self, expr: &str, alias: &str) -> Self {
+    let (expr, alias) = (expr.trim(), alias.trim());
+    if expr.is_empty() == false && alias.is_empty() == false {
+      let output_name = ClauseItem::default()
+        .expr(expr)
+        .expr(" ")
+        .keyword("AS")
+        .expr(&format!(" {alias}"));
+      push_returning(&mut self._returning, output_name);
+    } else {
+      push_returning(&mut self._returning, ClauseItem::default().expr(expr));
+    }
     self
   }
 
-  /// The `select` clause. This method overrides the previous value
+  /// The `with` clause
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
   /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users_bk")
+  ///   .where_clause("ative = true");
+  ///
   /// let insert_query = sql::Insert::new()
-  ///   .insert_into("users (login, name)")
-  ///   .select(
-  ///     sql::Select::new()
-  ///       .select("login, name")
-  ///       .from("users_bk")
-  ///       .where_clause("active = true"),
-  ///   )
-  ///   .as_string();
+  ///   .with("active_users", active_users)
+  ///   .insert_into("users")
+  ///   .select(sql::Select::new().select("*").from("active_users"))
+  ///   .to_string();
   ///
   /// # let expected = "\
-  /// #   INSERT INTO users (login, name) \
-  /// #   SELECT login, name \
-  /// #   FROM users_bk \
-  /// #   WHERE active = true\
+  /// #   WITH active_users AS (\
+  /// #     SELECT * \
+  /// #     FROM users_bk \
+  /// #     WHERE ative = true\
+  /// #   ) \
+  /// #   INSERT INTO users \
+  /// #   SELECT * \
+  /// #   FROM active_users\
   /// # ";
   /// # assert_eq!(insert_query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users (login, name)
-  /// SELECT login, name
-  /// FROM users_bk
-  /// WHERE active = true
+  /// WITH active_users AS (
+  ///   SELECT *
+  ///   FROM users_bk
+  ///   WHERE ative = true
+  /// )
+  /// INSERT INTO users
+  /// SELECT *
+  /// FROM active_users
   /// ```
-  pub fn select(mut self, select: Select) -> Self {
-    self._select = Some(select);
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
     self
   }
 
-  /// Adds at the beginning a raw SQL query.
+  /// The `with` clause with the query written as SQL, the query is wrapped in `name AS (...)` in the original
+  /// order with the queries added by the `with` method
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
   /// # use sql_query_builder as sql;
-  /// let raw_query = "insert into users (login, name)";
-  ///
-  /// let insert_query = sql::Insert::new()
-  ///   .raw(raw_query)
-  ///   .values("('foo', 'Foo')")
+  /// let query = sql::Insert::new()
+  ///   .with_raw("active_users", "select * from users_bk where active = true")
+  ///   .insert_into("users")
+  ///   .select(sql::Select::new().select("*").from("active_users"))
   ///   .as_string();
   ///
-  /// # let expected = "insert into users (login, name) VALUES ('foo', 'Foo')";
-  /// # assert_eq!(insert_query, expected);
+  /// # let expected = "WITH active_users AS (select * from users_bk where active = true) INSERT INTO users SELECT * FROM active_users";
+  /// # assert_eq!(query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// insert into users (login, name) VALUES ('foo', 'Foo')
+  /// WITH active_users AS (select * from users_bk where active = true) INSERT INTO users SELECT * FROM active_users
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_raw(mut self, name: &str, sql: &str) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Raw(sql.trim().to_string())));
     self
   }
 
-  /// Adds a raw SQL query after a specified clause.
+  /// The `with` clause marked as `recursive`, the query can reference itself by the `name`. When any query is
+  /// added by this method the whole `with` clause is rendered as `with recursive`
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
   /// # use sql_query_builder as sql;
-  /// let raw = "values ('foo', 'Foo')";
+  /// let base = sql::Select::new().select("1 as n");
+  /// let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
   ///
-  /// let insert_query = sql::Insert::new()
-  ///   .insert_into("users (login, name)")
-  ///   .raw_after(sql::InsertClause::InsertInto, raw)
+  /// let query = sql::Insert::new()
+  ///   .with_recursive("t", sql::Select::recursive(base, step))
+  ///   .insert_into("numbers (n)")
+  ///   .select(sql::Select::new().select("n").from("t"))
   ///   .as_string();
   ///
-  /// # let expected = "INSERT INTO users (login, name) values ('foo', 'Foo')";
-  /// # assert_eq!(insert_query, expected);
+  /// # let expected = "\
+  /// #   WITH RECURSIVE t AS (\
+  /// #     SELECT 1 as n \
+  /// #     UNION ALL \
+  /// #     SELECT n + 1 FROM t WHERE n < 10\
+  /// #   ) \
+  /// #   INSERT INTO numbers (n) SELECT n FROM t\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users (login, name) values ('foo', 'Foo')
+  /// WITH RECURSIVE t AS (
+  ///   SELECT 1 as n
+  ///   UNION ALL
+  ///   SELECT n + 1 FROM t WHERE n < 10
+  /// )
+  /// INSERT INTO numbers (n) SELECT n FROM t
   /// ```
-  pub fn raw_after(mut self, clause: InsertClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_recursive(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self._with_recursive = true;
     self
   }
+}
 
-  /// Adds a raw SQL query before a specified clause.
+#[cfg(any(doc, feature = "mysql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+impl Insert {
+  /// The `insert ignore into` clause, the rows that would cause a duplicate key error are discarded.
+  /// This method overrides the previous value of the [insert_into](Insert::insert_into) and
+  /// [replace_into](Insert::replace_into) methods
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
   /// # use sql_query_builder as sql;
-  /// let raw = "insert into users (login, name)";
-  ///
-  /// let insert_query = sql::Insert::new()
-  ///   .raw_before(sql::InsertClause::Values, raw)
-  ///   .values("('bar', 'Bar')")
+  /// let query = sql::Insert::new()
+  ///   .insert_ignore("users (login, name)")
+  ///   .values("('foo', 'Foo')")
   ///   .as_string();
   ///
-  /// # let expected = "insert into users (login, name) VALUES ('bar', 'Bar')";
-  /// # assert_eq!(insert_query, expected);
+  /// # let expected = "INSERT IGNORE INTO users (login, name) VALUES ('foo', 'Foo')";
+  /// # assert_eq!(query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// insert into users (login, name) VALUES ('bar', 'Bar')
+  /// INSERT IGNORE INTO users (login, name) VALUES ('foo', 'Foo')
   /// ```
-  pub fn raw_before(mut self, clause: InsertClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn insert_ignore(mut self, expression: &str) -> Self {
+    self._insert = (InsertVars::InsertIgnore, InsertTarget::from(expression));
     self
   }
 
-  /// The `values` clause
+  /// The `on duplicate key update` clause, consecutive calls accumulates the assignments.
+  /// When this clause is defined the [on_conflict](Insert::on_conflict) clause is not rendered
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let query = sql::Insert::new()
   ///   .insert_into("users (login, name)")
   ///   .values("('foo', 'Foo')")
-  ///   .values("('bar', 'Bar')")
+  ///   .on_duplicate_key_update("login = VALUES(login)")
+  ///   .on_duplicate_key_update("name = VALUES(name)")
   ///   .as_string();
   ///
-  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # let expected = "\
+  /// #   INSERT INTO users (login, name) \
+  /// #   VALUES ('foo', 'Foo') \
+  /// #   ON DUPLICATE KEY UPDATE login = VALUES(login), name = VALUES(name)\
+  /// # ";
   /// # assert_eq!(query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo')
+  /// ON DUPLICATE KEY UPDATE login = VALUES(login), name = VALUES(name)
   /// ```
-  pub fn values(mut self, value: &str) -> Self {
-    push_unique(&mut self._values, value.trim().to_string());
+  pub fn on_duplicate_key_update(mut self, assignment: &str) -> Self {
+    let assignment = assignment.trim();
+    if assignment.is_empty() == false {
+      push_unique(&mut self._on_duplicate_key_update, assignment.to_string());
+    }
     self
   }
-}
 
-#[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::behavior::WithQuery;
+  /// The `replace into` clause, the rows with the same primary or unique key are deleted before the new rows
+  /// are inserted. This method overrides the previous value of the [insert_into](Insert::insert_into) and
+  /// [insert_ignore](Insert::insert_ignore) methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .replace_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "REPLACE INTO users (login, name) VALUES ('foo', 'Foo')";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// REPLACE INTO users (login, name) VALUES ('foo', 'Foo')
+  /// ```
+  #[cfg(not(any(doc, feature = "sqlite")))]
+  pub fn replace_into(mut self, expression: &str) -> Self {
+    self._insert = (InsertVars::ReplaceInto, InsertTarget::from(expression));
+    self
+  }
+}
 
-#[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl WithQuery for Insert {}
+#[cfg(feature = "postgresql")]
+use crate::param_value::number_placeholders;
+#[cfg(any(doc, feature = "postgresql"))]
+use crate::structure::ParamValue;
 
-#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+#[cfg(any(doc, feature = "postgresql"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
-#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Insert {
-  /// The `returning` clause
+  /// Binds a value to the next `?` placeholder of the query. The placeholders are rewritten to the numbered
+  /// form `$1`, `$2`, ... by the [into_parts](Insert::into_parts) method, the numbering continues across
+  /// the nested builders of the `with` clause and the set operations
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let insert_query = sql::Insert::new()
-  ///   .insert_into("users")
-  ///   .returning("id")
-  ///   .returning("login")
-  ///   .to_string();
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("(?, ?)")
+  ///   .bind("foo")
+  ///   .bind(None::<&str>)
+  ///   .into_parts();
   ///
-  /// # let expected = "INSERT INTO users RETURNING id, login";
-  /// # assert_eq!(insert_query, expected);
+  /// # assert_eq!(query, "INSERT INTO users (login, name) VALUES ($1, $2)");
+  /// # assert_eq!(params, vec![sql::ParamValue::Text("foo".to_string()), sql::ParamValue::Null]);
   /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INSERT INTO users RETURNING id, login
+  /// INSERT INTO users (login, name) VALUES ($1, $2)
   /// ```
-  pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+  pub fn bind(mut self, value: impl Into<ParamValue>) -> Self {
+    self._params.push(value.into());
     self
   }
 
-  /// The `with` clause
+  /// Prefixes the query with `EXPLAIN (options)`, the options are rendered as is.
+  /// This method overrides the previous value of the [explain](Insert::explain) and
+  /// [explain_analyze](Insert::explain_analyze) methods, an empty options is the same as the `explain` method
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let active_users = sql::Select::new()
-  ///   .select("*")
-  ///   .from("users_bk")
-  ///   .where_clause("ative = true");
-  ///
   /// let insert_query = sql::Insert::new()
-  ///   .with("active_users", active_users)
-  ///   .insert_into("users")
-  ///   .select(sql::Select::new().select("*").from("active_users"))
-  ///   .to_string();
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .explain_with("FORMAT JSON, ANALYZE")
+  ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   WITH active_users AS (\
-  /// #     SELECT * \
-  /// #     FROM users_bk \
-  /// #     WHERE ative = true\
-  /// #   ) \
-  /// #   INSERT INTO users \
-  /// #   SELECT * \
-  /// #   FROM active_users\
-  /// # ";
+  /// # let expected = "EXPLAIN (FORMAT JSON, ANALYZE) INSERT INTO users (login) VALUES ('foo')";
   /// # assert_eq!(insert_query, expected);
   /// # }
   /// ```
@@ -398,35 +1738,44 @@ impl Insert {
   /// Output
   ///
   /// ```sql
-  /// WITH active_users AS (
-  ///   SELECT *
-  ///   FROM users_bk
-  ///   WHERE ative = true
-  /// )
-  /// INSERT INTO users
-  /// SELECT *
-  /// FROM active_users
+  /// EXPLAIN (FORMAT JSON, ANALYZE) INSERT INTO users (login) VALUES ('foo')
   /// ```
-  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  pub fn explain_with(mut self, options: &str) -> Self {
+    let options = options.trim();
+    self._explain = if options.is_empty() {
+      Explain::Plain
+    } else {
+      Explain::Options(options.to_string())
+    };
     self
   }
+
+  /// Returns the query with the `?` placeholders rewritten to the numbered form and the values bound
+  /// with the [bind](Insert::bind) method, including the values of the nested builders in the order of the placeholders.
+  /// The `?` inside single quoted strings, double quoted identifiers and comments are preserved, the `??` escapes
+  /// the `?`, `?|` and `?&` operators of the `jsonb` type and it's rendered as `?`
+  pub fn into_parts(self) -> (String, Vec<ParamValue>) {
+    let query = number_placeholders(&self.as_string());
+    (query, self.bound_params())
+  }
 }
 
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 use crate::structure::InsertVars;
 
-#[cfg(any(doc, feature = "sqlite"))]
-#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 impl Insert {
   /// The `insert into` clause, this method overrides the previous value
   #[cfg(not(doc))]
   pub fn insert_into(mut self, expression: &str) -> Self {
-    self._insert = (InsertVars::InsertInto, expression.trim().to_string());
+    self._insert = (InsertVars::InsertInto, InsertTarget::from(expression));
     self
   }
+}
 
+#[cfg(any(doc, feature = "sqlite"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl Insert {
   /// The `insert or <keyword> into` clause
   ///
   /// # Example
@@ -456,7 +1805,7 @@ impl Insert {
   /// INSERT OR abort into users (login, name)
   /// ```
   pub fn insert_or(mut self, expression: &str) -> Self {
-    self._insert = (InsertVars::InsertOr, expression.trim().to_string());
+    self._insert = (InsertVars::InsertOr, InsertTarget::from(expression));
     self
   }
 
@@ -482,7 +1831,7 @@ impl Insert {
   /// REPLACE INTO users (login, name)
   /// ```
   pub fn replace_into(mut self, expression: &str) -> Self {
-    self._insert = (InsertVars::ReplaceInto, expression.trim().to_string());
+    self._insert = (InsertVars::ReplaceInto, InsertTarget::from(expression));
     self
   }
 }
@@ -496,6 +1845,27 @@ impl std::fmt::Display for Insert {
 impl std::fmt::Debug for Insert {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat_statements(&fmts), self._terminated), &fmts)
+    )
+  }
+}
+
+/// Two builders are equal when they render the same SQL with the [one_line](fmt::one_line) formatter,
+/// the order the methods were called and the values bound with the `bind` method are not compared
+impl PartialEq for Insert {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_string() == other.as_string()
+  }
+}
+
+impl Eq for Insert {}
+
+/// The hash of the rendered SQL, consistent with the [PartialEq] implementation
+impl std::hash::Hash for Insert {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.as_string().hash(state);
   }
 }
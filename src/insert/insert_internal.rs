@@ -1,14 +1,21 @@
 use crate::{
-  concat::{concat_raw_before_after, Concat},
+  concat::{concat_explain, concat_raw_before_after, push_raw_before_after, Concat},
   fmt,
-  structure::{Insert, InsertClause},
+  structure::{Insert, InsertClause, InsertTarget, OnConflict},
 };
 
 impl Concat for Insert {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
+    self.concat_into(fmts, &mut query);
+    query
+  }
+
+  fn concat_into(&self, fmts: &fmt::Formatter, out: &mut String) {
+    let start = out.len();
+    let mut query = std::mem::take(out);
 
-    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_prefix(query, &fmts);
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
@@ -22,22 +29,24 @@ impl Concat for Insert {
       );
     }
 
-    #[cfg(not(feature = "sqlite"))]
-    {
-      query = self.concat_insert_into(query, &fmts);
-    }
-    #[cfg(feature = "sqlite")]
-    {
-      query = ConcatInsert::concat_insert(self, &self._raw_before, &self._raw_after, query, &fmts, &self._insert);
-    }
-
+    query = self.concat_target(query, &fmts);
     query = self.concat_overriding(query, &fmts);
-
     query = self.concat_values(query, &fmts);
 
     query = self.concat_select(query, &fmts);
 
-    query = self.concat_on_conflict(query, &fmts);
+    #[cfg(not(feature = "mysql"))]
+    {
+      query = self.concat_on_conflict(query, &fmts);
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+      if self._on_duplicate_key_update.is_empty() {
+        query = self.concat_on_conflict(query, &fmts);
+      }
+      query = self.concat_on_duplicate_key_update(query, &fmts);
+    }
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
@@ -51,17 +60,82 @@ impl Concat for Insert {
       );
     }
 
-    query.trim_end().to_string()
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.truncate(start.max(query.trim_end().len()));
+    *out = query;
   }
 }
 
 impl Insert {
-  #[cfg(not(feature = "sqlite"))]
+  pub(crate) fn missing_clause(&self) -> Option<&'static str> {
+    if self._raw.is_empty() == false {
+      return None;
+    }
+    if self.insert_target().is_empty() {
+      return Some("insert_into");
+    }
+    if self._values.is_empty() && self._select.is_none() && self._default_values == false {
+      return Some("values");
+    }
+    None
+  }
+
+  pub(crate) fn clone_without_values(&self) -> Self {
+    let mut insert = self.clone();
+    insert._values.clear();
+    insert
+  }
+
+  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+  pub(crate) fn insert_target(&self) -> &InsertTarget {
+    &self._insert_into
+  }
+
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  pub(crate) fn insert_target(&self) -> &InsertTarget {
+    &self._insert.1
+  }
+
+  /// Renders the raw SQL, the comments and the `explain` placed before the clauses of the query
+  fn concat_prefix(&self, mut query: String, fmts: &fmt::Formatter) -> String {
+    query = self.concat_raw(query, fmts, &self._raw_prepend);
+    query = self.concat_raw(query, fmts, &self._comment);
+    query = concat_explain(query, fmts, &self._explain);
+    self.concat_raw(query, fmts, &self._raw)
+  }
+
+  /// Renders the clause that defines the table of the insert, `insert into` or the variant of the dialect
+  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+  fn concat_target(&self, query: String, fmts: &fmt::Formatter) -> String {
+    self.concat_insert_into(query, fmts)
+  }
+
+  /// Renders the clause that defines the table of the insert, `insert into` or the variant of the dialect
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  fn concat_target(&self, query: String, fmts: &fmt::Formatter) -> String {
+    ConcatInsert::concat_insert(self, &self._raw_before, &self._raw_after, query, fmts, &self._insert)
+  }
+
+  /// Renders the insert command followed by the statements of the [append](Insert::append) method separated by `;`
+  /// and a line break regardless of the formatter, used only by the builder rendered at the top level so the
+  /// appended statements are never part of a nested insert
+  pub(crate) fn concat_statements(&self, fmts: &fmt::Formatter) -> String {
+    self._append.iter().fold(self.concat(fmts), |acc, statement| {
+      if acc.is_empty() {
+        statement.to_string()
+      } else {
+        format!("{acc};\n{statement}")
+      }
+    })
+  }
+
+  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
   fn concat_insert_into(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._insert_into.is_empty() == false {
       let insert_into = &self._insert_into;
-      format!("INSERT INTO{space}{insert_into}{space}{lb}")
+      format!("{}{space}{insert_into}{space}{lb}", fmts.kw("INSERT INTO"))
     } else {
       "".to_string()
     };
@@ -80,7 +154,7 @@ impl Insert {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._overriding.is_empty() == false {
       let overriding = &self._overriding;
-      format!("OVERRIDING{space}{overriding}{space}{lb}")
+      format!("{}{space}{overriding}{space}{lb}", fmts.kw("OVERRIDING"))
     } else {
       "".to_string()
     };
@@ -96,12 +170,32 @@ impl Insert {
   }
 
   fn concat_on_conflict(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._on_conflict.is_empty() == false {
-      let overriding = &self._on_conflict;
-      format!("ON CONFLICT{space}{overriding}{space}{lb}")
-    } else {
-      "".to_string()
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let on_conflict = fmts.kw("ON CONFLICT");
+    let sql = match &self._on_conflict {
+      OnConflict::None => "".to_string(),
+      OnConflict::Raw(conflict) => format!("{on_conflict}{space}{conflict}{space}{lb}"),
+      OnConflict::DoNothing(target) => {
+        let target = target.concat(fmts);
+        format!("{on_conflict}{space}{target}{}{space}{lb}", fmts.kw("DO NOTHING"))
+      }
+      OnConflict::DoUpdate(target, set) => {
+        let target = target.concat(fmts);
+        let set = set
+          .iter()
+          .map(|(column, value)| format!("{column} = {}", value.concat(fmts)))
+          .collect::<Vec<_>>()
+          .join(comma);
+        let where_clause = if self._on_conflict_where.is_empty() == false {
+          format!("{}{space}{}{space}{lb}", fmts.kw("WHERE"), self._on_conflict_where)
+        } else {
+          "".to_string()
+        };
+        format!(
+          "{on_conflict}{space}{target}{lb}{}{space}{set}{space}{lb}{where_clause}",
+          fmts.kw("DO UPDATE SET")
+        )
+      }
     };
 
     concat_raw_before_after(
@@ -116,43 +210,90 @@ impl Insert {
 
   fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if let Some(select) = &self._select {
-      let select_string = select.concat(fmts);
-      format!("{select_string}{space}{lb}")
-    } else {
-      "".to_string()
+    let select = match &self._select {
+      Some(select) => select,
+      None => {
+        return concat_raw_before_after(
+          &self._raw_before,
+          &self._raw_after,
+          query,
+          fmts,
+          InsertClause::Select,
+          "".to_string(),
+        )
+      }
     };
 
-    concat_raw_before_after(
+    push_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       InsertClause::Select,
-      sql,
+      |query| {
+        if fmts.paren_subqueries {
+          query.push('(');
+          select.concat_into(fmts, query);
+          query.push(')');
+        } else {
+          select.concat_into(fmts, query);
+        }
+        query.push_str(space);
+        query.push_str(lb);
+      },
     )
   }
 
-  fn concat_values(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { comma, lb, space, .. } = fmts;
+  fn concat_values(&self, mut query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { indent, lb, space, .. } = fmts;
 
-    let (clause, sql) = if self._default_values {
-      (InsertClause::DefaultValues, format!("DEFAULT VALUES{space}{lb}"))
-    } else if self._values.is_empty() == false {
-      let sep = format!("{comma}{lb}");
-      let values = self
-        ._values
-        .iter()
-        .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
-        .collect::<Vec<_>>()
-        .join(&sep);
-      (InsertClause::Values, format!("VALUES{space}{lb}{values}{space}{lb}"))
-    } else {
-      (InsertClause::Values, "".to_string())
-    };
+    if self._default_values {
+      let sql = format!("{}{space}{lb}", fmts.kw("DEFAULT VALUES"));
+      return concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        InsertClause::DefaultValues,
+        sql,
+      );
+    }
+
+    if self._values.is_empty() {
+      return concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        InsertClause::Values,
+        "".to_string(),
+      );
+    }
 
-    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, clause, sql)
+    // the rows are placed one per line aligned by the indent
+    let sep = format!("{}{indent}", fmts.line_comma());
+    let rows_len = self._values.iter().map(|row| row.len() + sep.len()).sum::<usize>();
+    query.reserve(rows_len + 16);
+
+    push_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::Values,
+      |query| {
+        query.push_str(&fmts.kw("VALUES"));
+        query.push_str(space);
+        query.push_str(lb);
+        let rows = self._values.iter().filter(|row| row.is_empty() == false);
+        for (index, row) in rows.enumerate() {
+          query.push_str(if index > 0 { &sep } else { indent });
+          query.push_str(row);
+        }
+        query.push_str(space);
+        query.push_str(lb);
+      },
+    )
   }
 }
 
@@ -162,10 +303,120 @@ use crate::concat::non_standard::{ConcatReturning, ConcatWith};
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl ConcatReturning<InsertClause> for Insert {}
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl ConcatWith<InsertClause> for Insert {}
+impl ConcatWith<InsertClause> for Insert {
+  fn is_recursive(&self) -> bool {
+    self._with_recursive
+  }
+}
 
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 use crate::concat::sqlite::ConcatInsert;
 
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 impl ConcatInsert for Insert {}
+
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
+impl Insert {
+  /// Removes the insert clause only when it was defined by the method of the `vars` variant
+  pub(crate) fn clear_insert(&mut self, vars: crate::structure::InsertVars) {
+    if self._insert.0 == vars {
+      self._insert = Default::default();
+    }
+  }
+}
+
+impl InsertTarget {
+  /// Compares the column names ignoring the case and the double quotes
+  pub(crate) fn has_column(&self, name: &str) -> bool {
+    let name = name.trim().trim_matches('"');
+    self
+      .columns
+      .trim()
+      .trim_start_matches('(')
+      .trim_end_matches(')')
+      .split(',')
+      .map(|column| column.trim().trim_matches('"'))
+      .any(|column| column.eq_ignore_ascii_case(name))
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.table.is_empty() && self.columns.is_empty()
+  }
+}
+
+impl From<&str> for InsertTarget {
+  fn from(expression: &str) -> Self {
+    let expression = expression.trim();
+    let (table, columns) = match expression.find('(') {
+      Some(index) => expression.split_at(index),
+      None => (expression, ""),
+    };
+
+    Self {
+      table: table.to_string(),
+      columns: columns.to_string(),
+    }
+  }
+}
+
+impl std::fmt::Display for InsertTarget {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}{}", self.table, self.columns)
+  }
+}
+
+#[cfg(feature = "mysql")]
+impl Insert {
+  fn concat_on_duplicate_key_update(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._on_duplicate_key_update.is_empty() == false {
+      let assignments = self._on_duplicate_key_update.join(comma);
+      format!("{}{space}{assignments}{space}{lb}", fmts.kw("ON DUPLICATE KEY UPDATE"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::OnDuplicateKeyUpdate,
+      sql,
+    )
+  }
+}
+
+#[cfg(feature = "postgresql")]
+impl Insert {
+  /// The values bound to the builder and to the nested builders in the order their placeholders are rendered,
+  /// the values bound to the clauses after the nested select are the remaining ones
+  pub(crate) fn ordered_params(&self) -> Vec<crate::structure::ParamValue> {
+    use crate::{behavior::WithQuery, concat::raw_queries, param_value::OrderedParams};
+
+    let fmts = fmt::one_line();
+    let raw_before = |clause| raw_queries(&self._raw_before, &clause).join(" ");
+    let raw_after = |clause| raw_queries(&self._raw_after, &clause).join(" ");
+    let mut params = OrderedParams::new(&self._params);
+
+    params.own(&self.concat_prefix("".to_string(), &fmts));
+
+    params.own(&raw_before(InsertClause::With));
+    self
+      ._with
+      .iter()
+      .for_each(|(_, query)| params.nested(query.bound_params()));
+    params.own(&raw_after(InsertClause::With));
+
+    params.own(&self.concat_target("".to_string(), &fmts));
+    params.own(&self.concat_overriding("".to_string(), &fmts));
+    params.own(&self.concat_values("".to_string(), &fmts));
+
+    params.own(&raw_before(InsertClause::Select));
+    if let Some(select) = &self._select {
+      params.nested(select.bound_params());
+    }
+
+    params.finish()
+  }
+}
@@ -4,11 +4,19 @@
 mod alter_table;
 mod behavior;
 mod concat;
+mod condition;
+mod conflict_target;
 mod create_table;
+mod create_view;
 mod delete;
 mod drop_table;
-mod fmt;
+mod drop_view;
+mod error;
+pub mod escape;
+pub mod fmt;
+mod from_item;
 mod insert;
+mod on_builder;
 mod select;
 mod structure;
 mod transaction;
@@ -16,15 +24,38 @@ mod update;
 mod utils;
 mod values;
 
+pub use crate::behavior::IntoRow;
 pub use crate::structure::{
-  AlterTable, AlterTableAction, CreateTable, CreateTableParams, Delete, DeleteClause, DropTable, DropTableParams,
-  Insert, InsertClause, Select, SelectClause, Transaction, Update, UpdateClause, Values, ValuesClause,
+  AlterTable, AlterTableAction, BuilderError, Condition, ConflictTarget, CreateTable, CreateTableParams, CreateView,
+  CreateViewParams, Delete, DeleteClause, DropTable, DropTableParams, DropView, DropViewParams, FromItem, Insert,
+  InsertClause, NullsOrder, OnBuilder, Order, Select, SelectClause, Transaction, Update, UpdateClause, Values,
+  ValuesClause,
 };
 
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod compound_select;
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod create_index;
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod drop_index;
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-pub use crate::structure::{CreateIndex, CreateIndexParams, DropIndex, DropIndexParams};
+pub use crate::structure::{
+  CompoundSelect, CompoundSelectClause, CreateIndex, CreateIndexParams, DropIndex, DropIndexParams,
+};
+
+#[cfg(feature = "postgresql")]
+mod merge;
+#[cfg(feature = "postgresql")]
+mod param_value;
+#[cfg(feature = "postgresql")]
+mod truncate;
+
+#[cfg(feature = "postgresql")]
+pub use crate::structure::{Merge, MergeClause, Truncate, TruncateParams};
+
+#[cfg(any(doc, feature = "postgresql"))]
+pub use crate::structure::ParamValue;
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod with_query;
@@ -0,0 +1,445 @@
+use crate::{
+  behavior::TransactionQuery,
+  concat::Concat,
+  fmt,
+  structure::{Merge, MergeClause, MergeWhen},
+  utils::{push_unique, terminate},
+};
+
+impl TransactionQuery for Merge {}
+
+impl Merge {
+  /// Gets the current state of the [Merge] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .using("new_users n")
+  ///   .on("u.login = n.login")
+  ///   .when_matched("DO NOTHING")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DO NOTHING";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DO NOTHING
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Prints the current state of the [Merge] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .using("new_users n")
+  ///   .on("u.login = n.login")
+  ///   .debug()
+  ///   .when_matched("DELETE")
+  ///   .as_string();
+  /// # }
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// MERGE INTO users u
+  /// USING new_users n
+  /// ON u.login = n.login
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// The `merge into` clause, defines the target table. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("orders")
+  ///   .merge_into("users u")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u
+  /// ```
+  pub fn merge_into(mut self, table: &str) -> Self {
+    self._merge_into = table.trim().to_string();
+    self
+  }
+
+  /// Creates instance of the [Merge] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The `on` clause, defines the join condition between the target and the source. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .using("new_users n")
+  ///   .on("u.login = n.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "USING new_users n ON u.login = n.login";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// USING new_users n ON u.login = n.login
+  /// ```
+  pub fn on(mut self, condition: &str) -> Self {
+    self._on = condition.trim().to_string();
+    self
+  }
+
+  /// Prints the current state of the [Merge] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex merge command.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .raw("/* merge command */")
+  ///   .merge_into("users u")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* merge command */ MERGE INTO users u";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* merge command */ MERGE INTO users u
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// The `MergeClause::When` works both to `.when_matched` and `.when_not_matched` methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .raw_after(sql::MergeClause::MergeInto, "USING new_users n")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u USING new_users n";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u USING new_users n
+  /// ```
+  pub fn raw_after(mut self, clause: MergeClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .raw_append("/* end of the merge command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u /* end of the merge command */";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u /* end of the merge command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// The `MergeClause::When` works both to `.when_matched` and `.when_not_matched` methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .raw_before(sql::MergeClause::Using, "MERGE INTO users u")
+  ///   .using("new_users n")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u USING new_users n";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u USING new_users n
+  /// ```
+  pub fn raw_before(mut self, clause: MergeClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](Merge::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .raw_prepend("/* merge command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* merge command */ MERGE INTO users u";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* merge command */ MERGE INTO users u
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .using("new_users n")
+  ///   .on("u.login = n.login")
+  ///   .when_matched("DELETE")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DELETE;";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DELETE;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// The `using` clause, defines the source table or subquery. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .using("(SELECT login, name FROM new_users) n")
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO users u USING (SELECT login, name FROM new_users) n";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users u USING (SELECT login, name FROM new_users) n
+  /// ```
+  pub fn using(mut self, source: &str) -> Self {
+    self._using = source.trim().to_string();
+    self
+  }
+
+  /// The `when matched then` clause, the argument is the action of the rows found in the target. Consecutive calls
+  /// of this method and the [when_not_matched](Merge::when_not_matched) method are rendered in the order of the calls
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .when_matched("UPDATE SET name = n.name")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHEN MATCHED THEN UPDATE SET name = n.name";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHEN MATCHED THEN UPDATE SET name = n.name
+  /// ```
+  pub fn when_matched(mut self, action: &str) -> Self {
+    self.push_when(MergeWhen::Matched, action);
+    self
+  }
+
+  /// The `when not matched then` clause, the argument is the action of the rows missing in the target. Consecutive calls
+  /// of this method and the [when_matched](Merge::when_matched) method are rendered in the order of the calls
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .when_not_matched("INSERT (login) VALUES (n.login)")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHEN NOT MATCHED THEN INSERT (login) VALUES (n.login)";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHEN NOT MATCHED THEN INSERT (login) VALUES (n.login)
+  /// ```
+  pub fn when_not_matched(mut self, action: &str) -> Self {
+    self.push_when(MergeWhen::NotMatched, action);
+    self
+  }
+
+  fn push_when(&mut self, when: MergeWhen, action: &str) {
+    let action = action.trim();
+    if action.is_empty() == false {
+      push_unique(&mut self._when, (when, action.to_string()));
+    }
+  }
+}
+
+impl std::fmt::Display for Merge {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Merge {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
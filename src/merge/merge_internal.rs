@@ -0,0 +1,97 @@
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{Merge, MergeClause, MergeWhen},
+};
+
+impl Concat for Merge {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_string();
+
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_merge_into(query, &fmts);
+    query = self.concat_using(query, &fmts);
+    query = self.concat_on(query, &fmts);
+    query = self.concat_when(query, &fmts);
+
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.trim_end().to_string()
+  }
+}
+
+impl Merge {
+  fn concat_merge_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._merge_into.is_empty() == false {
+      let target = &self._merge_into;
+      format!("{}{space}{target}{space}{lb}", fmts.kw("MERGE INTO"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      MergeClause::MergeInto,
+      sql,
+    )
+  }
+
+  fn concat_on(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._on.is_empty() == false {
+      let condition = &self._on;
+      format!("{}{space}{condition}{space}{lb}", fmts.kw("ON"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, MergeClause::On, sql)
+  }
+
+  fn concat_using(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._using.is_empty() == false {
+      let source = &self._using;
+      format!("{}{space}{source}{space}{lb}", fmts.kw("USING"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      MergeClause::Using,
+      sql,
+    )
+  }
+
+  fn concat_when(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._when.is_empty() == false {
+      let when = self
+        ._when
+        .iter()
+        .map(|(when, action)| {
+          let keyword = match when {
+            MergeWhen::Matched => fmts.kw("WHEN MATCHED THEN"),
+            MergeWhen::NotMatched => fmts.kw("WHEN NOT MATCHED THEN"),
+          };
+          format!("{keyword}{space}{action}")
+        })
+        .collect::<Vec<_>>()
+        .join(format!("{space}{lb}").as_str());
+      format!("{when}{space}{lb}")
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, MergeClause::When, sql)
+  }
+}
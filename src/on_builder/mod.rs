@@ -0,0 +1,2 @@
+mod on_builder;
+mod on_builder_internal;
@@ -0,0 +1,104 @@
+use crate::{
+  concat::Concat,
+  fmt,
+  structure::{LogicalOperator, OnBuilder},
+};
+
+impl OnBuilder {
+  /// Adds a condition joined with the `and` operator, the first condition of the join don't render the operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let on = sql::OnBuilder::new()
+  ///   .on("orders.user_id = users.id")
+  ///   .and("orders.status = 'paid'")
+  ///   .as_string();
+  ///
+  /// # let expected = "orders.user_id = users.id AND orders.status = 'paid'";
+  /// # assert_eq!(on, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// orders.user_id = users.id AND orders.status = 'paid'
+  /// ```
+  pub fn and(mut self, condition: &str) -> Self {
+    self.push(LogicalOperator::And, condition);
+    self
+  }
+
+  /// Gets the current state of the [OnBuilder] and returns it as string
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// Creates instance of the OnBuilder builder
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a condition of the `on` clause joined with the `and` operator, this method is an alias of the
+  /// [and](OnBuilder::and) method that reads better as the first condition
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let on = sql::OnBuilder::new()
+  ///   .on("addresses.user_id = users.id")
+  ///   .as_string();
+  ///
+  /// # let expected = "addresses.user_id = users.id";
+  /// # assert_eq!(on, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// addresses.user_id = users.id
+  /// ```
+  pub fn on(self, condition: &str) -> Self {
+    self.and(condition)
+  }
+
+  /// Adds a condition joined with the `or` operator, the first condition of the join don't render the operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let on = sql::OnBuilder::new()
+  ///   .on("contacts.email = users.email")
+  ///   .or("contacts.phone = users.phone")
+  ///   .as_string();
+  ///
+  /// # let expected = "contacts.email = users.email OR contacts.phone = users.phone";
+  /// # assert_eq!(on, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// contacts.email = users.email OR contacts.phone = users.phone
+  /// ```
+  pub fn or(mut self, condition: &str) -> Self {
+    self.push(LogicalOperator::Or, condition);
+    self
+  }
+}
+
+impl std::fmt::Display for OnBuilder {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for OnBuilder {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
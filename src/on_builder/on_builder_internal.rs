@@ -0,0 +1,32 @@
+use crate::{
+  concat::Concat,
+  fmt,
+  structure::{LogicalOperator, OnBuilder},
+};
+
+impl Concat for OnBuilder {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { space, .. } = fmts;
+
+    match self._conditions.split_first() {
+      Some(((_, first_condition), tail)) => {
+        tail
+          .iter()
+          .fold(first_condition.to_string(), |acc, (log_op, condition)| {
+            let log_op = fmts.kw(&log_op.to_string());
+            format!("{acc}{space}{log_op}{space}{condition}")
+          })
+      }
+      None => "".to_string(),
+    }
+  }
+}
+
+impl OnBuilder {
+  pub(crate) fn push(&mut self, operator: LogicalOperator, condition: &str) {
+    let condition = condition.trim();
+    if condition.is_empty() == false {
+      self._conditions.push((operator, condition.to_string()));
+    }
+  }
+}
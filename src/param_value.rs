@@ -0,0 +1,98 @@
+use crate::{fmt, structure::ParamValue};
+
+impl From<bool> for ParamValue {
+  fn from(value: bool) -> Self {
+    Self::Bool(value)
+  }
+}
+
+impl From<i16> for ParamValue {
+  fn from(value: i16) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<i32> for ParamValue {
+  fn from(value: i32) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<i64> for ParamValue {
+  fn from(value: i64) -> Self {
+    Self::Int(value)
+  }
+}
+
+impl From<f32> for ParamValue {
+  fn from(value: f32) -> Self {
+    Self::Float(value.into())
+  }
+}
+
+impl From<f64> for ParamValue {
+  fn from(value: f64) -> Self {
+    Self::Float(value)
+  }
+}
+
+impl From<&str> for ParamValue {
+  fn from(value: &str) -> Self {
+    Self::Text(value.to_string())
+  }
+}
+
+impl From<String> for ParamValue {
+  fn from(value: String) -> Self {
+    Self::Text(value)
+  }
+}
+
+impl<T: Into<ParamValue>> From<Option<T>> for ParamValue {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => value.into(),
+      None => Self::Null,
+    }
+  }
+}
+
+/// Rewrites the `?` placeholders to the numbered form `$1`, `$2`, ... in the order they appear in the query,
+/// the `?` inside single quoted strings, double quoted identifiers and comments are preserved
+pub(crate) fn number_placeholders(query: &str) -> String {
+  fmt::placeholders(query, fmt::PlaceholderStyle::Numbered).0
+}
+
+/// Collects the bound values in the order their placeholders are rendered. The values bound to a builder are taken by
+/// counting the `?` placeholders of each part of the query rendered by the builder itself and the values of the nested
+/// builders are added in the position the nested builder is rendered
+pub(crate) struct OrderedParams<'a> {
+  own: std::slice::Iter<'a, ParamValue>,
+  params: Vec<ParamValue>,
+}
+
+impl<'a> OrderedParams<'a> {
+  pub(crate) fn new(own: &'a [ParamValue]) -> Self {
+    Self {
+      own: own.iter(),
+      params: vec![],
+    }
+  }
+
+  /// Takes the values bound to the builder for the placeholders of the SQL rendered by the builder itself
+  pub(crate) fn own(&mut self, sql: &str) {
+    let (_, count) = fmt::placeholders(sql, fmt::PlaceholderStyle::Question);
+    self.params.extend(self.own.by_ref().take(count).cloned());
+  }
+
+  /// Adds the values of a nested builder
+  pub(crate) fn nested(&mut self, params: Vec<ParamValue>) {
+    self.params.extend(params);
+  }
+
+  /// Returns the collected values, the values bound to the builder not taken yet are placed at the end
+  pub(crate) fn finish(mut self) -> Vec<ParamValue> {
+    self.params.extend(self.own.cloned());
+    self.params
+  }
+}
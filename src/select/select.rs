@@ -1,9 +1,12 @@
 use crate::{
-  behavior::TransactionQuery,
+  behavior::{Join, TransactionQuery},
   concat::Concat,
   fmt,
-  structure::{LogicalOperator, Select, SelectClause},
-  utils::push_unique,
+  structure::{
+    BuilderError, ClauseItem, Condition, Distinct, Explain, FromItem, GroupByItem, LogicalOperator, NestedCondition,
+    NullsOrder, OnBuilder, Order, Select, SelectClause, SubqueryCondition,
+  },
+  utils::{block_comment, in_condition, push_unique, remove_raw_of_clause, terminate},
 };
 
 impl TransactionQuery for Select {}
@@ -32,406 +35,1742 @@ impl Select {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
   }
 
-  /// Prints the current state of the [Select] to the standard output in a more ease to read version.
-  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  /// Gets the current state of the [Select] and returns it as string using the [fmt::Formatter] passed as argument
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .select("*")
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
   ///   .from("users")
-  ///   .where_clause("login = foo")
-  ///   .where_clause("active = true")
-  ///   .debug();
-  /// ```
-  ///
-  /// Prints to the standard output
+  ///   .as_string_with(&sql::fmt::multiline_crlf());
   ///
-  /// ```sql
-  /// -- ------------------------------------------------------------------------------
-  /// SELECT *
-  /// FROM users
-  /// WHERE login = foo AND active = true
-  /// -- ------------------------------------------------------------------------------
+  /// # let expected = "SELECT id \r\nFROM users";
+  /// # assert_eq!(select_query, expected);
   /// ```
-  ///
-  /// You can debug different parts of the select putting it in another position
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select_query = sql::Select::new()
-  ///   .select("*")
+  /// let query = sql::Select::new()
+  ///   .select("id")
   ///   .from("users")
-  ///   .debug()
-  ///   .where_clause("login = foo")
-  ///   .where_clause("active = true")
+  ///   .terminated()
   ///   .as_string();
+  ///
+  /// # let expected = "SELECT id FROM users;";
+  /// # assert_eq!(query, expected);
   /// ```
   ///
-  /// Prints to the standard output
+  /// Output
   ///
   /// ```sql
-  /// -- ------------------------------------------------------------------------------
-  /// SELECT *
-  /// FROM users
-  /// -- ------------------------------------------------------------------------------
+  /// SELECT id FROM users;
   /// ```
-  pub fn debug(self) -> Self {
-    let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
     self
   }
 
-  /// The `from` clause
+  /// Gets the current state of the [Select] and returns it as string, returns an error when a required clause is missing,
+  /// a [Select] must define the `select` clause. The builders used with the `raw` method are not verified
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .from("users");
+  /// let result = sql::Select::new()
+  ///   .from("users")
+  ///   .try_as_string();
   ///
-  /// # let expected = "FROM users";
-  /// # assert_eq!(select.as_string(), expected);
+  /// assert_eq!(result, Err(sql::BuilderError::MissingClause("select")));
   /// ```
-  pub fn from(mut self, tables: &str) -> Self {
-    push_unique(&mut self._from, tables.trim().to_string());
-    self
+  pub fn try_as_string(&self) -> Result<String, BuilderError> {
+    match self.missing_clause() {
+      Some(clause) => Err(BuilderError::MissingClause(clause)),
+      None => Ok(self.as_string()),
+    }
   }
 
-  /// The `group by` clause
+  /// Writes the current state of the [Select] into the writer passed as argument using the [fmt::Formatter],
+  /// useful to append many queries into the same buffer. The clauses are written as they are rendered, without
+  /// building the whole query first, except when the query has a set operation or the formatter minifies the query
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .group_by("id");
+  /// let fmts = sql::fmt::one_line();
+  /// let mut script = String::new();
   ///
-  /// # let expected = "GROUP BY id";
-  /// # assert_eq!(select.as_string(), expected);
+  /// sql::Select::new().select("id").from("users").terminated().write_to(&mut script, &fmts).unwrap();
+  /// sql::Select::new().select("id").from("orders").terminated().write_to(&mut script, &fmts).unwrap();
+  ///
+  /// # let expected = "SELECT id FROM users;SELECT id FROM orders;";
+  /// # assert_eq!(script, expected);
   /// ```
-  pub fn group_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._group_by, column.trim().to_string());
-    self
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users;SELECT id FROM orders;
+  /// ```
+  pub fn write_to<W: std::fmt::Write>(&self, w: &mut W, fmts: &fmt::Formatter) -> std::fmt::Result {
+    self.write_clauses(w, fmts)
   }
 
-  /// The `having` clause
+  /// Removes the clause passed as argument and the raw SQL queries added with [raw_before](Select::raw_before)
+  /// and [raw_after](Select::raw_after) bound to it, clearing a clause that was never defined has no effect
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select_query = sql::Select::new()
-  ///   .group_by("status")
-  ///   .having("status != 'disabled'")
-  ///   .as_string();
+  /// let template = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause("active = true");
   ///
-  /// # let expected = "GROUP BY status HAVING status != 'disabled'";
-  /// # assert_eq!(select_query, expected);
+  /// let select = template.clear(sql::SelectClause::Where);
+  ///
+  /// # let expected = "SELECT id, login FROM users";
+  /// # assert_eq!(select.as_string(), expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// GROUP BY status HAVING status != 'disabled'
-  /// ```
-  pub fn having(mut self, condition: &str) -> Self {
-    push_unique(&mut self._having, condition.trim().to_string());
+  /// SELECT id, login FROM users
+  /// ```
+  pub fn clear(mut self, clause: SelectClause) -> Self {
+    match clause {
+      SelectClause::Fetch => self._fetch.clear(),
+      SelectClause::From => {
+        self._from.clear();
+        self._from_item.clear();
+      }
+      SelectClause::GroupBy => self._group_by.clear(),
+      SelectClause::Having => self._having.clear(),
+      SelectClause::Join => self._join.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+      SelectClause::Limit => self._limit.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+      SelectClause::Offset => self._offset.clear(),
+      #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mssql")))]
+      SelectClause::Limit | SelectClause::Offset => {}
+      SelectClause::OffsetRows => self._offset_rows.clear(),
+      SelectClause::OrderBy => self._order_by.clear(),
+      SelectClause::Select => {
+        self._select.clear();
+        self._distinct = Distinct::None;
+      }
+      SelectClause::Where => {
+        self._where.clear();
+        self._where_nested.clear();
+        self._where_join_or = false;
+      }
+      SelectClause::Window => self._window.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Except => self._except.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Intersect => self._intersect.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Union => self._union.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::UnionAll => self._union_all.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::With => {
+        self._with.clear();
+        self._with_recursive = false;
+        #[cfg(feature = "postgresql")]
+        {
+          self._with_cycle.clear();
+          self._with_search.clear();
+        }
+      }
+      #[cfg(feature = "postgresql")]
+      SelectClause::For => {
+        self._for.clear();
+        self._for_wait.clear();
+      }
+    }
+    remove_raw_of_clause(&mut self._raw_before, &clause);
+    remove_raw_of_clause(&mut self._raw_after, &clause);
     self
   }
 
-  /// The `cross join` clause
+  /// Returns the clauses defined on the builder in the order they are rendered, the raw SQL added by the `raw`,
+  /// `raw_before` and `raw_after` methods is not considered a clause
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select_query = sql::Select::new()
+  /// let select = sql::Select::new()
   ///   .from("users")
-  ///   .cross_join("addresses")
-  ///   .as_string();
-  ///
-  /// # let expected = "FROM users CROSS JOIN addresses";
-  /// # assert_eq!(select_query, expected);
-  /// ```
+  ///   .select("id, login")
+  ///   .where_clause("active = true");
   ///
-  /// Output
+  /// let clauses = select.clauses();
   ///
-  /// ```sql
-  /// FROM users CROSS JOIN addresses
+  /// assert!(clauses == vec![sql::SelectClause::Select, sql::SelectClause::From, sql::SelectClause::Where]);
   /// ```
-  pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let join = format!("CROSS JOIN {table}");
-      push_unique(&mut self._join, join);
-    }
-    self
+  pub fn clauses(&self) -> Vec<SelectClause> {
+    Self::all_clauses()
+      .into_iter()
+      .filter(|clause| self.has_clause(clause.clone()))
+      .collect()
   }
 
-  /// The `inner join` clause
+  /// Adds a block comment at the start of the query, useful to trace the code that generated the query
+  /// in the database logs. The comment is rendered in a single line and a `*/` in the text can't close it early
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
+  ///   .comment("users#index")
+  ///   .select("id")
   ///   .from("users")
-  ///   .inner_join("addresses on addresses.user_login = users.login")
+  ///   .comment_on(sql::SelectClause::Where, "only active */ users")
+  ///   .where_clause("active = true")
   ///   .as_string();
   ///
-  /// # let expected = "FROM users INNER JOIN addresses on addresses.user_login = users.login";
+  /// # let expected = "/* users#index */ SELECT id FROM users /* only active * / users */ WHERE active = true";
   /// # assert_eq!(select_query, expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// FROM users INNER JOIN addresses on addresses.user_login = users.login
+  /// /* users#index */ SELECT id FROM users /* only active * / users */ WHERE active = true
   /// ```
-  pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let join = format!("INNER JOIN {table}");
-      push_unique(&mut self._join, join);
+  pub fn comment(mut self, text: &str) -> Self {
+    if text.trim().is_empty() == false {
+      push_unique(&mut self._comment, block_comment(text));
     }
     self
   }
 
-  /// The `left join` clause
+  /// Adds a block comment before the clause passed as argument, the comment is escaped like the
+  /// [comment](Self::comment) method
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
+  ///   .select("id")
   ///   .from("users")
-  ///   .left_join("addresses on addresses.user_login = users.login")
+  ///   .where_clause("active = true")
+  ///   .comment_on(sql::SelectClause::Where, "soft deleted users are kept")
   ///   .as_string();
   ///
-  /// # let expected = "FROM users LEFT JOIN addresses on addresses.user_login = users.login";
+  /// # let expected = "SELECT id FROM users /* soft deleted users are kept */ WHERE active = true";
   /// # assert_eq!(select_query, expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// FROM users LEFT JOIN addresses on addresses.user_login = users.login
+  /// SELECT id FROM users /* soft deleted users are kept */ WHERE active = true
   /// ```
-  pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let join = format!("LEFT JOIN {table}");
-      push_unique(&mut self._join, join);
+  pub fn comment_on(self, clause: SelectClause, text: &str) -> Self {
+    if text.trim().is_empty() {
+      return self;
     }
-    self
+    self.raw_before(clause, &block_comment(text))
   }
 
-  /// The `right join` clause
+  /// Prints the current state of the [Select] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("login = foo")
+  ///   .where_clause("active = true")
+  ///   .debug();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// SELECT *
+  /// FROM users
+  /// WHERE login = foo AND active = true
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  ///
+  /// You can debug different parts of the select putting it in another position
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
+  ///   .select("*")
   ///   .from("users")
-  ///   .right_join("addresses on addresses.user_login = users.login")
+  ///   .debug()
+  ///   .where_clause("login = foo")
+  ///   .where_clause("active = true")
   ///   .as_string();
-  ///
-  /// # let expected = "FROM users RIGHT JOIN addresses on addresses.user_login = users.login";
-  /// # assert_eq!(select_query, expected);
   /// ```
   ///
-  /// Output
+  /// Prints to the standard output
   ///
   /// ```sql
-  /// FROM users RIGHT JOIN addresses on addresses.user_login = users.login
+  /// -- ------------------------------------------------------------------------------
+  /// SELECT *
+  /// FROM users
+  /// -- ------------------------------------------------------------------------------
   /// ```
-  pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let join = format!("RIGHT JOIN {table}");
-      push_unique(&mut self._join, join);
-    }
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
-  /// Creates instance of the Select command
-  pub fn new() -> Self {
-    Self::default()
-  }
-
-  /// The `order by` clause
+  /// The `distinct` keyword of the select clause, rendered once regardless of the number of [select](Select::select)
+  /// calls. This method overrides the [distinct_on](Select::distinct_on) method
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .select("name, login")
-  ///   .order_by("login asc");
+  /// let select_query = sql::Select::new()
+  ///   .distinct()
+  ///   .select("login")
+  ///   .select("email")
+  ///   .from("users")
+  ///   .as_string();
   ///
-  /// # let expected = "SELECT name, login ORDER BY login asc";
-  /// # assert_eq!(select.as_string(), expected);
+  /// # let expected = "SELECT DISTINCT login, email FROM users";
+  /// # assert_eq!(select_query, expected);
   /// ```
-  pub fn order_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._order_by, column.trim().to_string());
-    self
-  }
-
-  /// Prints the current state of the [Select] to the standard output similar to debug method,
-  /// the difference is that this method prints in one line.
-  pub fn print(self) -> Self {
-    let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT DISTINCT login, email FROM users
+  /// ```
+  pub fn distinct(mut self) -> Self {
+    self._distinct = Distinct::Plain;
     self
   }
 
-  /// Adds at the beginning a raw SQL query.
+  /// Prefixes the query with `EXPLAIN` to show its execution plan, the prefix is placed before the `with` clause.
+  /// This method overrides the previous value of the [explain_analyze](Select::explain_analyze) method
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_query = "select * from users";
-  ///
   /// let select_query = sql::Select::new()
-  ///   .raw(raw_query)
-  ///   .where_clause("users.login = 'foo'")
+  ///   .select("id")
+  ///   .from("users")
+  ///   .explain()
   ///   .as_string();
   ///
-  /// # let expected = "select * from users WHERE users.login = 'foo'";
+  /// # let expected = "EXPLAIN SELECT id FROM users";
   /// # assert_eq!(select_query, expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// select * from users WHERE users.login = 'foo'
+  /// EXPLAIN SELECT id FROM users
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn explain(mut self) -> Self {
+    self._explain = Explain::Plain;
     self
   }
 
-  /// Adds a raw SQL query after a specified clause.
+  /// Prefixes the query with `EXPLAIN ANALYZE`, the query is executed to show its actual execution plan.
+  /// This method overrides the previous value of the [explain](Select::explain) method
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_join = "inner join addresses addr on u.login = addr.owner_login";
-  ///
   /// let select_query = sql::Select::new()
-  ///   .from("users u")
-  ///   .raw_after(sql::SelectClause::From, raw_join)
-  ///   .where_clause("u.login = foo")
+  ///   .select("id")
+  ///   .from("users")
+  ///   .explain_analyze()
   ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   FROM users u \
-  /// #   inner join addresses addr on u.login = addr.owner_login \
-  /// #   WHERE u.login = foo\
-  /// # ";
+  /// # let expected = "EXPLAIN ANALYZE SELECT id FROM users";
   /// # assert_eq!(select_query, expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// FROM users u
-  /// inner join addresses addr on u.login = addr.owner_login
-  /// WHERE u.login = foo
+  /// EXPLAIN ANALYZE SELECT id FROM users
   /// ```
-  pub fn raw_after(mut self, clause: SelectClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  pub fn explain_analyze(mut self) -> Self {
+    self._explain = Explain::Analyze;
     self
   }
 
-  /// Adds a raw SQL query before a specified clause.
+  /// The standard `fetch next n rows only` clause, the `count` is the number of rows returned. This method overrides
+  /// the previous value and clears the value of the `limit` clause, so only one of them is rendered
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_query = "from users";
-  ///
   /// let select_query = sql::Select::new()
-  ///   .raw_before(sql::SelectClause::Where, raw_query)
-  ///   .where_clause("users.login = 'foo'")
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .order_by("id")
+  ///   .offset_rows("20")
+  ///   .fetch("10")
   ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   from users \
-  /// #   WHERE users.login = 'foo'\
-  /// # ";
+  /// # let expected = "SELECT id, login FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
   /// # assert_eq!(select_query, expected);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// from users
-  /// WHERE users.login = 'foo'
+  /// SELECT id, login FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY
   /// ```
-  pub fn raw_before(mut self, clause: SelectClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn fetch(mut self, count: &str) -> Self {
+    self._fetch = count.trim().to_string();
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+    self._limit.clear();
     self
   }
 
-  /// The `select` clause
+  /// The `from` clause
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
   /// let select = sql::Select::new()
-  ///   .select("count(id)");
+  ///   .from("users");
   ///
-  /// # let expected = "SELECT count(id)";
+  /// # let expected = "FROM users";
   /// # assert_eq!(select.as_string(), expected);
   /// ```
-  pub fn select(mut self, column: &str) -> Self {
-    push_unique(&mut self._select, column.trim().to_string());
+  pub fn from(mut self, tables: &str) -> Self {
+    push_unique(&mut self._from, tables.trim().to_string());
     self
   }
 
-  /// The method will concatenate multiples calls using the `and` operator. This method is un alias of `where_clause`.
+  /// The `from` clause with an item composed by the [FromItem] builder, see the builder for the available options
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let select_query = sql::Select::new()
-  ///   .from("carts")
-  ///   .where_clause("login = $1")
-  ///   .where_and("session_id = $2")
-  ///   .where_and("created_at >= current_date")
-  ///   .as_string();
+  /// let select = sql::Select::new()
+  ///   .select("m.value")
+  ///   .from_item(sql::FromItem::table("measurements").schema("metrics").only().alias("m"));
   ///
-  /// # let expected = "FROM carts WHERE login = $1 AND session_id = $2 AND created_at >= current_date";
-  /// # assert_eq!(select_query, expected);
+  /// # let expected = "SELECT m.value FROM ONLY metrics.measurements AS m";
+  /// # assert_eq!(select.as_string(), expected);
   /// ```
   ///
-  /// Outputs
+  /// Output
   ///
   /// ```sql
-  /// FROM carts
-  /// WHERE
-  ///   login = $1
-  ///   AND session_id = $2
-  ///   AND created_at >= current_date
+  /// SELECT m.value FROM ONLY metrics.measurements AS m
   /// ```
-  pub fn where_and(self, condition: &str) -> Self {
-    self.where_clause(condition)
+  pub fn from_item(mut self, item: FromItem) -> Self {
+    self.push_from_item(item);
+    self
+  }
+
+  /// The `from` clause with a subquery as a derived table, the select is stored and rendered between parentheses
+  /// followed by the alias. The subquery keeps its position among the tables of the [from](Select::from) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let last_orders = sql::Select::new()
+  ///   .select("user_id, max(created_at) AS created_at")
+  ///   .from("orders")
+  ///   .group_by("user_id");
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("u.login, o.created_at")
+  ///   .from_subquery(last_orders, "o")
+  ///   .inner_join("users u ON u.id = o.user_id");
+  ///
+  /// # let expected = "\
+  /// #   SELECT u.login, o.created_at \
+  /// #   FROM (SELECT user_id, max(created_at) AS created_at FROM orders GROUP BY user_id) AS o \
+  /// #   INNER JOIN users u ON u.id = o.user_id\
+  /// # ";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  ///
+  /// Output (multiline)
+  ///
+  /// ```sql
+  /// SELECT u.login, o.created_at
+  /// FROM (
+  ///   SELECT user_id, max(created_at) AS created_at
+  ///   FROM orders
+  ///   GROUP BY user_id
+  /// ) AS o
+  /// INNER JOIN users u ON u.id = o.user_id
+  /// ```
+  pub fn from_subquery(mut self, subquery: Select, alias: &str) -> Self {
+    let position = self._from.len();
+    self
+      ._from_item
+      .push((position, FromItem::subquery(subquery).alias(alias)));
+    self
+  }
+
+  /// The `group by` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .group_by("id");
+  ///
+  /// # let expected = "GROUP BY id";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  pub fn group_by(mut self, column: &str) -> Self {
+    let column = column.trim();
+    if column.is_empty() == false {
+      push_unique(&mut self._group_by, GroupByItem::Expr(column.to_string()));
+    }
+    self
+  }
+
+  /// The `cube` grouping element of the `group by` clause, the groups are all the combinations of the `columns`.
+  /// Consecutive calls and the [group_by](Select::group_by) method accumulates the items separated by comma
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("brand, size, sum(sales)")
+  ///   .from("items_sold")
+  ///   .group_by_cube("brand, size")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT brand, size, sum(sales) FROM items_sold GROUP BY CUBE (brand, size)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT brand, size, sum(sales) FROM items_sold GROUP BY CUBE (brand, size)
+  /// ```
+  pub fn group_by_cube(mut self, columns: &str) -> Self {
+    let columns = columns.trim();
+    if columns.is_empty() == false {
+      push_unique(&mut self._group_by, GroupByItem::Cube(columns.to_string()));
+    }
+    self
+  }
+
+  /// The `rollup` grouping element of the `group by` clause, the groups are the hierarchy of the `columns`
+  /// from left to right plus the grand total. Consecutive calls and the [group_by](Select::group_by) method
+  /// accumulates the items separated by comma
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("country, city, sum(sales)")
+  ///   .from("sales")
+  ///   .group_by_rollup("country, city")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT country, city, sum(sales) FROM sales GROUP BY ROLLUP (country, city)";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT country, city, sum(sales) FROM sales GROUP BY ROLLUP (country, city)
+  /// ```
+  ///
+  /// # Example `crate features mysql only`
+  ///
+  /// MySQL has no `rollup` grouping element, the columns are rendered in place and the `with rollup` modifier
+  /// is always rendered at the end of the `group by` clause
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("country, city, sum(sales)")
+  ///   .from("sales")
+  ///   .group_by_rollup("country, city")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT country, city, sum(sales) FROM sales GROUP BY country, city WITH ROLLUP";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT country, city, sum(sales) FROM sales GROUP BY country, city WITH ROLLUP
+  /// ```
+  pub fn group_by_rollup(mut self, columns: &str) -> Self {
+    let columns = columns.trim();
+    if columns.is_empty() == false {
+      push_unique(&mut self._group_by, GroupByItem::Rollup(columns.to_string()));
+    }
+    self
+  }
+
+  /// The `grouping sets` element of the `group by` clause, each set of the `sets` argument is a group,
+  /// the empty set `()` is the grand total. Consecutive calls and the [group_by](Select::group_by) method
+  /// accumulates the items separated by comma
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("brand, size, sum(sales)")
+  ///   .from("items_sold")
+  ///   .grouping_sets("(brand), (size), ()")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT brand, size, sum(sales) FROM items_sold GROUP BY GROUPING SETS ((brand), (size), ())";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT brand, size, sum(sales) FROM items_sold GROUP BY GROUPING SETS ((brand), (size), ())
+  /// ```
+  pub fn grouping_sets(mut self, sets: &str) -> Self {
+    let sets = sets.trim();
+    if sets.is_empty() == false {
+      push_unique(&mut self._group_by, GroupByItem::GroupingSets(sets.to_string()));
+    }
+    self
+  }
+
+  /// The `having` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .group_by("status")
+  ///   .having("status != 'disabled'")
+  ///   .as_string();
+  ///
+  /// # let expected = "GROUP BY status HAVING status != 'disabled'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// GROUP BY status HAVING status != 'disabled'
+  /// ```
+  pub fn having(mut self, condition: &str) -> Self {
+    push_unique(&mut self._having, (LogicalOperator::And, condition.trim().to_string()));
+    self
+  }
+
+  /// The `having` clause, this method will concatenate multiples calls using the `and` operator.
+  /// This method is an alias of the [having](Select::having) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .group_by("status")
+  ///   .having("count(*) > 10")
+  ///   .having_and("status != 'disabled'")
+  ///   .as_string();
+  ///
+  /// # let expected = "GROUP BY status HAVING count(*) > 10 AND status != 'disabled'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// GROUP BY status HAVING count(*) > 10 AND status != 'disabled'
+  /// ```
+  pub fn having_and(self, condition: &str) -> Self {
+    self.having(condition)
+  }
+
+  /// The `having` clause, this method will concatenate the condition using the `or` operator.
+  /// Like the [where_or](Select::where_or) method the conditions aren't wrapped in parentheses, so the `and`
+  /// operator takes precedence, use an explicit group like `(a OR b)` to change it
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .group_by("status")
+  ///   .having("count(*) > 10")
+  ///   .having_or("max(amount) > 1000")
+  ///   .as_string();
+  ///
+  /// # let expected = "GROUP BY status HAVING count(*) > 10 OR max(amount) > 1000";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// GROUP BY status HAVING count(*) > 10 OR max(amount) > 1000
+  /// ```
+  pub fn having_or(mut self, condition: &str) -> Self {
+    push_unique(&mut self._having, (LogicalOperator::Or, condition.trim().to_string()));
+    self
+  }
+
+  /// Returns true when the clause passed as argument is defined on the builder, useful to inspect the builder
+  /// without rendering it
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users");
+  ///
+  /// assert_eq!(select.has_clause(sql::SelectClause::From), true);
+  /// assert_eq!(select.has_clause(sql::SelectClause::Where), false);
+  /// ```
+  pub fn has_clause(&self, clause: SelectClause) -> bool {
+    let has_items = |items: &Vec<String>| items.iter().any(|item| item.is_empty() == false);
+    let has_clause_items = |items: &Vec<ClauseItem>| items.iter().any(|item| item.is_empty() == false);
+
+    match clause {
+      SelectClause::Fetch => self._fetch.is_empty() == false,
+      SelectClause::From => has_items(&self._from) || self._from_item.is_empty() == false,
+      SelectClause::GroupBy => self._group_by.is_empty() == false,
+      SelectClause::Having => self._having.iter().any(|(_, condition)| condition.is_empty() == false),
+      SelectClause::Join => has_clause_items(&self._join),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+      SelectClause::Limit => self._limit.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+      SelectClause::Offset => self._offset.is_empty() == false,
+      #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mssql")))]
+      SelectClause::Limit | SelectClause::Offset => false,
+      SelectClause::OffsetRows => self._offset_rows.is_empty() == false,
+      SelectClause::OrderBy => has_clause_items(&self._order_by),
+      SelectClause::Select => has_clause_items(&self._select),
+      SelectClause::Where => {
+        self._where.iter().any(|(_, condition)| condition.is_empty() == false) || self._where_nested.is_empty() == false
+      }
+      SelectClause::Window => self._window.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Except => self._except.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Intersect => self._intersect.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Union => self._union.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::UnionAll => self._union_all.is_empty() == false,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::With => self._with.is_empty() == false,
+      #[cfg(feature = "postgresql")]
+      SelectClause::For => self._for.is_empty() == false,
+    }
+  }
+
+  /// The `cross join` clause, a cross join has no join condition so an `on` condition passed as argument is not rendered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .cross_join("addresses")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users CROSS JOIN addresses";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users CROSS JOIN addresses
+  /// ```
+  pub fn cross_join(mut self, table: &str) -> Self {
+    self.push_cross_join(table);
+    self
+  }
+
+  /// The `full outer join` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .full_join("addresses ON addresses.user_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users FULL OUTER JOIN addresses ON addresses.user_login = users.login";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users FULL OUTER JOIN addresses ON addresses.user_login = users.login
+  /// ```
+  pub fn full_join(mut self, table: &str) -> Self {
+    self.push_join("FULL OUTER JOIN", table);
+    self
+  }
+
+  /// The `inner join` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .inner_join("addresses on addresses.user_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users INNER JOIN addresses on addresses.user_login = users.login";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users INNER JOIN addresses on addresses.user_login = users.login
+  /// ```
+  pub fn inner_join(mut self, table: &str) -> Self {
+    self.push_join("INNER JOIN", table);
+    self
+  }
+
+  /// The `inner join` clause with the conditions of the `on` clause built by the closure, the join is rendered
+  /// in the order of the calls together with the other joins. An `inner join` requires the `on` clause, so the join
+  /// is not added when the closure doesn't add any condition
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .inner_join_on("orders", |on| {
+  ///     on.on("orders.user_id = users.id").and("orders.status = 'paid'")
+  ///   })
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users INNER JOIN orders ON orders.user_id = users.id AND orders.status = 'paid'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users INNER JOIN orders ON orders.user_id = users.id AND orders.status = 'paid'
+  /// ```
+  pub fn inner_join_on(mut self, table: &str, f: impl FnOnce(OnBuilder) -> OnBuilder) -> Self {
+    let table = table.trim();
+    if table.is_empty() == false {
+      let conditions = f(OnBuilder::new()).as_string();
+      if conditions.is_empty() == false {
+        let join = ClauseItem::default()
+          .keyword("INNER JOIN")
+          .expr(&format!(" {table} "))
+          .keyword("ON")
+          .expr(&format!(" {conditions}"));
+        push_unique(&mut self._join, join);
+      }
+    }
+    self
+  }
+
+  /// The `inner join lateral` clause, the subquery of a lateral join can reference the columns of the preceding
+  /// items of the `from` clause. The lateral joins are rendered in the order of the calls together with the other joins
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .inner_join_lateral("(SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users INNER JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users INNER JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true
+  /// ```
+  pub fn inner_join_lateral(mut self, expr: &str) -> Self {
+    self.push_join("INNER JOIN LATERAL", expr);
+    self
+  }
+
+  /// The `left join` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .left_join("addresses on addresses.user_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users LEFT JOIN addresses on addresses.user_login = users.login";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users LEFT JOIN addresses on addresses.user_login = users.login
+  /// ```
+  pub fn left_join(mut self, table: &str) -> Self {
+    self.push_join("LEFT JOIN", table);
+    self
+  }
+  /// The `left join lateral` clause, the subquery of a lateral join can reference the columns of the preceding
+  /// items of the `from` clause. The lateral joins are rendered in the order of the calls together with the other joins
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .left_join_lateral("(SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users LEFT JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users LEFT JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id LIMIT 1) o ON true
+  /// ```
+  pub fn left_join_lateral(mut self, expr: &str) -> Self {
+    self.push_join("LEFT JOIN LATERAL", expr);
+    self
+  }
+
+  /// The `right join` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .right_join("addresses on addresses.user_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users RIGHT JOIN addresses on addresses.user_login = users.login";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users RIGHT JOIN addresses on addresses.user_login = users.login
+  /// ```
+  pub fn right_join(mut self, table: &str) -> Self {
+    self.push_join("RIGHT JOIN", table);
+    self
+  }
+
+  /// Creates instance of the Select command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The standard `offset n rows` clause, used together with the [fetch](Select::fetch) method. This method overrides
+  /// the previous value and clears the value of the `offset` clause, so only one of them is rendered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .order_by("id")
+  ///   .offset_rows("1000")
+  ///   .offset_rows("1500");
+  ///
+  /// # let expected = "ORDER BY id OFFSET 1500 ROWS";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ORDER BY id OFFSET 1500 ROWS
+  /// ```
+  pub fn offset_rows(mut self, start: &str) -> Self {
+    self._offset_rows = start.trim().to_string();
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+    self._offset.clear();
+    self
+  }
+
+  /// The `order by` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("name, login")
+  ///   .order_by("login asc");
+  ///
+  /// # let expected = "SELECT name, login ORDER BY login asc";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  pub fn order_by(mut self, column: &str) -> Self {
+    self.push_order_by(ClauseItem::default().expr(column.trim()));
+    self
+  }
+
+  /// Deduplicates the columns of the `order by` clause ignoring the ASCII case, so `order_by("ID")` and
+  /// `order_by("id")` render a single column. The columns are compared as is, the whitespaces are not normalized
+  /// and the quoted identifiers are compared exactly, `"Login"` and `"login"` are different columns.
+  /// The first column added is the one kept, the duplicates already added are removed
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .order_by("Login")
+  ///   .order_by_ignore_case()
+  ///   .order_by("login")
+  ///   .order_by("id");
+  ///
+  /// # let expected = "ORDER BY Login, id";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ORDER BY Login, id
+  /// ```
+  pub fn order_by_ignore_case(mut self) -> Self {
+    self._order_by_ignore_case = true;
+    let columns = std::mem::take(&mut self._order_by);
+    for column in columns {
+      self.push_order_by(column);
+    }
+    self
+  }
+
+  /// The `order by` clause with the column sorted in ascending order, consecutive calls accumulates columns
+  /// like the [order_by](Select::order_by) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("name, login")
+  ///   .order_by_asc("login");
+  ///
+  /// # let expected = "SELECT name, login ORDER BY login ASC";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  pub fn order_by_asc(self, column: &str) -> Self {
+    self.order_by_with(column, Order::Asc, NullsOrder::Default)
+  }
+
+  /// The `order by` clause with the column sorted in descending order, consecutive calls accumulates columns
+  /// like the [order_by](Select::order_by) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("name, login")
+  ///   .order_by_desc("created_at");
+  ///
+  /// # let expected = "SELECT name, login ORDER BY created_at DESC";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  pub fn order_by_desc(self, column: &str) -> Self {
+    self.order_by_with(column, Order::Desc, NullsOrder::Default)
+  }
+
+  /// The `order by` clause with the column sorted by the [Order] direction and the null values placed by the
+  /// [NullsOrder], consecutive calls accumulates columns like the [order_by](Select::order_by) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("name, login")
+  ///   .order_by_with("last_login", sql::Order::Desc, sql::NullsOrder::First)
+  ///   .order_by_asc("login");
+  ///
+  /// # let expected = "SELECT name, login ORDER BY last_login DESC NULLS FIRST, login ASC";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT name, login ORDER BY last_login DESC NULLS FIRST, login ASC
+  /// ```
+  pub fn order_by_with(mut self, column: &str, order: Order, nulls: NullsOrder) -> Self {
+    let column = column.trim();
+    if column.is_empty() {
+      return self;
+    }
+
+    let order = match order {
+      Order::Asc => "ASC",
+      Order::Desc => "DESC",
+    };
+    let column = ClauseItem::default().expr(&format!("{column} ")).keyword(order);
+    let column = match nulls {
+      NullsOrder::First => column.expr(" ").keyword("NULLS FIRST"),
+      NullsOrder::Last => column.expr(" ").keyword("NULLS LAST"),
+      NullsOrder::Default => column,
+    };
+
+    self.push_order_by(column);
+    self
+  }
+
+  /// Renders the query using the [fmt::Formatter] passed as argument and rewrites the `?` placeholders
+  /// to the [fmt::PlaceholderStyle] of the formatter, returns the query and the number of placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::fmt::PlaceholderStyle;
+  ///
+  /// let fmts = sql::fmt::one_line().with_placeholder_style(PlaceholderStyle::Named);
+  /// let (query, placeholders) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("login = ?")
+  ///   .where_clause("status = ?")
+  ///   .prepare(&fmts);
+  ///
+  /// # let expected = "SELECT id FROM users WHERE login = :p1 AND status = :p2";
+  /// # assert_eq!(query, expected);
+  /// # assert_eq!(placeholders, 2);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users WHERE login = :p1 AND status = :p2
+  /// ```
+  pub fn prepare(&self, fmts: &fmt::Formatter) -> (String, usize) {
+    let query = self.as_string_with(&fmt::Formatter {
+      placeholder_style: fmt::PlaceholderStyle::Question,
+      ..*fmts
+    });
+    fmt::placeholders(&query, fmts.placeholder_style)
+  }
+
+  /// Prints the current state of the [Select] to the standard output like the debug method, wrapping the lines
+  /// longer than `width` after the commas of the lists. The wrap only affects the printed output, the `as_string`
+  /// method is not affected
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id, login, name, email, created_at")
+  ///   .from("users")
+  ///   .pretty(25)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// SELECT id, login, name,
+  ///   email, created_at
+  /// FROM users
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn pretty(self, width: usize) -> Self {
+    let fmts = fmt::multiline();
+    let query = fmt::wrap(&terminate(self.concat(&fmts), self._terminated), width, &fmts);
+    println!("{}", fmt::format(query, &fmts));
+    self
+  }
+
+  /// Prints the current state of the [Select] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query, after the comment and the explain prefix.
+  /// Use the [raw_prepend](Select::raw_prepend) method to add it at the very start
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "select * from users";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .raw(raw_query)
+  ///   .where_clause("users.login = 'foo'")
+  ///   .as_string();
+  ///
+  /// # let expected = "select * from users WHERE users.login = 'foo'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// select * from users WHERE users.login = 'foo'
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_join = "inner join addresses addr on u.login = addr.owner_login";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .from("users u")
+  ///   .raw_after(sql::SelectClause::From, raw_join)
+  ///   .where_clause("u.login = foo")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   FROM users u \
+  /// #   inner join addresses addr on u.login = addr.owner_login \
+  /// #   WHERE u.login = foo\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users u
+  /// inner join addresses addr on u.login = addr.owner_login
+  /// WHERE u.login = foo
+  /// ```
+  pub fn raw_after(mut self, clause: SelectClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .raw_append("/* end of the select command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id FROM users /* end of the select command */";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users /* end of the select command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "from users";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .raw_before(sql::SelectClause::Where, raw_query)
+  ///   .where_clause("users.login = 'foo'")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   from users \
+  /// #   WHERE users.login = 'foo'\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// from users
+  /// WHERE users.login = 'foo'
+  /// ```
+  pub fn raw_before(mut self, clause: SelectClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the comment and the explain prefix,
+  /// unlike the [raw](Select::raw) method that is rendered after them.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .raw_prepend("/* select command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* select command */ SELECT id FROM users";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* select command */ SELECT id FROM users
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Renders only the clause passed as argument using the [fmt::Formatter], including the raw SQL added with
+  /// the [raw_before](Select::raw_before) and [raw_after](Select::raw_after) methods bound to it.
+  /// Useful to splice a fragment of the builder in another query, an empty string is returned when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("active = true")
+  ///   .where_clause("login = $1");
+  ///
+  /// let where_clause = select.render_clause(sql::SelectClause::Where, &sql::fmt::one_line());
+  ///
+  /// # let expected = "WHERE active = true AND login = $1";
+  /// # assert_eq!(where_clause, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE active = true AND login = $1
+  /// ```
+  pub fn render_clause(&self, clause: SelectClause, fmts: &fmt::Formatter) -> String {
+    let mut select = Self::all_clauses()
+      .into_iter()
+      .filter(|other| *other != clause)
+      .fold(self.clone(), |select, other| select.clear(other));
+    select._comment.clear();
+    select._explain = Explain::None;
+    select._raw.clear();
+    select._raw_append.clear();
+    select._raw_prepend.clear();
+
+    select.concat(fmts)
+  }
+
+  /// The `select` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("count(id)");
+  ///
+  /// # let expected = "SELECT count(id)";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  pub fn select(mut self, column: &str) -> Self {
+    push_unique(&mut self._select, ClauseItem::default().expr(column.trim()));
+    self
+  }
+
+  /// Adds to the `select` clause the expression with the output name, the alias is omitted when empty.
+  /// Consecutive calls accumulates columns like the [select](Select::select) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .select_as("count(orders.id)", "total_orders")
+  ///   .from("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, count(orders.id) AS total_orders FROM users";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, count(orders.id) AS total_orders FROM users
+  /// ```
+  pub fn select_as(mut self, expr: &str, alias: &str) -> Self {
+    let expr = expr.trim();
+    if expr.is_empty() {
+      return self;
+    }
+
+    let alias = alias.trim();
+    let column = if alias.is_empty() {
+      ClauseItem::default().expr(expr)
+    } else {
+      ClauseItem::default()
+        .expr(&format!("{expr} "))
+        .keyword("AS")
+        .expr(&format!(" {alias}"))
+    };
+
+    push_unique(&mut self._select, column);
+    self
+  }
+
+  /// Adds to the `select` clause a `coalesce` function of the expressions, aliased when the alias is not empty
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .select_coalesce(&["nickname", "name", "'anonymous'"], "display_name")
+  ///   .from("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, COALESCE(nickname, name, 'anonymous') AS display_name FROM users";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, COALESCE(nickname, name, 'anonymous') AS display_name FROM users
+  /// ```
+  pub fn select_coalesce(mut self, exprs: &[&str], alias: &str) -> Self {
+    let args = exprs
+      .iter()
+      .map(|expr| expr.trim())
+      .filter(|expr| expr.is_empty() == false)
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    if args.is_empty() {
+      return self;
+    }
+
+    let alias = alias.trim();
+    let column = ClauseItem::default().keyword("COALESCE").expr(&format!("({args})"));
+    let column = if alias.is_empty() {
+      column
+    } else {
+      column.expr(" ").keyword("AS").expr(&format!(" {alias}"))
+    };
+
+    push_unique(&mut self._select, column);
+    self
+  }
+
+  /// Adds to the `select` clause a `count(*)` window function, useful for running totals. The `partition by`
+  /// and `order by` sub-clauses are omitted when empty, and the alias is omitted when empty
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .select_count_over("customer_id", "created_at", "orders_so_far")
+  ///   .from("orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT id, count(*) OVER (PARTITION BY customer_id ORDER BY created_at) AS orders_so_far \
+  /// #   FROM orders\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, count(*) OVER (PARTITION BY customer_id ORDER BY created_at) AS orders_so_far FROM orders
+  /// ```
+  pub fn select_count_over(mut self, partition: &str, order: &str, alias: &str) -> Self {
+    let partition = partition.trim();
+    let order = order.trim();
+    let alias = alias.trim();
+
+    let mut column = ClauseItem::default().expr("count(*) ").keyword("OVER").expr(" (");
+    if partition.is_empty() == false {
+      column = column.keyword("PARTITION BY").expr(&format!(" {partition}"));
+    }
+    if order.is_empty() == false {
+      if partition.is_empty() == false {
+        column = column.expr(" ");
+      }
+      column = column.keyword("ORDER BY").expr(&format!(" {order}"));
+    }
+    column = column.expr(")");
+    if alias.is_empty() == false {
+      column = column.expr(" ").keyword("AS").expr(&format!(" {alias}"));
+    }
+
+    push_unique(&mut self._select, column);
+    self
+  }
+
+  /// Runs advisory checks over the current state of the [Select] and returns the warnings found, an empty list
+  /// means no issue was found. The rendered query is not affected by the checks. See the list of checks
+  ///
+  /// - a `distinct` projection together with the `group by` clause is usually redundant or a logic error
+  /// - the expressions of the `distinct on` must match the leftmost expressions of the `order by` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("DISTINCT status")
+  ///   .from("orders")
+  ///   .group_by("status");
+  ///
+  /// let warnings = select.validate();
+  ///
+  /// # assert_eq!(warnings.len(), 1);
+  /// assert_eq!(warnings[0], "distinct is redundant with the group by clause");
+  /// ```
+  pub fn validate(&self) -> Vec<String> {
+    let mut warnings = vec![];
+
+    if self.has_distinct() && self._group_by.is_empty() == false {
+      warnings.push("distinct is redundant with the group by clause".to_string());
+    }
+
+    if self.distinct_on_matches_order_by() == false {
+      warnings.push("distinct on expressions must match the leftmost order by expressions".to_string());
+    }
+
+    warnings
+  }
+
+  /// The quantified comparison `column operator ALL (subquery)` of the `where` clause, the select passed as argument
+  /// is stored and rendered as the subquery. This method will concatenate multiples calls using the `and` operator,
+  /// or the `or` operator when the [where_join_or](Select::where_join_or) method is used
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let all_orders = sql::Select::new()
+  ///   .select("total")
+  ///   .from("orders")
+  ///   .where_clause("status = 'paid'");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("name")
+  ///   .from("products")
+  ///   .where_all("price", ">", all_orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT name FROM products WHERE price > ALL (SELECT total FROM orders WHERE status = 'paid')";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output (multiline)
+  ///
+  /// ```sql
+  /// SELECT name
+  /// FROM products
+  /// WHERE
+  ///   price > ALL (
+  ///     SELECT total
+  ///     FROM orders
+  ///     WHERE
+  ///       status = 'paid'
+  ///   )
+  /// ```
+  pub fn where_all(mut self, column: &str, operator: &str, select: Self) -> Self {
+    let kind = SubqueryCondition::All(column.trim().to_string(), operator.trim().to_string());
+    self.push_where_nested(NestedCondition::Subquery(kind, Box::new(select)));
+    self
+  }
+
+  /// The method will concatenate multiples calls using the `and` operator. This method is un alias of `where_clause`,
+  /// except when the [where_join_or](Select::where_join_or) method is used, where this method still uses the `and` operator.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("carts")
+  ///   .where_clause("login = $1")
+  ///   .where_and("session_id = $2")
+  ///   .where_and("created_at >= current_date")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM carts WHERE login = $1 AND session_id = $2 AND created_at >= current_date";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// FROM carts
+  /// WHERE
+  ///   login = $1
+  ///   AND session_id = $2
+  ///   AND created_at >= current_date
+  /// ```
+  pub fn where_and(mut self, condition: &str) -> Self {
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::And, ClauseItem::default().expr(condition.trim())),
+    );
+    self
+  }
+
+  /// The quantified comparison `column operator ANY (subquery)` of the `where` clause, the select passed as argument
+  /// is stored and rendered as the subquery. This method will concatenate multiples calls using the `and` operator,
+  /// or the `or` operator when the [where_join_or](Select::where_join_or) method is used
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let any_orders = sql::Select::new()
+  ///   .select("total")
+  ///   .from("orders")
+  ///   .where_clause("status = 'paid'");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("name")
+  ///   .from("products")
+  ///   .where_any("price", "=", any_orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT name FROM products WHERE price = ANY (SELECT total FROM orders WHERE status = 'paid')";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output (multiline)
+  ///
+  /// ```sql
+  /// SELECT name
+  /// FROM products
+  /// WHERE
+  ///   price = ANY (
+  ///     SELECT total
+  ///     FROM orders
+  ///     WHERE
+  ///       status = 'paid'
+  ///   )
+  /// ```
+  pub fn where_any(mut self, column: &str, operator: &str, select: Self) -> Self {
+    let kind = SubqueryCondition::Any(column.trim().to_string(), operator.trim().to_string());
+    self.push_where_nested(NestedCondition::Subquery(kind, Box::new(select)));
+    self
   }
 
   /// The `where` clause, this method will concatenate multiples calls using the `and` operator.
@@ -442,163 +1781,1465 @@ impl Select {
   /// ```
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
-  ///   .where_clause("login = $1")
-  ///   .where_clause("status = 'active'")
+  ///   .where_clause("login = $1")
+  ///   .where_clause("status = 'active'")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE login = $1 AND status = 'active'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// WHERE
+  ///   login = $1
+  ///   AND status = 'active'
+  /// ```
+  pub fn where_clause(mut self, condition: &str) -> Self {
+    self.push_where(ClauseItem::default().expr(condition.trim()));
+    self
+  }
+
+  /// Same as the [where_clause](Select::where_clause) method but receives an optional condition,
+  /// the builder is returned unchanged when the condition is `None` or empty
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login: Option<&str> = None;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("status = 'active'")
+  ///   .where_clause_opt(login)
+  ///   .where_clause_opt(Some("created_at >= $1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE status = 'active' AND created_at >= $1";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// WHERE
+  ///   status = 'active'
+  ///   AND created_at >= $1
+  /// ```
+  pub fn where_clause_opt(self, condition: Option<&str>) -> Self {
+    match condition {
+      Some(condition) if condition.trim().is_empty() == false => self.where_clause(condition),
+      _ => self,
+    }
+  }
+
+  /// The `where` clause with the condition `EXISTS (subquery)`, the select passed as argument is rendered as
+  /// the subquery and can reference the tables of the outer query, like a correlated subquery. Useful to filter the rows that have at least one related row
+  /// This method is concatenated like the [where_clause](Select::where_clause) method and keeps its position
+  /// among the other conditions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let orders = sql::Select::new()
+  ///   .select("1")
+  ///   .from("orders o")
+  ///   .where_clause("o.user_id = u.id");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("u.login")
+  ///   .from("users u")
+  ///   .where_clause("u.active = true")
+  ///   .where_exists(orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT u.login FROM users u WHERE u.active = true AND EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output (multiline)
+  ///
+  /// ```sql
+  /// SELECT u.login
+  /// FROM users u
+  /// WHERE
+  ///   u.active = true
+  ///   AND EXISTS (
+  ///     SELECT 1
+  ///     FROM orders o
+  ///     WHERE
+  ///       o.user_id = u.id
+  ///   )
+  /// ```
+  pub fn where_exists(mut self, subquery: Self) -> Self {
+    self.push_where_nested(NestedCondition::Subquery(SubqueryCondition::Exists, Box::new(subquery)));
+    self
+  }
+
+  /// The `where` clause with a parenthesized group of conditions built by the closure, the group is concatenated
+  /// like the [where_clause](Select::where_clause) method, with the `and` operator or the `or` operator after the
+  /// [where_join_or](Select::where_join_or) method. The group is rendered with the formatter of the query and an
+  /// empty group is ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_group(|c| c.or("login = $1").or("email = $1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE active = true AND (login = $1 OR email = $1)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   active = true
+  ///   AND (login = $1 OR email = $1)
+  /// ```
+  pub fn where_group(mut self, f: impl FnOnce(Condition) -> Condition) -> Self {
+    let group = f(Condition::new());
+    if group.is_empty() == false {
+      self.push_where_nested(NestedCondition::Group(group));
+    }
+    self
+  }
+
+  /// The `where` clause with the condition `column IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always false `1 = 0` condition instead of the invalid `IN ()`.
+  /// This method is concatenated like the [where_clause](Select::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("name")
+  ///   .from("users")
+  ///   .where_in("id", ["1", "2", "3"])
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT name FROM users WHERE id IN (1, 2, 3)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// SELECT name
+  /// FROM users
+  /// WHERE id IN (1, 2, 3)
+  /// ```
+  pub fn where_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, false);
+    if condition.is_empty() == false {
+      self.push_where(condition);
+    }
+    self
+  }
+
+  /// Restores the `and` operator as the default operator of the next [where_clause](Select::where_clause) calls,
+  /// see the [where_join_or](Select::where_join_or) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_join_or()
+  ///   .where_clause("login = 'foo'")
+  ///   .where_clause("login = 'bar'")
+  ///   .where_join_and()
+  ///   .where_clause("active = true")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE login = 'foo' OR login = 'bar' AND active = true";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   login = 'foo'
+  ///   OR login = 'bar'
+  ///   AND active = true
+  /// ```
+  pub fn where_join_and(mut self) -> Self {
+    self._where_join_or = false;
+    self
+  }
+
+  /// Switches the default operator of the next [where_clause](Select::where_clause) calls from `and` to `or`,
+  /// the conditions added before are not changed. The [where_and](Select::where_and) and [where_or](Select::where_or)
+  /// methods are not affected and always use their own operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_join_or()
+  ///   .where_clause("login = 'foo'")
+  ///   .where_and("created_at >= current_date")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE active = true OR login = 'foo' AND created_at >= current_date";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   active = true
+  ///   OR login = 'foo'
+  ///   AND created_at >= current_date
+  /// ```
+  pub fn where_join_or(mut self) -> Self {
+    self._where_join_or = true;
+    self
+  }
+
+  /// The `where` clause with the condition `NOT EXISTS (subquery)`, the select passed as argument is rendered as
+  /// the subquery and can reference the tables of the outer query, like a correlated subquery. Useful to filter the rows without any related row
+  /// This method is concatenated like the [where_clause](Select::where_clause) method and keeps its position
+  /// among the other conditions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let orders = sql::Select::new()
+  ///   .select("1")
+  ///   .from("orders o")
+  ///   .where_clause("o.user_id = u.id");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("u.login")
+  ///   .from("users u")
+  ///   .where_clause("u.active = true")
+  ///   .where_not_exists(orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT u.login FROM users u WHERE u.active = true AND NOT EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output (multiline)
+  ///
+  /// ```sql
+  /// SELECT u.login
+  /// FROM users u
+  /// WHERE
+  ///   u.active = true
+  ///   AND NOT EXISTS (
+  ///     SELECT 1
+  ///     FROM orders o
+  ///     WHERE
+  ///       o.user_id = u.id
+  ///   )
+  /// ```
+  pub fn where_not_exists(mut self, subquery: Self) -> Self {
+    self.push_where_nested(NestedCondition::Subquery(
+      SubqueryCondition::NotExists,
+      Box::new(subquery),
+    ));
+    self
+  }
+
+  /// The `where` clause with the condition `column NOT IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always true `1 = 1` condition instead of the invalid `NOT IN ()`.
+  /// This method is concatenated like the [where_clause](Select::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let statuses = vec!["'banned'".to_string(), "'deleted'".to_string()];
+  /// let select_query = sql::Select::new()
+  ///   .select("name")
+  ///   .from("users")
+  ///   .where_not_in("status", &statuses)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT name FROM users WHERE status NOT IN ('banned', 'deleted')";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// SELECT name
+  /// FROM users
+  /// WHERE status NOT IN ('banned', 'deleted')
+  /// ```
+  pub fn where_not_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, true);
+    if condition.is_empty() == false {
+      self.push_where(condition);
+    }
+    self
+  }
+
+  /// The `where` clause that concatenate multiples calls using the OR operator.
+  /// If you intended to use the `and` operator you should use the [where_clause](Select::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("login = 'foo'")
+  ///   .where_or("login = 'bar'")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE login = 'foo' OR login = 'bar'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   login = 'foo'
+  ///   OR login = 'bar'
+  /// ```
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("login = 'foo'")
+  ///   .where_or("login = 'bar'")
+  ///   .where_clause("login = 'joe'")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   WHERE \
+  /// #     login = 'foo' \
+  /// #     OR login = 'bar' \
+  /// #     AND login = 'joe'\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   login = 'foo'
+  ///   OR login = 'bar'
+  ///   AND login = 'joe'
+  /// ```
+  ///
+  /// # Example
+  ///
+  /// If the `where_or` was the first clause then the operator will be ignored
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_or("login = 'joe'")
+  ///   .where_clause("login = 'foo'")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE login = 'joe' AND login = 'foo'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   login = 'joe'
+  ///   AND login = 'foo'
+  /// ```
+  pub fn where_or(mut self, condition: &str) -> Self {
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::Or, ClauseItem::default().expr(condition.trim())),
+    );
+    self
+  }
+
+  /// The `window` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .window("win as (partition by department)")
+  ///   .as_string();
+  ///
+  /// # let expected = "WINDOW win as (partition by department)";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WINDOW win as (partition by department)
+  /// ```
+  pub fn window(mut self, name: &str) -> Self {
+    push_unique(&mut self._window, (name.trim().to_string(), "".to_string()));
+    self
+  }
+
+  /// The `window` clause with a named window, the definition is wrapped in parentheses. Consecutive calls with
+  /// different names accumulates the windows, a call with the same name replaces the previous definition
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("login, rank() OVER w")
+  ///   .from("users")
+  ///   .window_as("w", "PARTITION BY department ORDER BY created_at")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT login, rank() OVER w \
+  /// #   FROM users \
+  /// #   WINDOW w AS (PARTITION BY department ORDER BY created_at)\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login, rank() OVER w
+  /// FROM users
+  /// WINDOW w AS (PARTITION BY department ORDER BY created_at)
+  /// ```
+  pub fn window_as(mut self, name: &str, definition: &str) -> Self {
+    let name = name.trim().to_string();
+    let definition = definition.trim().to_string();
+    match self._window.iter_mut().find(|(window_name, _)| *window_name == name) {
+      Some(window) => window.1 = definition,
+      None => self._window.push((name, definition)),
+    }
+    self
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+use crate::{
+  behavior::WithQuery,
+  structure::{CompoundSelect, WithBody},
+};
+
+#[cfg(feature = "postgresql")]
+use crate::param_value::number_placeholders;
+#[cfg(any(doc, feature = "postgresql"))]
+use crate::structure::ParamValue;
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+impl WithQuery for Select {
+  #[cfg(feature = "postgresql")]
+  fn bound_params(&self) -> Vec<ParamValue> {
+    self.ordered_params()
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl Select {
+  /// The `except` clause, the clauses of the select are applied to the left query of the `except`.
+  /// Use the `CompoundSelect` builder to apply `order by`, `limit` and `with` to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_users = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users");
+  ///
+  /// let select_inactives = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("status = 'inactive'");
+  ///
+  /// let select_query = select_users.except(select_inactives).as_string();
+  ///
+  /// # let expected = "\
+  /// #   (SELECT login FROM users) \
+  /// #   EXCEPT \
+  /// #   (SELECT login FROM users WHERE status = 'inactive')\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT login FROM users)
+  /// EXCEPT
+  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// ```
+  #[deprecated(
+    note = "use the CompoundSelect builder, `except` of Select applies the clauses of the select to the left query"
+  )]
+  pub fn except(mut self, select: Self) -> Self {
+    self._except.push(select);
+    self
+  }
+
+  /// The `intersect` clause, the clauses of the select are applied to the left query of the `intersect`.
+  /// Use the `CompoundSelect` builder to apply `order by`, `limit` and `with` to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_users = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users");
+  ///
+  /// let select_inactives = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("status = 'inactive'");
+  ///
+  /// let select_query = select_users.intersect(select_inactives).as_string();
+  ///
+  /// # let expected = "\
+  /// #   (SELECT login FROM users) \
+  /// #   INTERSECT \
+  /// #   (SELECT login FROM users WHERE status = 'inactive')\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT login FROM users)
+  /// INTERSECT
+  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// ```
+  #[deprecated(
+    note = "use the CompoundSelect builder, `intersect` of Select applies the clauses of the select to the left query"
+  )]
+  pub fn intersect(mut self, select: Self) -> Self {
+    self._intersect.push(select);
+    self
+  }
+
+  /// Creates the body of a recursive CTE, the `base` select is the non-recursive term and the `step` select
+  /// is the recursive term, both are combined using the `union all` clause. The terms are rendered without
+  /// parentheses as required by SQLite, a term with its own `order by` or `limit` is still wrapped in parentheses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let base = sql::Select::new()
+  ///   .select("1 as n");
+  ///
+  /// let step = sql::Select::new()
+  ///   .select("n + 1")
+  ///   .from("counter")
+  ///   .where_clause("n < 10");
+  ///
+  /// let select_query = sql::Select::recursive(base, step).as_string();
+  ///
+  /// # let expected = "SELECT 1 as n UNION ALL SELECT n + 1 FROM counter WHERE n < 10";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT 1 as n
+  /// UNION ALL
+  /// SELECT n + 1 FROM counter WHERE n < 10
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn recursive(base: Self, step: Self) -> CompoundSelect {
+    CompoundSelect::new(base).union_all(step)
+  }
+
+  /// Selects a random sample of `n` rows of the table, the table is added to the `from` clause together with the
+  /// other tables. An empty table has no effect
+  ///
+  /// # Example `crate features postgresql only`
+  ///
+  /// The table is rendered with the `tablesample system_rows` method, the method is provided by the `tsm_system_rows`
+  /// extension and it must be installed in the database with `CREATE EXTENSION tsm_system_rows`
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("*")
+  ///   .sample_rows("users", 100)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users TABLESAMPLE SYSTEM_ROWS (100)";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users TABLESAMPLE SYSTEM_ROWS (100)
+  /// ```
+  ///
+  /// # Example `crate features sqlite only`
+  ///
+  /// The table is replaced by a derived table with `n` rows shuffled by the `random()` function, the derived table
+  /// is named as the table, or as its alias when one is given like in `users u`. The `order by` and `limit` clauses
+  /// of the query are applied to the sampled rows
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "sqlite", not(feature = "postgresql")))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("*")
+  ///   .sample_rows("users", 100)
+  ///   .order_by("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM (SELECT * FROM users ORDER BY random() LIMIT 100) AS users ORDER BY login";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM (SELECT * FROM users ORDER BY random() LIMIT 100) AS users ORDER BY login
+  /// ```
+  pub fn sample_rows(mut self, table: &str, n: u64) -> Self {
+    if table.trim().is_empty() {
+      return self;
+    }
+
+    #[cfg(feature = "postgresql")]
+    {
+      self.push_sampled_from(table, "", "SYSTEM_ROWS", &n.to_string());
+    }
+
+    #[cfg(not(feature = "postgresql"))]
+    {
+      // the sample is a derived table named as the table, so the table can still be referenced by its name
+      // and the `order by` and `limit` clauses of the query are kept as they are
+      let table = table.trim();
+      let mut words = table.split_whitespace();
+      let source = words.next().unwrap_or_default();
+      let alias = match words.last() {
+        Some(alias) => alias,
+        None => source.rsplit('.').next().unwrap_or_default(),
+      };
+      let sample = Select::new()
+        .select("*")
+        .from(source)
+        .order_by("random()")
+        .limit(&n.to_string());
+      self.push_from_item(FromItem::subquery(sample).alias(alias));
+    }
+
+    self
+  }
+
+  /// The `union` clause, the clauses of the select are applied to the left query of the `union`.
+  /// Use the `CompoundSelect` builder to apply `order by`, `limit` and `with` to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_users = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users");
+  ///
+  /// let select_inactives = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("status = 'inactive'");
+  ///
+  /// let select_query = select_users.union(select_inactives).as_string();
+  ///
+  /// # let expected = "\
+  /// #   (SELECT login FROM users) \
+  /// #   UNION \
+  /// #   (SELECT login FROM users WHERE status = 'inactive')\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT login FROM users)
+  /// UNION
+  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// ```
+  #[deprecated(
+    note = "use the CompoundSelect builder, `union` of Select applies the clauses of the select to the left query"
+  )]
+  pub fn union(mut self, select: Self) -> Self {
+    self._union.push(select);
+    self
+  }
+
+  /// Combines two selects with the `union` clause and renames their output columns. The column names of a set
+  /// operation are always taken from the first select, so the aliases are applied in order to the output columns of
+  /// this select and the branches are rendered as is, without parentheses. A previous alias of the column is replaced
+  /// and an empty alias keeps the column name. The result is a [CompoundSelect], its `order by` and `limit` clauses
+  /// are applied to the whole compound and can reference the aliases
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let customers = sql::Select::new()
+  ///   .select("id, customer_login")
+  ///   .from("orders");
+  ///
+  /// let sellers = sql::Select::new()
+  ///   .select("id, seller_login")
+  ///   .from("products");
+  ///
+  /// let query = customers
+  ///   .union_as(sellers, &["", "login"])
+  ///   .order_by("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT id, customer_login AS login FROM orders \
+  /// #   UNION \
+  /// #   SELECT id, seller_login FROM products \
+  /// #   ORDER BY login\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, customer_login AS login FROM orders
+  /// UNION
+  /// SELECT id, seller_login FROM products
+  /// ORDER BY login
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn union_as(self, other: Self, aliases: &[&str]) -> CompoundSelect {
+    CompoundSelect::new(self.with_column_aliases(aliases)).union(other)
+  }
+
+  /// The `union all` clause, the clauses of the select are applied to the left query of the `union all`.
+  /// Use the `CompoundSelect` builder to apply `order by`, `limit` and `with` to the whole compound
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_users = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users");
+  ///
+  /// let select_inactives = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("status = 'inactive'");
+  ///
+  /// let select_query = select_users.union_all(select_inactives).as_string();
+  ///
+  /// # let expected = "\
+  /// #   (SELECT login FROM users) \
+  /// #   UNION ALL \
+  /// #   (SELECT login FROM users WHERE status = 'inactive')\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT login FROM users)
+  /// UNION ALL
+  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// ```
+  #[deprecated(
+    note = "use the CompoundSelect builder, `union_all` of Select applies the clauses of the select to the left query"
+  )]
+  pub fn union_all(mut self, select: Self) -> Self {
+    self._union_all.push(select);
+    self
+  }
+
+  /// The `with` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let logins = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("id in ($1)");
+  ///
+  /// let select = sql::Select::new()
+  ///   .with("logins", logins)
+  ///   .select("name, price")
+  ///   .from("orders")
+  ///   .where_clause("owner_login in (select * from logins)")
+  ///   .debug();
+  ///
+  /// # let expected = "\
+  /// #   WITH logins AS (\
+  /// #     SELECT login \
+  /// #     FROM users \
+  /// #     WHERE id in ($1)\
+  /// #   ) \
+  /// #   SELECT name, price \
+  /// #   FROM orders \
+  /// #   WHERE owner_login in (select * from logins)\
+  /// # ";
+  /// # assert_eq!(select.as_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// WITH
+  /// logins AS (
+  ///   SELECT login
+  ///   FROM users
+  ///   WHERE id in ($1)
+  /// )
+  /// SELECT name, price
+  /// FROM orders
+  /// WHERE owner_login in (select * from logins)
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self
+  }
+
+  /// The `with` clause with the query written as SQL, the query is wrapped in `name AS (...)` in the original
+  /// order with the queries added by the `with` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Select::new()
+  ///   .with_raw("active_users", "select login from users where active = true")
+  ///   .select("login")
+  ///   .from("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "WITH active_users AS (select login from users where active = true) SELECT login FROM active_users";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH active_users AS (select login from users where active = true) SELECT login FROM active_users
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_raw(mut self, name: &str, sql: &str) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Raw(sql.trim().to_string())));
+    self
+  }
+
+  /// The `with` clause marked as `recursive`, the query can reference itself by the `name`. When any query is
+  /// added by this method the whole `with` clause is rendered as `with recursive`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let base = sql::Select::new().select("1 as n");
+  /// let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+  ///
+  /// let query = sql::Select::new()
+  ///   .with_recursive("t", sql::Select::recursive(base, step))
+  ///   .select("sum(n)")
+  ///   .from("t")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   WITH RECURSIVE t AS (\
+  /// #     SELECT 1 as n \
+  /// #     UNION ALL \
+  /// #     SELECT n + 1 FROM t WHERE n < 10\
+  /// #   ) \
+  /// #   SELECT sum(n) FROM t\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH RECURSIVE t AS (
+  ///   SELECT 1 as n
+  ///   UNION ALL
+  ///   SELECT n + 1 FROM t WHERE n < 10
+  /// )
+  /// SELECT sum(n) FROM t
+  /// ```
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_recursive(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self._with_recursive = true;
+    self
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mssql")))]
+impl Select {
+  /// The `limit` clause, the value is rendered as is, so it can be a number or an SQL expression like
+  /// `(SELECT max_rows FROM config)`. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .limit("123");
+  ///
+  /// let select = sql::Select::new()
+  ///   .limit("1000")
+  ///   .limit("123");
+  ///
+  /// # let expected = "LIMIT 123";
+  /// # assert_eq!(select.as_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// # Example `crate features mssql only`
+  ///
+  /// The limit is rendered as `TOP` in the projection, or as `FETCH NEXT` when an [offset](Select::offset) is defined.
+  /// The raw SQL added before or after the [SelectClause::Limit] is placed around the `TOP n` or around the
+  /// `offset fetch` clause
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .limit("10")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT TOP 10 id, login FROM users";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT TOP 10 id, login FROM users
+  /// ```
+  pub fn limit(mut self, num: &str) -> Self {
+    self._limit = num.trim().to_string();
+    self._fetch.clear();
+    self
+  }
+
+  /// The `offset` clause, the value is rendered as is, so it can be a number or an SQL expression like
+  /// `(SELECT page_start FROM config)`. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .offset("1500");
+  ///
+  /// let select = sql::Select::new()
+  ///   .offset("1000")
+  ///   .offset("1500");
+  ///
+  /// # let expected = "OFFSET 1500";
+  /// # assert_eq!(select.as_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// # Example `crate features mssql only`
+  ///
+  /// SQL Server requires the `order by` clause to render the `offset` clause, without an [order_by](Select::order_by)
+  /// the `ORDER BY (SELECT NULL)` is rendered. The `fetch next` clause also requires the `offset` clause,
+  /// so the [fetch](Select::fetch) method without an offset renders `OFFSET 0 ROWS`
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .order_by("id")
+  ///   .offset("20")
+  ///   .limit("10")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, login FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY
+  /// ```
+  pub fn offset(mut self, num: &str) -> Self {
+    self._offset = num.trim().to_string();
+    self._offset_rows.clear();
+    self
+  }
+
+  /// The `limit` clause defined by an integer. The [limit](Select::limit) and `limit_value` methods share the same value,
+  /// the last call overrides the previous one
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let page_size = 25;
+  /// let select = sql::Select::new()
+  ///   .limit("ALL")
+  ///   .limit_value(page_size);
+  ///
+  /// # let expected = "LIMIT 25";
+  /// # assert_eq!(select.as_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LIMIT 25
+  /// ```
+  pub fn limit_value(mut self, num: u64) -> Self {
+    self._limit = num.to_string();
+    self._fetch.clear();
+    self
+  }
+
+  /// The `offset` clause defined by an integer. The [offset](Select::offset) and `offset_value` methods share
+  /// the same value, the last call overrides the previous one
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let page = 3;
+  /// let select = sql::Select::new()
+  ///   .limit_value(25)
+  ///   .offset_value(page * 25);
+  ///
+  /// # let expected = "LIMIT 25 OFFSET 75";
+  /// # assert_eq!(select.as_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LIMIT 25 OFFSET 75
+  /// ```
+  pub fn offset_value(mut self, num: u64) -> Self {
+    self._offset = num.to_string();
+    self._offset_rows.clear();
+    self
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl Select {
+  /// Binds a value to the next `?` placeholder of the query. The placeholders are rewritten to the numbered
+  /// form `$1`, `$2`, ... by the [into_parts](Select::into_parts) method, the numbering continues across
+  /// the nested builders of the `with` clause and the set operations
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("active = ?")
+  ///   .bind(true);
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .with("active_users", active_users)
+  ///   .select("*")
+  ///   .from("orders")
+  ///   .where_clause("owner_id in (select id from active_users)")
+  ///   .where_clause("total > ?")
+  ///   .bind(100)
+  ///   .into_parts();
+  ///
+  /// # assert_eq!(query, "WITH active_users AS (SELECT id FROM users WHERE active = $1) SELECT * FROM orders WHERE owner_id in (select id from active_users) AND total > $2");
+  /// # assert_eq!(params, vec![sql::ParamValue::Bool(true), sql::ParamValue::Int(100)]);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH active_users AS (SELECT id FROM users WHERE active = $1) SELECT * FROM orders WHERE owner_id in (select id from active_users) AND total > $2
+  /// ```
+  pub fn bind(mut self, value: impl Into<ParamValue>) -> Self {
+    self._params.push(value.into());
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN (options)`, the options are rendered as is.
+  /// This method overrides the previous value of the [explain](Select::explain) and
+  /// [explain_analyze](Select::explain_analyze) methods, an empty options is the same as the `explain` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .explain_with("FORMAT JSON, ANALYZE")
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN (FORMAT JSON, ANALYZE) SELECT id FROM users";
+  /// # assert_eq!(select_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN (FORMAT JSON, ANALYZE) SELECT id FROM users
+  /// ```
+  pub fn explain_with(mut self, options: &str) -> Self {
+    let options = options.trim();
+    self._explain = if options.is_empty() {
+      Explain::Plain
+    } else {
+      Explain::Options(options.to_string())
+    };
+    self
+  }
+
+  /// Returns the query with the `?` placeholders rewritten to the numbered form and the values bound
+  /// with the [bind](Select::bind) method, including the values of the nested builders in the order of the placeholders.
+  /// The `?` inside single quoted strings, double quoted identifiers and comments are preserved, the `??` escapes
+  /// the `?`, `?|` and `?&` operators of the `jsonb` type and it's rendered as `?`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, params) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("settings ??| array['theme', 'lang']")
+  ///   .where_clause("login = ?")
+  ///   .bind("foo")
+  ///   .into_parts();
+  ///
+  /// # assert_eq!(query, "SELECT id FROM users WHERE settings ?| array['theme', 'lang'] AND login = $1");
+  /// # assert_eq!(params, vec![sql::ParamValue::Text("foo".to_string())]);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users WHERE settings ?| array['theme', 'lang'] AND login = $1
+  /// ```
+  pub fn into_parts(self) -> (String, Vec<ParamValue>) {
+    let query = number_placeholders(&self.as_string());
+    (query, self.bound_params())
+  }
+
+  /// The `distinct on` of the select clause, the expressions are rendered right after the `select` keyword and before
+  /// the projection list. Each call adds its expression as is after the previous ones, so an expression with commas
+  /// like `coalesce(a, b)` is kept whole. This method overrides the [distinct](Select::distinct) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .distinct_on("user_id")
+  ///   .distinct_on("coalesce(store_id, 0)")
+  ///   .select("user_id, created_at")
+  ///   .from("orders")
+  ///   .order_by("user_id, coalesce(store_id, 0), created_at desc")
   ///   .as_string();
   ///
-  /// # let expected = "WHERE login = $1 AND status = 'active'";
+  /// # let expected = "\
+  /// #   SELECT DISTINCT ON (user_id, coalesce(store_id, 0)) user_id, created_at \
+  /// #   FROM orders \
+  /// #   ORDER BY user_id, coalesce(store_id, 0), created_at desc\
+  /// # ";
   /// # assert_eq!(select_query, expected);
+  /// # }
   /// ```
   ///
-  /// Outputs
+  /// Output
   ///
   /// ```sql
-  /// WHERE
-  ///   login = $1
-  ///   AND status = 'active'
+  /// SELECT DISTINCT ON (user_id, coalesce(store_id, 0)) user_id, created_at
+  /// FROM orders
+  /// ORDER BY user_id, coalesce(store_id, 0), created_at desc
   /// ```
-  pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+  pub fn distinct_on(mut self, expression: &str) -> Self {
+    let expression = expression.trim();
+    if expression.is_empty() {
+      return self;
+    }
+    match &mut self._distinct {
+      Distinct::On(expressions) => push_unique(expressions, expression.to_string()),
+      _ => self._distinct = Distinct::On(vec![expression.to_string()]),
+    }
     self
   }
 
-  /// The `where` clause that concatenate multiples calls using the OR operator.
-  /// If you intended to use the `and` operator you should use the [where_clause](Select::where_clause) method
+  /// The `for share` locking clause, rendered after the `limit` and `offset` clauses. This method overrides the previous
+  /// value of the [for_update](Select::for_update) method
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
-  ///   .where_clause("login = 'foo'")
-  ///   .where_or("login = 'bar'")
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .where_clause("state = 'ready'")
+  ///   .limit("1")
+  ///   .for_share()
   ///   .as_string();
   ///
-  /// # let expected = "WHERE login = 'foo' OR login = 'bar'";
+  /// # let expected = "SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR SHARE";
   /// # assert_eq!(select_query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// WHERE
-  ///   login = 'foo'
-  ///   OR login = 'bar'
+  /// SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR SHARE
   /// ```
+  pub fn for_share(mut self) -> Self {
+    self._for = "SHARE".to_string();
+    self
+  }
+
+  /// The `for update` locking clause, rendered after the `limit` and `offset` clauses. This method overrides the previous
+  /// value of the [for_share](Select::for_share) method
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
-  ///   .where_clause("login = 'foo'")
-  ///   .where_or("login = 'bar'")
-  ///   .where_clause("login = 'joe'")
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .where_clause("state = 'ready'")
+  ///   .limit("1")
+  ///   .for_update()
   ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   WHERE \
-  /// #     login = 'foo' \
-  /// #     OR login = 'bar' \
-  /// #     AND login = 'joe'\
-  /// # ";
+  /// # let expected = "SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE";
   /// # assert_eq!(select_query, expected);
+  /// # }
   /// ```
+  ///
   /// Output
   ///
   /// ```sql
-  /// WHERE
-  ///   login = 'foo'
-  ///   OR login = 'bar'
-  ///   AND login = 'joe'
+  /// SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE
   /// ```
+  pub fn for_update(mut self) -> Self {
+    self._for = "UPDATE".to_string();
+    self
+  }
+
+  /// The `from` clause with a function call using the named notation for the arguments, useful for set-returning
+  /// functions with many optional parameters. Arguments with an empty name are ignored and the alias is omitted when empty
   ///
   /// # Example
   ///
-  /// If the `where_or` was the first clause then the operator will be ignored
-  ///
   /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
-  ///   .where_or("login = 'joe'")
-  ///   .where_clause("login = 'foo'")
+  ///   .select("*")
+  ///   .from_function_named("generate_report", &[("start_date", "$1"), ("limit_rows", "100")], "report")
   ///   .as_string();
   ///
-  /// # let expected = "WHERE login = 'joe' AND login = 'foo'";
+  /// # let expected = "SELECT * FROM generate_report(start_date => $1, limit_rows => 100) AS report";
   /// # assert_eq!(select_query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// WHERE
-  ///   login = 'joe'
-  ///   AND login = 'foo'
+  /// SELECT * FROM generate_report(start_date => $1, limit_rows => 100) AS report
   /// ```
-  pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+  pub fn from_function_named(mut self, func: &str, args: &[(&str, &str)], alias: &str) -> Self {
+    let func = func.trim();
+    if func.is_empty() {
+      return self;
+    }
+
+    let args = args
+      .iter()
+      .map(|(name, value)| (name.trim(), value.trim()))
+      .filter(|(name, _)| name.is_empty() == false)
+      .map(|(name, value)| format!("{name} => {value}"))
+      .collect::<Vec<_>>()
+      .join(", ");
+    let alias = alias.trim();
+    let from = if alias.is_empty() {
+      format!("{func}({args})")
+    } else {
+      format!("{func}({args}) AS {alias}")
+    };
+
+    push_unique(&mut self._from, from);
     self
   }
 
-  /// The `window` clause
+  /// The `from` clause with the `only` keyword, only the table defined will be scanned excluding the inheritance children.
+  /// The keyword is attached to the table so it can be mixed with the [from](Select::from) method
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let select_query = sql::Select::new()
-  ///   .window("win as (partition by department)")
+  ///   .select("*")
+  ///   .from_only("measurements")
   ///   .as_string();
   ///
-  /// # let expected = "WINDOW win as (partition by department)";
+  /// # let expected = "SELECT * FROM ONLY measurements";
   /// # assert_eq!(select_query, expected);
+  /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// WINDOW win as (partition by department)
+  /// SELECT * FROM ONLY measurements
   /// ```
-  pub fn window(mut self, name: &str) -> Self {
-    push_unique(&mut self._window, name.trim().to_string());
+  pub fn from_only(mut self, table: &str) -> Self {
+    self.push_from_item(FromItem::table(table).only());
     self
   }
-}
 
-#[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::behavior::WithQuery;
-
-#[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl WithQuery for Select {}
-
-#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
-#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
-#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
-impl Select {
-  /// The `except` clause
+  /// The `from` clause with a `tablesample` method attached to the table, the sample is rendered after the table name.
+  /// Same as the [from_sampled](Select::from_sampled) method without alias, an empty method or an empty percent
+  /// will render only the table name
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let select_users = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users");
-  ///
-  /// let select_inactives = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users")
-  ///   .where_clause("status = 'inactive'");
-  ///
-  /// let select_query = select_users.except(select_inactives).as_string();
+  /// let select_query = sql::Select::new()
+  ///   .select("count(*)")
+  ///   .from_sample("big_table", "BERNOULLI", "10")
+  ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   (SELECT login FROM users) \
-  /// #   EXCEPT \
-  /// #   (SELECT login FROM users WHERE status = 'inactive')\
-  /// # ";
+  /// # let expected = "SELECT count(*) FROM big_table TABLESAMPLE BERNOULLI (10)";
   /// # assert_eq!(select_query, expected);
   /// # }
   /// ```
@@ -606,39 +3247,28 @@ impl Select {
   /// Output
   ///
   /// ```sql
-  /// (SELECT login FROM users)
-  /// EXCEPT
-  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// SELECT count(*) FROM big_table TABLESAMPLE BERNOULLI (10)
   /// ```
-  pub fn except(mut self, select: Self) -> Self {
-    self._except.push(select);
+  pub fn from_sample(mut self, table: &str, method: &str, percent: &str) -> Self {
+    self.push_sampled_from(table, "", method, percent);
     self
   }
 
-  /// The `intersect` clause
+  /// The `from` clause with a `tablesample` method, the table, the alias and the sample are defined in one call.
+  /// An empty alias will omit the `as` keyword and an empty method will render only the table and the alias
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let select_users = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users");
-  ///
-  /// let select_inactives = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users")
-  ///   .where_clause("status = 'inactive'");
-  ///
-  /// let select_query = select_users.intersect(select_inactives).as_string();
+  /// let select_query = sql::Select::new()
+  ///   .select("u.login")
+  ///   .from_sampled("users", "u", "bernoulli", 10.0)
+  ///   .as_string();
   ///
-  /// # let expected = "\
-  /// #   (SELECT login FROM users) \
-  /// #   INTERSECT \
-  /// #   (SELECT login FROM users WHERE status = 'inactive')\
-  /// # ";
+  /// # let expected = "SELECT u.login FROM users AS u TABLESAMPLE bernoulli (10)";
   /// # assert_eq!(select_query, expected);
   /// # }
   /// ```
@@ -646,86 +3276,109 @@ impl Select {
   /// Output
   ///
   /// ```sql
-  /// (SELECT login FROM users)
-  /// INTERSECT
-  /// (SELECT login FROM users WHERE status = 'inactive')
+  /// SELECT u.login FROM users AS u TABLESAMPLE bernoulli (10)
   /// ```
-  pub fn intersect(mut self, select: Self) -> Self {
-    self._intersect.push(select);
+  pub fn from_sampled(mut self, table: &str, alias: &str, method: &str, pct: f64) -> Self {
+    self.push_sampled_from(table, alias, method, &pct.to_string());
     self
   }
 
-  /// The `limit` clause, this method overrides the previous value
+  /// The `nowait` option of the locking clause, only rendered with the [for_update](Select::for_update) or the
+  /// [for_share](Select::for_share) methods. This method overrides the [skip_locked](Select::skip_locked) option
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .limit("123");
-  ///
-  /// let select = sql::Select::new()
-  ///   .limit("1000")
-  ///   .limit("123");
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .where_clause("state = 'ready'")
+  ///   .limit("1")
+  ///   .for_update()
+  ///   .nowait()
+  ///   .as_string();
   ///
-  /// # let expected = "LIMIT 123";
-  /// # assert_eq!(select.as_string(), expected);
+  /// # let expected = "SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE NOWAIT";
+  /// # assert_eq!(select_query, expected);
   /// # }
   /// ```
-  pub fn limit(mut self, num: &str) -> Self {
-    self._limit = num.trim().to_string();
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE NOWAIT
+  /// ```
+  pub fn nowait(mut self) -> Self {
+    self._for_wait = "NOWAIT".to_string();
     self
   }
 
-  /// The `offset` clause, this method overrides the previous value
+  /// The `skip locked` option of the locking clause, only rendered with the [for_update](Select::for_update) or the
+  /// [for_share](Select::for_share) methods. This method overrides the [nowait](Select::nowait) option
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let select = sql::Select::new()
-  ///   .offset("1500");
-  ///
-  /// let select = sql::Select::new()
-  ///   .offset("1000")
-  ///   .offset("1500");
+  /// let select_query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .where_clause("state = 'ready'")
+  ///   .limit("1")
+  ///   .for_update()
+  ///   .skip_locked()
+  ///   .as_string();
   ///
-  /// # let expected = "OFFSET 1500";
-  /// # assert_eq!(select.as_string(), expected);
+  /// # let expected = "SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE SKIP LOCKED";
+  /// # assert_eq!(select_query, expected);
   /// # }
   /// ```
-  pub fn offset(mut self, num: &str) -> Self {
-    self._offset = num.trim().to_string();
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM jobs WHERE state = 'ready' LIMIT 1 FOR UPDATE SKIP LOCKED
+  /// ```
+  pub fn skip_locked(mut self) -> Self {
+    self._for_wait = "SKIP LOCKED".to_string();
     self
   }
 
-  /// The `union` clause
+  /// The `cycle` clause of a recursive query defined in the `with` clause, it's rendered after the body of
+  /// the query named by `name` and marks the `with` clause as `recursive`. Available since PostgreSQL 14
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let select_users = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users");
-  ///
-  /// let select_inactives = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users")
-  ///   .where_clause("status = 'inactive'");
+  /// let base = sql::Select::new().select("id, parent_id").from("nodes").where_clause("id = $1");
+  /// let step = sql::Select::new()
+  ///   .select("n.id, n.parent_id")
+  ///   .from("nodes n")
+  ///   .inner_join("tree t ON n.parent_id = t.id");
   ///
-  /// let select_query = select_users.union(select_inactives).as_string();
+  /// let select_query = sql::Select::new()
+  ///   .with("tree", sql::Select::recursive(base, step))
+  ///   .with_recursive_cycle("tree", "id SET is_cycle USING path")
+  ///   .select("*")
+  ///   .from("tree")
+  ///   .as_string();
   ///
   /// # let expected = "\
-  /// #   (SELECT login FROM users) \
-  /// #   UNION \
-  /// #   (SELECT login FROM users WHERE status = 'inactive')\
+  /// #   WITH RECURSIVE tree AS (\
+  /// #     SELECT id, parent_id FROM nodes WHERE id = $1 \
+  /// #     UNION ALL \
+  /// #     SELECT n.id, n.parent_id FROM nodes n INNER JOIN tree t ON n.parent_id = t.id\
+  /// #   ) CYCLE id SET is_cycle USING path \
+  /// #   SELECT * \
+  /// #   FROM tree\
   /// # ";
   /// # assert_eq!(select_query, expected);
   /// # }
@@ -734,80 +3387,114 @@ impl Select {
   /// Output
   ///
   /// ```sql
-  /// (SELECT login FROM users)
-  /// UNION
-  /// (SELECT login FROM users WHERE status = 'inactive')
-  /// ```
-  pub fn union(mut self, select: Self) -> Self {
-    self._union.push(select);
+  /// WITH RECURSIVE tree AS (
+  ///   SELECT id, parent_id FROM nodes WHERE id = $1
+  ///   UNION ALL
+  ///   SELECT n.id, n.parent_id FROM nodes n INNER JOIN tree t ON n.parent_id = t.id
+  /// ) CYCLE id SET is_cycle USING path
+  /// SELECT *
+  /// FROM tree
+  /// ```
+  pub fn with_recursive_cycle(mut self, name: &str, cycle: &str) -> Self {
+    let name = name.trim();
+    let cycle = cycle.trim();
+    if name.is_empty() == false && cycle.is_empty() == false {
+      push_unique(&mut self._with_cycle, (name.to_string(), cycle.to_string()));
+      self._with_recursive = true;
+    }
     self
   }
 
-  /// The `with` clause
+  /// The `search` clause of a recursive query defined in the `with` clause, it's rendered after the body of
+  /// the query named by `name` and marks the `with` clause as `recursive`. Available since PostgreSQL 14
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let logins = sql::Select::new()
-  ///   .select("login")
-  ///   .from("users")
-  ///   .where_clause("id in ($1)");
+  /// let base = sql::Select::new().select("id, parent_id").from("nodes").where_clause("id = $1");
+  /// let step = sql::Select::new()
+  ///   .select("n.id, n.parent_id")
+  ///   .from("nodes n")
+  ///   .inner_join("tree t ON n.parent_id = t.id");
   ///
-  /// let select = sql::Select::new()
-  ///   .with("logins", logins)
-  ///   .select("name, price")
-  ///   .from("orders")
-  ///   .where_clause("owner_login in (select * from logins)")
-  ///   .debug();
+  /// let select_query = sql::Select::new()
+  ///   .with("tree", sql::Select::recursive(base, step))
+  ///   .with_recursive_search("tree", "DEPTH FIRST BY id SET ordercol")
+  ///   .select("*")
+  ///   .from("tree")
+  ///   .order_by("ordercol")
+  ///   .as_string();
   ///
   /// # let expected = "\
-  /// #   WITH logins AS (\
-  /// #     SELECT login \
-  /// #     FROM users \
-  /// #     WHERE id in ($1)\
-  /// #   ) \
-  /// #   SELECT name, price \
-  /// #   FROM orders \
-  /// #   WHERE owner_login in (select * from logins)\
+  /// #   WITH RECURSIVE tree AS (\
+  /// #     SELECT id, parent_id FROM nodes WHERE id = $1 \
+  /// #     UNION ALL \
+  /// #     SELECT n.id, n.parent_id FROM nodes n INNER JOIN tree t ON n.parent_id = t.id\
+  /// #   ) SEARCH DEPTH FIRST BY id SET ordercol \
+  /// #   SELECT * \
+  /// #   FROM tree \
+  /// #   ORDER BY ordercol\
   /// # ";
-  /// # assert_eq!(select.as_string(), expected);
+  /// # assert_eq!(select_query, expected);
   /// # }
   /// ```
   ///
-  /// Prints to the standard output
+  /// Output
   ///
   /// ```sql
-  /// -- ------------------------------------------------------------------------------
-  /// WITH
-  /// logins AS (
-  ///   SELECT login
-  ///   FROM users
-  ///   WHERE id in ($1)
-  /// )
-  /// SELECT name, price
-  /// FROM orders
-  /// WHERE owner_login in (select * from logins)
-  /// -- ------------------------------------------------------------------------------
-  /// ```
-  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  /// WITH RECURSIVE tree AS (
+  ///   SELECT id, parent_id FROM nodes WHERE id = $1
+  ///   UNION ALL
+  ///   SELECT n.id, n.parent_id FROM nodes n INNER JOIN tree t ON n.parent_id = t.id
+  /// ) SEARCH DEPTH FIRST BY id SET ordercol
+  /// SELECT *
+  /// FROM tree
+  /// ORDER BY ordercol
+  /// ```
+  pub fn with_recursive_search(mut self, name: &str, search: &str) -> Self {
+    let name = name.trim();
+    let search = search.trim();
+    if name.is_empty() == false && search.is_empty() == false {
+      push_unique(&mut self._with_search, (name.to_string(), search.to_string()));
+      self._with_recursive = true;
+    }
     self
   }
 }
 
 impl std::fmt::Display for Select {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-    write!(f, "{}", self.as_string())
+    self.write_to(f, &fmt::one_line())
   }
 }
 
 impl std::fmt::Debug for Select {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
+
+/// Two builders are equal when they render the same SQL with the [one_line](fmt::one_line) formatter,
+/// the order the methods were called and the values bound with the `bind` method are not compared
+impl PartialEq for Select {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_string() == other.as_string()
+  }
+}
+
+impl Eq for Select {}
+
+/// The hash of the rendered SQL, consistent with the [PartialEq] implementation
+impl std::hash::Hash for Select {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.as_string().hash(state);
   }
 }
@@ -1,72 +1,241 @@
 use crate::{
+  behavior::Join,
   concat::{
-    concat_raw_before_after,
+    concat_explain, concat_raw_before_after,
     sql_standard::{ConcatFrom, ConcatJoin, ConcatOrderBy, ConcatWhere},
     Concat,
   },
   fmt,
-  structure::{Select, SelectClause},
+  structure::{
+    ClauseItem, Distinct, FromItem, GroupByItem, LogicalOperator, NestedCondition, Select, SelectClause,
+    SubqueryCondition,
+  },
+  utils::{push_unique, push_unique_ci, split_top_level_commas},
 };
 
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+use crate::structure::Combinator;
+
 impl ConcatFrom<SelectClause> for Select {}
 impl ConcatWhere<SelectClause> for Select {}
 impl ConcatJoin<SelectClause> for Select {}
 impl ConcatOrderBy<SelectClause> for Select {}
 
+impl Join for Select {
+  fn join_items(&mut self) -> &mut Vec<ClauseItem> {
+    &mut self._join
+  }
+}
+
 impl Concat for Select {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
+    self.concat_into(fmts, &mut query);
+    query
+  }
 
-    query = self.concat_raw(query, &fmts, &self._raw);
+  fn concat_into(&self, fmts: &fmt::Formatter, out: &mut String) {
+    let start = out.len();
 
+    // the set operations wrap the query rendered before them, so the select is rendered apart from the buffer
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if start > 0 && self.has_combinators() {
+      out.push_str(&self.concat(fmts));
+      return;
+    }
+
+    self.concat_clauses(fmts, |_, clause| out.push_str(&clause));
+
+    let mut query = std::mem::take(out);
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for combinator in COMBINATORS {
+      query = self.concat_combinator(query, fmts, combinator);
+    }
+
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.truncate(start.max(query.trim_end().len()));
+    *out = query;
+  }
+}
+
+/// The set operations in the order they are rendered
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+const COMBINATORS: [Combinator; 4] = [
+  Combinator::Except,
+  Combinator::Intersect,
+  Combinator::Union,
+  Combinator::UnionAll,
+];
+
+/// The parts of the query passed to the `push_clause` function of [Select::concat_clauses], the parts with
+/// nested builders are told apart so the bound values can be collected while the query is rendered
+#[derive(Clone, Copy)]
+pub(crate) enum QueryPart {
+  Sql,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  With,
+  From,
+  Where,
+}
+
+/// An item of a clause or an item added in a position of the clause, like the subqueries of the `where` clause
+enum Positioned<'a, T, N> {
+  Item(&'a T),
+  Nested(&'a N),
+}
+
+/// Merges the items added in a position of the clause with the items of the clause, in the order they are rendered
+fn positioned<'a, T, N>(items: &'a [T], nested: &'a [N], position: impl Fn(&N) -> usize) -> Vec<Positioned<'a, T, N>> {
+  let mut merged = vec![];
+  let mut nested = nested.iter().peekable();
+  for (index, item) in items.iter().enumerate() {
+    while let Some(nested_item) = nested.next_if(|nested_item| position(nested_item) <= index) {
+      merged.push(Positioned::Nested(nested_item));
+    }
+    merged.push(Positioned::Item(item));
+  }
+  merged.extend(nested.map(Positioned::Nested));
+  merged
+}
+
+impl Select {
+  /// Renders the clauses placed before the set operations, each clause is passed to `push_clause` as soon as
+  /// it is rendered so the caller decides where the clause is written
+  fn concat_clauses(&self, fmts: &fmt::Formatter, mut push_clause: impl FnMut(QueryPart, String)) {
+    push_clause(QueryPart::Sql, self.concat_prefix("".to_string(), fmts));
+
+    #[cfg(all(feature = "sqlite", not(feature = "postgresql")))]
     {
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::With,
-        &self._with,
+      push_clause(
+        QueryPart::With,
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          "".to_string(),
+          fmts,
+          SelectClause::With,
+          &self._with,
+        ),
       );
     }
 
-    query = self.concat_select(query, &fmts);
-    query = self.concat_from(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      &fmts,
-      SelectClause::From,
-      &self._from,
+    #[cfg(feature = "postgresql")]
+    {
+      push_clause(QueryPart::With, self.concat_with_recursive("".to_string(), fmts));
+    }
+
+    push_clause(QueryPart::Sql, self.concat_select("".to_string(), fmts));
+    push_clause(
+      QueryPart::From,
+      self.concat_from(
+        &self._raw_before,
+        &self._raw_after,
+        "".to_string(),
+        fmts,
+        SelectClause::From,
+        &self.items_of_from(fmts),
+      ),
     );
-    query = self.concat_join(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      &fmts,
-      SelectClause::Join,
-      &self._join,
+    push_clause(
+      QueryPart::Sql,
+      self.concat_join(
+        &self._raw_before,
+        &self._raw_after,
+        "".to_string(),
+        fmts,
+        SelectClause::Join,
+        &self._join,
+      ),
     );
-
-    query = self.concat_where(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      &fmts,
-      SelectClause::Where,
-      &self._where,
+    push_clause(
+      QueryPart::Where,
+      self.concat_where(
+        &self._raw_before,
+        &self._raw_after,
+        "".to_string(),
+        fmts,
+        SelectClause::Where,
+        &self.items_of_where(fmts),
+      ),
     );
-    query = self.concat_group_by(query, &fmts);
-    query = self.concat_having(query, &fmts);
-    query = self.concat_window(query, &fmts);
+    push_clause(QueryPart::Sql, self.concat_tail("".to_string(), fmts));
+  }
+
+  /// Writes the query clause by clause into the writer, the trailing whitespaces of a clause are only written
+  /// when another clause follows, so the output is the same as [Concat::concat]. The set operations wrap the
+  /// clauses rendered before them and the minify pass needs the whole query, in both cases the query is rendered
+  /// into a string first
+  pub(crate) fn write_clauses<W: std::fmt::Write>(&self, w: &mut W, fmts: &fmt::Formatter) -> std::fmt::Result {
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let has_set_operation = self._except.is_empty() == false
+      || self._intersect.is_empty() == false
+      || self._union.is_empty() == false
+      || self._union_all.is_empty() == false;
+    #[cfg(not(any(feature = "postgresql", feature = "sqlite")))]
+    let has_set_operation = false;
+
+    if fmts.minify || has_set_operation {
+      return w.write_str(&self.as_string_with(fmts));
+    }
+
+    let mut result = Ok(());
+    let mut written = false;
+    let mut whitespaces = "".to_string();
+    let mut write_clause = |_, clause: String| {
+      let sql = clause.trim_end();
+      if result.is_err() || sql.is_empty() {
+        whitespaces.push_str(&clause);
+        return;
+      }
+      result = w.write_str(&whitespaces).and_then(|_| w.write_str(sql));
+      whitespaces = clause[sql.len()..].to_string();
+      written = true;
+    };
+
+    self.concat_clauses(fmts, &mut write_clause);
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for combinator in COMBINATORS {
+      write_clause(QueryPart::Sql, self.concat_combinator("".to_string(), fmts, combinator));
+    }
+
+    write_clause(QueryPart::Sql, self.concat_raw("".to_string(), fmts, &self._raw_append));
+
+    result?;
+    if self._terminated && written {
+      w.write_char(';')?;
+    }
+    Ok(())
+  }
+
+  /// Renders the raw SQL, the comments and the `explain` placed before the clauses of the query
+  fn concat_prefix(&self, mut query: String, fmts: &fmt::Formatter) -> String {
+    query = self.concat_raw(query, fmts, &self._raw_prepend);
+    query = self.concat_raw(query, fmts, &self._comment);
+    query = concat_explain(query, fmts, &self._explain);
+    self.concat_raw(query, fmts, &self._raw)
+  }
+
+  /// Renders the clauses placed after the `where` clause and before the set operations
+  fn concat_tail(&self, mut query: String, fmts: &fmt::Formatter) -> String {
+    query = self.concat_group_by(query, fmts);
+    query = self.concat_having(query, fmts);
+    query = self.concat_window(query, fmts);
+
+    #[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+    let order_by = &self._order_by;
+    #[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+    let order_by = &self.items_of_order_by();
+
     query = self.concat_order_by(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       SelectClause::OrderBy,
-      &self._order_by,
+      order_by,
     );
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -75,37 +244,181 @@ impl Concat for Select {
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         SelectClause::Limit,
         &self._limit,
       );
-      query = self.concat_offset(query, &fmts);
+      query = self.concat_offset(query, fmts);
     }
 
-    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    #[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+    {
+      query = self.concat_standard_offset(query, fmts);
+      query = self.concat_fetch(query, fmts);
+    }
+
+    #[cfg(feature = "postgresql")]
     {
-      use crate::structure::Combinator;
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
+      query = self.concat_for(query, fmts);
     }
 
-    query.trim_end().to_string()
+    #[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+    {
+      query = self.concat_offset_fetch(query, fmts);
+    }
+
+    query
+  }
+
+  /// The tables of the `from` clause with the items of the [FromItem](crate::FromItem) builder rendered
+  /// in the position they were added
+  fn items_of_from(&self, fmts: &fmt::Formatter) -> Vec<String> {
+    if self._from_item.is_empty() {
+      return self._from.clone();
+    }
+
+    positioned(&self._from, &self._from_item, |(at, _)| *at)
+      .into_iter()
+      .map(|part| match part {
+        Positioned::Item(table) => table.clone(),
+        Positioned::Nested((_, item)) => item.concat(fmts),
+      })
+      .collect()
+  }
+
+  /// The conditions of the `where` clause with the groups and the subqueries rendered in the position they were added,
+  /// the subquery is indented one level deeper than the conditions
+  fn items_of_where(&self, fmts: &fmt::Formatter) -> Vec<(LogicalOperator, ClauseItem)> {
+    if self._where_nested.is_empty() {
+      return self._where.clone();
+    }
+
+    let fmt::Formatter { lb, indent, space, .. } = fmts;
+    let inner_lb = format!("{lb}{indent}{indent}");
+    let inner_fmts = fmt::Formatter {
+      lb: inner_lb.as_str(),
+      ..*fmts
+    };
+    let condition = |(_, operator, nested): &(usize, LogicalOperator, NestedCondition)| {
+      let (kind, select) = match nested {
+        NestedCondition::Group(group) => {
+          let group = format!("({})", group.concat(fmts));
+          return (operator.clone(), ClauseItem::default().expr(&group));
+        }
+        NestedCondition::Subquery(kind, select) => (kind, select),
+      };
+      let subquery = format!("({inner_lb}{}{lb}{indent})", select.concat(&inner_fmts));
+      let condition = match kind {
+        SubqueryCondition::All(column, op) => format!("{column}{space}{op}{space}{}{space}{subquery}", fmts.kw("ALL")),
+        SubqueryCondition::Any(column, op) => format!("{column}{space}{op}{space}{}{space}{subquery}", fmts.kw("ANY")),
+        SubqueryCondition::Exists => format!("{}{space}{subquery}", fmts.kw("EXISTS")),
+        SubqueryCondition::NotExists => format!("{}{space}{subquery}", fmts.kw("NOT EXISTS")),
+      };
+      (operator.clone(), ClauseItem::default().expr(&condition))
+    };
+
+    positioned(&self._where, &self._where_nested, |(at, _, _)| *at)
+      .into_iter()
+      .map(|part| match part {
+        Positioned::Item(item) => item.clone(),
+        Positioned::Nested(nested) => condition(nested),
+      })
+      .collect()
+  }
+
+  /// Adds the condition to the `where` clause concatenated with the current operator, see the
+  /// [where_join_or](Select::where_join_or) method
+  pub(crate) fn push_where(&mut self, condition: ClauseItem) {
+    let operator = self.where_operator();
+    push_unique(&mut self._where, (operator, condition));
+  }
+
+  /// Adds a condition stored as a builder to the `where` clause, concatenated with the current operator
+  /// of the [where_clause](Select::where_clause) method
+  pub(crate) fn push_where_nested(&mut self, condition: NestedCondition) {
+    let operator = self.where_operator();
+    let position = self._where.len();
+    let same_item = self
+      ._where_nested
+      .iter()
+      .any(|(_, op, nested)| *op == operator && *nested == condition);
+    if same_item == false {
+      self._where_nested.push((position, operator, condition));
+    }
+  }
+
+  fn where_operator(&self) -> LogicalOperator {
+    if self._where_join_or {
+      LogicalOperator::Or
+    } else {
+      LogicalOperator::And
+    }
+  }
+
+  /// All the clauses of the builder in the order they are rendered
+  pub(crate) fn all_clauses() -> Vec<SelectClause> {
+    vec![
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::With,
+      SelectClause::Select,
+      SelectClause::From,
+      SelectClause::Join,
+      SelectClause::Where,
+      SelectClause::GroupBy,
+      SelectClause::Having,
+      SelectClause::Window,
+      SelectClause::OrderBy,
+      SelectClause::Limit,
+      SelectClause::Offset,
+      SelectClause::OffsetRows,
+      SelectClause::Fetch,
+      #[cfg(feature = "postgresql")]
+      SelectClause::For,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Except,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Intersect,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::Union,
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      SelectClause::UnionAll,
+    ]
+  }
+
+  pub(crate) fn missing_clause(&self) -> Option<&'static str> {
+    if self._raw.is_empty() && self._select.is_empty() {
+      return Some("select");
+    }
+    None
   }
-}
 
-impl Select {
   fn concat_group_by(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if self._group_by.is_empty() == false {
       let columns = self
         ._group_by
         .iter()
-        .filter(|column| column.is_empty() == false)
-        .map(|column| column.as_str())
+        .map(|item| match item {
+          GroupByItem::Expr(column) => column.clone(),
+          GroupByItem::Cube(columns) => format!("{} ({columns})", fmts.kw("CUBE")),
+          GroupByItem::GroupingSets(sets) => format!("{} ({sets})", fmts.kw("GROUPING SETS")),
+          #[cfg(not(feature = "mysql"))]
+          GroupByItem::Rollup(columns) => format!("{} ({columns})", fmts.kw("ROLLUP")),
+          #[cfg(feature = "mysql")]
+          GroupByItem::Rollup(columns) => columns.clone(),
+        })
         .collect::<Vec<_>>()
         .join(comma);
-      format!("GROUP BY{space}{columns}{space}{lb}")
+
+      #[cfg(not(feature = "mysql"))]
+      let with_rollup = "".to_string();
+      #[cfg(feature = "mysql")]
+      let with_rollup = match self._group_by.iter().any(|item| matches!(item, GroupByItem::Rollup(_))) {
+        true => format!(" {}", fmts.kw("WITH ROLLUP")),
+        false => "".to_string(),
+      };
+
+      format!("{}{space}{columns}{with_rollup}{space}{lb}", fmts.kw("GROUP BY"))
     } else {
       "".to_string()
     };
@@ -122,15 +435,17 @@ impl Select {
 
   fn concat_having(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._having.is_empty() == false {
-      let conditions = self
-        ._having
-        .iter()
-        .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
-        .collect::<Vec<_>>()
-        .join(" AND ");
-      format!("HAVING{space}{conditions}{space}{lb}")
+    let conditions = self
+      ._having
+      .iter()
+      .filter(|(_, condition)| condition.is_empty() == false)
+      .collect::<Vec<_>>();
+    let sql = if let Some(((_, first), tail)) = conditions.split_first() {
+      let conditions = tail.iter().fold(first.to_string(), |acc, (log_op, condition)| {
+        let log_op = fmts.kw(&log_op.to_string());
+        format!("{acc}{space}{log_op}{space}{condition}")
+      });
+      format!("{}{space}{conditions}{space}{lb}", fmts.kw("HAVING"))
     } else {
       "".to_string()
     };
@@ -147,15 +462,33 @@ impl Select {
 
   fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._select.is_empty() == false {
+
+    #[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+    let top = "".to_string();
+    #[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+    let top = self.concat_top(fmts);
+
+    let distinct = match &self._distinct {
+      Distinct::None => "".to_string(),
+      Distinct::Plain => fmts.kw("DISTINCT"),
+      #[cfg(feature = "postgresql")]
+      Distinct::On(expressions) => format!("{}{space}({})", fmts.kw("DISTINCT ON"), expressions.join(comma)),
+    };
+
+    let sql = if self._select.is_empty() == false || top.is_empty() == false || distinct.is_empty() == false {
       let columns = self
         ._select
         .iter()
         .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
+        .map(|item| item.concat(fmts))
         .collect::<Vec<_>>()
         .join(comma);
-      format!("SELECT{space}{columns}{space}{lb}")
+      let projection = [distinct.as_str(), top.as_str(), columns.as_str()]
+        .into_iter()
+        .filter(|item| item.is_empty() == false)
+        .collect::<Vec<_>>()
+        .join(space);
+      format!("{}{space}{projection}{space}{lb}", fmts.kw("SELECT"))
     } else {
       "".to_string()
     };
@@ -176,11 +509,17 @@ impl Select {
       let columns = self
         ._window
         .iter()
-        .filter(|item| item.is_empty() == false)
-        .map(|item| item.as_str())
+        .filter(|(name, _)| name.is_empty() == false)
+        .map(|(name, definition)| {
+          if definition.is_empty() {
+            name.to_string()
+          } else {
+            format!("{name}{space}{}{space}({definition})", fmts.kw("AS"))
+          }
+        })
         .collect::<Vec<_>>()
         .join(comma);
-      format!("WINDOW{space}{columns}{space}{lb}")
+      format!("{}{space}{columns}{space}{lb}", fmts.kw("WINDOW"))
     } else {
       "".to_string()
     };
@@ -194,32 +533,183 @@ impl Select {
       sql,
     )
   }
+
+  /// The expressions of the `distinct on` defined in the select clause
+  fn distinct_on_expressions(&self) -> Option<Vec<String>> {
+    #[cfg(feature = "postgresql")]
+    if let Distinct::On(expressions) = &self._distinct {
+      let expressions = expressions
+        .iter()
+        .flat_map(|expression| split_top_level_commas(expression))
+        .map(|expression| expression.to_string())
+        .collect::<Vec<_>>();
+      return Some(expressions);
+    }
+
+    self._select.iter().find_map(|item| {
+      let item = item.to_string();
+      let upper = item.to_ascii_uppercase();
+      let rest = upper
+        .strip_prefix("DISTINCT")?
+        .trim_start()
+        .strip_prefix("ON")?
+        .trim_start();
+      if rest.starts_with('(') == false {
+        return None;
+      }
+      let start = item.len() - rest.len() + 1;
+      let mut depth = 0;
+      let end = start
+        + rest[1..].find(|char| {
+          match char {
+            '(' => depth += 1,
+            ')' if depth == 0 => return true,
+            ')' => depth -= 1,
+            _ => {}
+          }
+          false
+        })?;
+      let expressions = split_top_level_commas(&item[start..end])
+        .into_iter()
+        .map(|expression| expression.to_string())
+        .collect::<Vec<_>>();
+
+      Some(expressions)
+    })
+  }
+
+  /// Adds the item to the `from` clause after the tables already added, the item is not added when the same table
+  /// or the same item was already added
+  pub(crate) fn push_from_item(&mut self, item: FromItem) {
+    if item.is_empty() {
+      return;
+    }
+    let rendered = item.as_string();
+    let same_table = self._from.contains(&rendered);
+    let same_item = self._from_item.iter().any(|(_, from_item)| *from_item == item);
+    if same_table == false && same_item == false {
+      self._from_item.push((self._from.len(), item));
+    }
+  }
+
+  /// Adds the column to the `order by` clause, ignoring the case when enabled by the `order_by_ignore_case` method
+  pub(crate) fn push_order_by(&mut self, column: ClauseItem) {
+    if self._order_by_ignore_case {
+      push_unique_ci(&mut self._order_by, column);
+    } else {
+      push_unique(&mut self._order_by, column);
+    }
+  }
+
+  pub(crate) fn distinct_on_matches_order_by(&self) -> bool {
+    let expressions = match self.distinct_on_expressions() {
+      Some(expressions) => expressions,
+      None => return true,
+    };
+    if self._order_by.is_empty() {
+      return true;
+    }
+
+    let order_by = self._order_by.iter().map(|item| item.to_string()).collect::<Vec<_>>();
+    let leftmost_order_by = order_by
+      .iter()
+      .flat_map(|item| split_top_level_commas(item))
+      .take(expressions.len())
+      .collect::<Vec<_>>();
+
+    expressions.iter().all(|expression| {
+      leftmost_order_by.iter().any(|item| {
+        item
+          .strip_prefix(expression.as_str())
+          .map_or(false, |rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+      })
+    })
+  }
+
+  pub(crate) fn has_distinct(&self) -> bool {
+    self._distinct != Distinct::None
+      || self
+        ._select
+        .iter()
+        .any(|item| item.to_string().to_ascii_uppercase().starts_with("DISTINCT"))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 use crate::concat::non_standard::{ConcatLimit, ConcatWith};
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl ConcatWith<SelectClause> for Select {}
+impl ConcatWith<SelectClause> for Select {
+  fn is_recursive(&self) -> bool {
+    self._with_recursive
+  }
+}
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl ConcatLimit<SelectClause> for Select {}
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl Select {
-  fn concat_combinator(
-    &self,
-    query: String,
-    fmts: &fmt::Formatter,
-    combinator: crate::structure::Combinator,
-  ) -> String {
-    use crate::{concat::raw_queries, structure::Combinator};
+  /// Renames the output columns in order with the aliases, the columns of a single `select` call are split by the
+  /// top level commas. A previous alias of the column is replaced and an empty alias keeps the column as it is
+  pub(crate) fn with_column_aliases(mut self, aliases: &[&str]) -> Self {
+    use crate::{structure::ClauseItemPart, utils::output_expression};
 
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let (clause, clause_name, clause_list) = match combinator {
+    let mut aliases = aliases.iter().map(|alias| alias.trim());
+    let mut columns = vec![];
+    for item in std::mem::take(&mut self._select) {
+      let items = match item.0.as_slice() {
+        [ClauseItemPart::Expr(text)] => split_top_level_commas(text)
+          .into_iter()
+          .map(|column| ClauseItem::default().expr(column))
+          .collect(),
+        _ => vec![item],
+      };
+
+      for column in items {
+        let alias = aliases.next().unwrap_or_default();
+        if alias.is_empty() {
+          columns.push(column);
+          continue;
+        }
+
+        let is_alias = |part: &ClauseItemPart| matches!(part, ClauseItemPart::Keyword(keyword) if keyword == "AS");
+        let mut parts = match (column.0.iter().position(is_alias), column.0.as_slice()) {
+          (Some(index), _) => column.0[..index].to_vec(),
+          (None, [ClauseItemPart::Expr(text)]) => vec![ClauseItemPart::Expr(output_expression(text).to_string())],
+          (None, _) => column.0,
+        };
+        if let Some(ClauseItemPart::Expr(text)) = parts.last_mut() {
+          text.truncate(text.trim_end().len());
+        }
+        columns.push(ClauseItem(parts).expr(" ").keyword("AS").expr(&format!(" {alias}")));
+      }
+    }
+
+    self._select = columns;
+    self
+  }
+
+  /// The clause, the keyword and the queries of the set operation
+  fn combinator_clause(&self, combinator: &Combinator) -> (SelectClause, &'static str, &Vec<Select>) {
+    match combinator {
       Combinator::Except => (SelectClause::Except, "EXCEPT", &self._except),
       Combinator::Intersect => (SelectClause::Intersect, "INTERSECT", &self._intersect),
       Combinator::Union => (SelectClause::Union, "UNION", &self._union),
-    };
+      Combinator::UnionAll => (SelectClause::UnionAll, "UNION ALL", &self._union_all),
+    }
+  }
+
+  fn has_combinators(&self) -> bool {
+    COMBINATORS
+      .iter()
+      .any(|combinator| self.combinator_clause(combinator).2.is_empty() == false)
+  }
+
+  fn concat_combinator(&self, query: String, fmts: &fmt::Formatter, combinator: Combinator) -> String {
+    use crate::concat::raw_queries;
+
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let (clause, clause_name, clause_list) = self.combinator_clause(&combinator);
 
     let raw_before = raw_queries(&self._raw_before, &clause).join(space).trim().to_string();
     let raw_after = raw_queries(&self._raw_after, &clause).join(space).trim().to_string();
@@ -240,6 +730,7 @@ impl Select {
       return format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}");
     }
 
+    let clause_name = fmts.kw(clause_name);
     let right_stmt = clause_list.iter().fold("".to_string(), |acc, select| {
       let query = select.concat(&fmts);
       format!("{acc}{clause_name}{space}({lb}{query}){space}{lb}")
@@ -253,13 +744,63 @@ impl Select {
   }
 }
 
+impl Select {
+  #[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+  fn concat_fetch(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._fetch.is_empty() == false {
+      let count = &self._fetch;
+      format!(
+        "{}{space}{count}{space}{}{space}{lb}",
+        fmts.kw("FETCH NEXT"),
+        fmts.kw("ROWS ONLY")
+      )
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::Fetch,
+      sql,
+    )
+  }
+
+  #[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+  fn concat_standard_offset(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._offset_rows.is_empty() == false {
+      let start = &self._offset_rows;
+      format!(
+        "{}{space}{start}{space}{}{space}{lb}",
+        fmts.kw("OFFSET"),
+        fmts.kw("ROWS")
+      )
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::OffsetRows,
+      sql,
+    )
+  }
+}
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl Select {
   fn concat_offset(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._offset.is_empty() == false {
       let start = &self._offset;
-      format!("OFFSET{space}{start}{space}{lb}")
+      format!("{}{space}{start}{space}{lb}", fmts.kw("OFFSET"))
     } else {
       "".to_string()
     };
@@ -274,3 +815,242 @@ impl Select {
     )
   }
 }
+
+#[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+impl Select {
+  /// The start row of the `offset fetch` clause, SQL Server requires the `offset` to render the `fetch`,
+  /// so `0` is the start row when only the number of rows is defined
+  fn offset_fetch_start(&self) -> &str {
+    match (self._offset.as_str(), self._offset_rows.as_str()) {
+      ("", "") if self._fetch.is_empty() == false => "0",
+      ("", start) | (start, _) => start,
+    }
+  }
+
+  /// The number of rows of the `offset fetch` clause, the limit is only rendered as `fetch next` when there is a
+  /// start row, otherwise the limit is rendered as `TOP n` in the projection
+  fn offset_fetch_count(&self) -> &str {
+    match self._fetch.is_empty() {
+      true if self.offset_fetch_start().is_empty() == false => &self._limit,
+      true => "",
+      false => &self._fetch,
+    }
+  }
+
+  /// SQL Server requires the `order by` clause to render the `offset fetch` clause, when the query has no
+  /// order the `order by (select null)` keeps the order of the rows undefined
+  fn items_of_order_by(&self) -> Vec<ClauseItem> {
+    let has_order = self._order_by.iter().any(|item| item.is_empty() == false);
+    if has_order || self.offset_fetch_start().is_empty() {
+      return self._order_by.clone();
+    }
+    vec![ClauseItem::default().expr("(").keyword("SELECT NULL").expr(")")]
+  }
+
+  /// The `offset` and the `fetch` clauses are rendered together, so the raw SQL added before or after
+  /// any of the `offset`, `offset rows`, `fetch` and `limit` clauses is placed around the whole clause.
+  /// The raw SQL of the limit rendered as `TOP n` is placed around the `TOP` in the projection
+  fn concat_offset_fetch(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let (start, count) = (self.offset_fetch_start(), self.offset_fetch_count());
+    let offset = if start.is_empty() == false {
+      format!(
+        "{}{space}{start}{space}{}{space}{lb}",
+        fmts.kw("OFFSET"),
+        fmts.kw("ROWS")
+      )
+    } else {
+      "".to_string()
+    };
+    let fetch = if count.is_empty() == false {
+      format!(
+        "{}{space}{count}{space}{}{space}{lb}",
+        fmts.kw("FETCH NEXT"),
+        fmts.kw("ROWS ONLY")
+      )
+    } else {
+      "".to_string()
+    };
+
+    let mut clauses = vec![SelectClause::Offset, SelectClause::OffsetRows, SelectClause::Fetch];
+    if self.has_top() == false {
+      clauses.push(SelectClause::Limit);
+    }
+    let anchored = |raw_list: &Vec<(SelectClause, String)>| {
+      raw_list
+        .iter()
+        .filter(|(clause, _)| clauses.contains(clause))
+        .map(|(_, raw_sql)| (SelectClause::Offset, raw_sql.clone()))
+        .collect::<Vec<_>>()
+    };
+
+    concat_raw_before_after(
+      &anchored(&self._raw_before),
+      &anchored(&self._raw_after),
+      query,
+      fmts,
+      SelectClause::Offset,
+      format!("{offset}{fetch}"),
+    )
+  }
+
+  /// SQL Server has no `LIMIT` clause, when there is no offset the limit is rendered as `TOP n` in the projection.
+  /// When the `postgresql` or `sqlite` feature is also enabled the `LIMIT` and `OFFSET` clauses are rendered instead
+  fn concat_top(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { space, .. } = fmts;
+    if self.has_top() == false {
+      return "".to_string();
+    }
+    let top = format!("{}{space}{}{space}", fmts.kw("TOP"), self._limit);
+    let top = concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      "".to_string(),
+      fmts,
+      SelectClause::Limit,
+      top,
+    );
+    top.trim_end().to_string()
+  }
+
+  fn has_top(&self) -> bool {
+    self._limit.is_empty() == false && self.offset_fetch_start().is_empty()
+  }
+}
+
+#[cfg(feature = "postgresql")]
+impl Select {
+  /// Adds the table to the `from` clause with the alias and the `tablesample` method, an empty method or
+  /// empty arguments of the method will add only the table and the alias
+  pub(crate) fn push_sampled_from(&mut self, table: &str, alias: &str, method: &str, arguments: &str) {
+    let (method, arguments) = (method.trim(), arguments.trim());
+    let mut item = FromItem::table(table).alias(alias);
+    if method.is_empty() == false && arguments.is_empty() == false {
+      item = item.tablesample(method, arguments);
+    }
+
+    self.push_from_item(item);
+  }
+
+  fn concat_for(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._for.is_empty() == false {
+      let strength = fmts.kw(&format!("FOR {}", self._for));
+      if self._for_wait.is_empty() == false {
+        format!("{strength}{space}{}{space}{lb}", fmts.kw(&self._for_wait))
+      } else {
+        format!("{strength}{space}{lb}")
+      }
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, SelectClause::For, sql)
+  }
+
+  fn concat_with_recursive(&self, query: String, fmts: &fmt::Formatter) -> String {
+    use crate::concat::non_standard::with_queries;
+
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._with.is_empty() == false {
+      let with = with_queries(fmts, &self._with, |name| {
+        let search = self
+          ._with_search
+          .iter()
+          .filter(|(query_name, _)| query_name == name)
+          .map(|(_, search)| format!("{}{space}{search}", fmts.kw("SEARCH")));
+        let cycle = self
+          ._with_cycle
+          .iter()
+          .filter(|(query_name, _)| query_name == name)
+          .map(|(_, cycle)| format!("{}{space}{cycle}", fmts.kw("CYCLE")));
+
+        search.chain(cycle).collect::<Vec<_>>().join(space)
+      });
+      let keyword = fmts.kw(if self._with_recursive { "WITH RECURSIVE" } else { "WITH" });
+      format!("{keyword}{space}{lb}{with}{space}{lb}")
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::With,
+      sql,
+    )
+  }
+}
+
+/// The raw SQL added before or after the clause, used to take the values of its placeholders
+#[cfg(feature = "postgresql")]
+fn raw_of_clause(raw_list: &Vec<(SelectClause, String)>, clause: &SelectClause) -> String {
+  crate::concat::raw_queries(raw_list, clause).join(" ")
+}
+
+#[cfg(feature = "postgresql")]
+impl Select {
+  /// The values bound to the builder and to the nested builders in the order their placeholders are rendered,
+  /// the values are collected while the clauses are rendered by the [Select::concat_clauses] method
+  pub(crate) fn ordered_params(&self) -> Vec<crate::structure::ParamValue> {
+    use crate::{behavior::WithQuery, param_value::OrderedParams};
+
+    let fmts = fmt::one_line();
+    let mut params = OrderedParams::new(&self._params);
+
+    self.concat_clauses(&fmts, |part, sql| match part {
+      QueryPart::Sql => params.own(&sql),
+      QueryPart::With => self.params_of_with(&mut params),
+      QueryPart::From => self.params_of_from(&mut params),
+      QueryPart::Where => self.params_of_where(&mut params, &fmts),
+    });
+
+    for combinator in COMBINATORS {
+      let (clause, _, selects) = self.combinator_clause(&combinator);
+      params.own(&raw_of_clause(&self._raw_before, &clause));
+      selects.iter().for_each(|select| params.nested(select.bound_params()));
+      params.own(&raw_of_clause(&self._raw_after, &clause));
+    }
+
+    params.finish()
+  }
+
+  fn params_of_with(&self, params: &mut crate::param_value::OrderedParams) {
+    params.own(&raw_of_clause(&self._raw_before, &SelectClause::With));
+    for (name, query) in self._with.iter() {
+      params.nested(query.bound_params());
+      let search_and_cycle = self._with_search.iter().chain(self._with_cycle.iter());
+      for (_, sql) in search_and_cycle.filter(|(query_name, _)| query_name == name) {
+        params.own(sql);
+      }
+    }
+    params.own(&raw_of_clause(&self._raw_after, &SelectClause::With));
+  }
+
+  fn params_of_from(&self, params: &mut crate::param_value::OrderedParams) {
+    params.own(&raw_of_clause(&self._raw_before, &SelectClause::From));
+    for part in positioned(&self._from, &self._from_item, |(at, _)| *at) {
+      match part {
+        Positioned::Item(table) => params.own(table),
+        Positioned::Nested((_, item)) => item.collect_params(params),
+      }
+    }
+    params.own(&raw_of_clause(&self._raw_after, &SelectClause::From));
+  }
+
+  fn params_of_where(&self, params: &mut crate::param_value::OrderedParams, fmts: &fmt::Formatter) {
+    use crate::behavior::WithQuery;
+
+    params.own(&raw_of_clause(&self._raw_before, &SelectClause::Where));
+    for part in positioned(&self._where, &self._where_nested, |(at, _, _)| *at) {
+      match part {
+        Positioned::Item((_, condition)) => params.own(&condition.to_string()),
+        Positioned::Nested((_, _, NestedCondition::Group(group))) => params.own(&group.concat(fmts)),
+        Positioned::Nested((_, _, NestedCondition::Subquery(_, select))) => params.nested(select.bound_params()),
+      }
+    }
+    params.own(&raw_of_clause(&self._raw_after, &SelectClause::Where));
+  }
+}
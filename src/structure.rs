@@ -42,9 +42,12 @@ pub struct AlterTable {
   pub(crate) _alter_table: String,
   pub(crate) _ordered_actions: Vec<AlterTableActionItem>,
   pub(crate) _raw_after: Vec<(AlterTableAction, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(AlterTableAction, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _rename_to: String,
+  pub(crate) _terminated: bool,
 }
 
 #[derive(PartialEq, Clone)]
@@ -58,6 +61,8 @@ pub(crate) enum AlterTableOrderedAction {
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Rename,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  RenameColumn,
 
   #[cfg(any(feature = "postgresql"))]
   Alter,
@@ -74,11 +79,191 @@ pub enum AlterTableAction {
   RenameTo,
 }
 
+/// Errors returned by the `try_as_string` method of the builders
+///
+/// # Example
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let result = sql::Update::new().update("users").try_as_string();
+///
+/// assert_eq!(result, Err(sql::BuilderError::MissingClause("set")));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuilderError {
+  /// A required clause was not defined, the value is the name of the builder method of the missing clause
+  MissingClause(&'static str),
+  /// The [Delete] has no `where` clause and would delete all rows of the table,
+  /// use the [Delete::allow_full_table] method to opt in
+  UnsafeDelete,
+}
+
+/// An item of the `select`, `join` and `order by` clauses, the keywords added by the builder methods are stored
+/// apart from the expressions passed as argument so they are rendered with the keyword case of the formatter.
+/// Two items with the same text are equal, regardless of the parts they are made of
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct ClauseItem(pub(crate) Vec<ClauseItemPart>);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum ClauseItemPart {
+  Expr(String),
+  Keyword(String),
+}
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+#[derive(Clone)]
 pub(crate) enum Combinator {
   Except,
   Intersect,
   Union,
+  UnionAll,
+}
+
+/// Builder to contruct a compound select, the set operations combine the queries and the outer clauses
+/// like `order by`, `limit`, `offset` and `with` are applied to the whole compound instead of one of the branches.
+/// A branch is wrapped in parentheses only when it has its own `order by`, `limit`, `offset`, `with`, set operations or raw SQL.
+/// Available only for the crate features `postgresql` and `sqlite`.
+///
+/// Basic API
+///
+/// ```
+/// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let users = sql::Select::new().select("login").from("users");
+/// let admins = sql::Select::new().select("login").from("admins");
+///
+/// let query = sql::CompoundSelect::new(users)
+///   .union(admins)
+///   .order_by("login")
+///   .limit("10")
+///   .as_string();
+///
+/// # let expected = "SELECT login FROM users UNION SELECT login FROM admins ORDER BY login LIMIT 10";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT login FROM users UNION SELECT login FROM admins ORDER BY login LIMIT 10
+/// ```
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+#[derive(Default, Clone)]
+pub struct CompoundSelect {
+  pub(crate) _combinators: Vec<(Combinator, Select)>,
+  pub(crate) _limit: String,
+  pub(crate) _offset: String,
+  pub(crate) _order_by: Vec<String>,
+  pub(crate) _raw_after: Vec<(CompoundSelectClause, String)>,
+  pub(crate) _raw_before: Vec<(CompoundSelectClause, String)>,
+  pub(crate) _select: Select,
+  pub(crate) _terminated: bool,
+  pub(crate) _with: Vec<(String, WithBody)>,
+}
+
+/// All available clauses to be used in [CompoundSelect::raw_before], [CompoundSelect::raw_after] and
+/// [CompoundSelect::clear] methods on [CompoundSelect] builder. The `Select` clause is the first query of the
+/// compound and the `Combinator` clause is the set operations added after it
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompoundSelectClause {
+  Combinator,
+  Limit,
+  Offset,
+  OrderBy,
+  Select,
+  With,
+}
+
+/// Builder to contruct a group of conditions, used by the [Select::where_group] method.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let condition = sql::Condition::new()
+///   .and("login = $1")
+///   .or("email = $1")
+///   .as_string();
+///
+/// # let expected = "login = $1 OR email = $1";
+/// # assert_eq!(expected, condition);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// login = $1 OR email = $1
+/// ```
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Condition {
+  pub(crate) _conditions: Vec<(LogicalOperator, ConditionItem)>,
+}
+
+/// The items of the [Condition] builder, the groups are stored as builders and rendered with the formatter
+/// of the query
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum ConditionItem {
+  Condition(String),
+  Group(Condition),
+}
+
+/// Builder to contruct the conditions of the `on` clause of a join, used by the [Select::inner_join_on] method.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let on = sql::OnBuilder::new()
+///   .on("orders.user_id = users.id")
+///   .and("orders.status = 'paid'")
+///   .as_string();
+///
+/// # let expected = "orders.user_id = users.id AND orders.status = 'paid'";
+/// # assert_eq!(expected, on);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// orders.user_id = users.id AND orders.status = 'paid'
+/// ```
+#[derive(Default, Clone)]
+pub struct OnBuilder {
+  pub(crate) _conditions: Vec<(LogicalOperator, String)>,
+}
+
+/// The conflict target of the [Insert::on_conflict_do_nothing] and [Insert::on_conflict_do_update] methods
+///
+/// # Example
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let columns = sql::ConflictTarget::columns(&["login", "email"]);
+/// let constraint = sql::ConflictTarget::constraint("users_login_key");
+///
+/// # assert_eq!(columns, sql::ConflictTarget::Columns(vec!["login".to_string(), "email".to_string()]));
+/// # assert_eq!(constraint, sql::ConflictTarget::Constraint("users_login_key".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConflictTarget {
+  /// The columns of an unique index, renders `(login, email)`
+  Columns(Vec<String>),
+  /// The name of an unique constraint, renders `ON CONSTRAINT users_login_key`
+  Constraint(String),
 }
 
 /// Builder to contruct a [CreateIndex] command. Available only for the crate features `postgresql` and `sqlite`.
@@ -115,12 +300,15 @@ pub struct CreateIndex {
   pub(crate) _if_not_exists: bool,
   pub(crate) _on: String,
   pub(crate) _raw_after: Vec<(CreateIndexParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(CreateIndexParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
   pub(crate) _unique: bool,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _where: Vec<(LogicalOperator, ClauseItem)>,
 
   #[cfg(feature = "postgresql")]
   pub(crate) _concurrently: bool,
@@ -195,10 +383,14 @@ pub struct CreateTable {
   pub(crate) _constraint: Vec<String>,
   pub(crate) _create_table: String,
   pub(crate) _foreign_key: Vec<String>,
+  pub(crate) _if_not_exists: bool,
   pub(crate) _primary_key: String,
   pub(crate) _raw_after: Vec<(CreateTableParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(CreateTableParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
 }
 
 /// All available params to be used in [CreateTable::raw_before] and [CreateTable::raw_after] methods on [CreateTable] builder
@@ -211,6 +403,57 @@ pub enum CreateTableParams {
   PrimaryKey,
 }
 
+/// Builder to contruct a [CreateView] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("login, name")
+///   .from("users")
+///   .where_clause("active = true");
+///
+/// let query = sql::CreateView::new()
+///   .create_view("active_users")
+///   .as_select(select)
+///   .as_string();
+///
+/// # let expected = "CREATE VIEW active_users AS SELECT login, name FROM users WHERE active = true";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// CREATE VIEW active_users AS
+/// SELECT login, name
+/// FROM users
+/// WHERE active = true
+/// ```
+#[derive(Default, Clone)]
+pub struct CreateView {
+  pub(crate) _as_select: Option<Select>,
+  pub(crate) _create_view: String,
+  pub(crate) _raw_after: Vec<(CreateViewParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
+  pub(crate) _raw_before: Vec<(CreateViewParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
+
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  pub(crate) _or_replace: bool,
+}
+
+/// All available params to be used in [CreateView::raw_before] and [CreateView::raw_after] methods on [CreateView] builder
+#[derive(PartialEq, Clone)]
+pub enum CreateViewParams {
+  AsSelect,
+  CreateView,
+}
+
 /// Builder to contruct a [DropIndex] command. Available only for the crate features `postgresql` and `sqlite`.
 ///
 /// Basic API
@@ -241,8 +484,11 @@ pub struct DropIndex {
   pub(crate) _drop_index: Vec<String>,
   pub(crate) _if_exists: bool,
   pub(crate) _raw_after: Vec<(DropIndexParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(DropIndexParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
 }
 
 /// All available params to be used in [DropIndex::raw_before] and [DropIndex::raw_after] methods on [DropIndex] builder
@@ -278,8 +524,11 @@ pub struct DropTable {
   pub(crate) _drop_table: Vec<String>,
   pub(crate) _if_exists: bool,
   pub(crate) _raw_after: Vec<(DropTableParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(DropTableParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
 }
 
 /// All available params to be used in [DropTable::raw_before] and [DropTable::raw_after] methods on [DropTable] builder
@@ -288,6 +537,47 @@ pub enum DropTableParams {
   DropTable,
 }
 
+/// Builder to contruct a [DropView] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::DropView::new()
+///   .drop_view("active_users")
+///   .as_string();
+///
+/// # let expected = "DROP VIEW active_users";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// DROP VIEW active_users
+/// ```
+#[derive(Default, Clone)]
+pub struct DropView {
+  pub(crate) _drop_view: Vec<String>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropViewParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
+  pub(crate) _raw_before: Vec<(DropViewParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _cascade: bool,
+}
+
+/// All available params to be used in [DropView::raw_before] and [DropView::raw_after] methods on [DropView] builder
+#[derive(PartialEq, Clone)]
+pub enum DropViewParams {
+  DropView,
+}
+
 /// Builder to contruct a [Delete] command.
 ///
 /// Basic API
@@ -310,22 +600,40 @@ pub enum DropTableParams {
 /// DELETE FROM users WHERE id = $1
 /// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delete {
+  pub(crate) _allow_full_table: bool,
+  pub(crate) _comment: Vec<String>,
   pub(crate) _delete_from: String,
+  pub(crate) _explain: Explain,
   pub(crate) _raw_after: Vec<(DeleteClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(DeleteClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _terminated: bool,
+  pub(crate) _where: Vec<(LogicalOperator, ClauseItem)>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _returning: Vec<ClauseItem>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(with = "crate::with_query"))]
+  pub(crate) _with: Vec<(String, WithBody)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  pub(crate) _with_recursive: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _join: Vec<ClauseItem>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _using: Vec<String>,
 }
 
 /// All available clauses to be used in [Delete::raw_before] and [Delete::raw_after] methods on [Delete] builder
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeleteClause {
   DeleteFrom,
   Where,
@@ -339,6 +647,88 @@ pub enum DeleteClause {
   #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   With,
+
+  #[cfg(feature = "postgresql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  Join,
+
+  #[cfg(feature = "postgresql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  Using,
+}
+
+/// The `distinct` of the select clause, the [Select::distinct] and [Select::distinct_on] methods are mutually exclusive
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Distinct {
+  #[default]
+  None,
+  Plain,
+
+  #[cfg(feature = "postgresql")]
+  On(Vec<String>),
+}
+
+/// The `explain` prefix of the query, the [Select::explain], [Select::explain_analyze] and `explain_with` methods
+/// override each other
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Explain {
+  #[default]
+  None,
+  Plain,
+  Analyze,
+
+  #[cfg(feature = "postgresql")]
+  Options(String),
+}
+
+/// Builder to contruct an item of the `from` clause, used by the [Select::from_item] method.
+/// The options are composed in the order defined by the SQL syntax, the item is rendered as
+/// `[LATERAL] [ONLY] [schema.]source [*] [AS alias] [TABLESAMPLE method (arguments)]`
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let item = sql::FromItem::table("measurements")
+///   .schema("metrics")
+///   .only()
+///   .alias("m")
+///   .as_string();
+///
+/// # let expected = "ONLY metrics.measurements AS m";
+/// # assert_eq!(expected, item);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// ONLY metrics.measurements AS m
+/// ```
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FromItem {
+  pub(crate) _alias: String,
+  pub(crate) _columns: Vec<String>,
+  pub(crate) _descendants: bool,
+  pub(crate) _lateral: bool,
+  pub(crate) _only: bool,
+  pub(crate) _schema: String,
+  pub(crate) _source: String,
+  pub(crate) _subquery: Option<Box<Select>>,
+  pub(crate) _tablesample: String,
+}
+
+/// The items of the `group by` clause, the keywords of the grouping elements are rendered at concat time
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum GroupByItem {
+  Expr(String),
+  Cube(String),
+  GroupingSets(String),
+  Rollup(String),
 }
 
 /// Builder to contruct a [Insert] command.
@@ -364,40 +754,81 @@ pub enum DeleteClause {
 /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
 /// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Insert {
+  pub(crate) _append: Vec<String>,
+  pub(crate) _comment: Vec<String>,
   pub(crate) _default_values: bool,
-  pub(crate) _on_conflict: String,
+  pub(crate) _explain: Explain,
+  pub(crate) _on_conflict: OnConflict,
+  pub(crate) _on_conflict_where: String,
   pub(crate) _overriding: String,
   pub(crate) _raw_after: Vec<(InsertClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(InsertClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _select: Option<Select>,
+  pub(crate) _terminated: bool,
   pub(crate) _values: Vec<String>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  pub(crate) _returning: Vec<ClauseItem>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(with = "crate::with_query"))]
+  pub(crate) _with: Vec<(String, WithBody)>,
 
-  #[cfg(not(feature = "sqlite"))]
-  pub(crate) _insert_into: String,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with_recursive: bool,
 
-  #[cfg(feature = "sqlite")]
-  pub(crate) _insert: (InsertVars, String),
+  #[cfg(feature = "postgresql")]
+  pub(crate) _params: Vec<ParamValue>,
+
+  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+  pub(crate) _insert_into: InsertTarget,
+
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  pub(crate) _insert: (InsertVars, InsertTarget),
+  #[cfg(feature = "mysql")]
+  pub(crate) _on_duplicate_key_update: Vec<String>,
 }
 
-#[cfg(feature = "sqlite")]
+/// The table and the column list of the insert clauses, split on the first `(`
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct InsertTarget {
+  pub(crate) table: String,
+  pub(crate) columns: String,
+}
+
+/// The action of the on conflict clause, the raw variant holds the value of the [Insert::on_conflict] method
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum OnConflict {
+  #[default]
+  None,
+  Raw(String),
+  DoNothing(ConflictTarget),
+  DoUpdate(ConflictTarget, Vec<(String, ClauseItem)>),
+}
+
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 #[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum InsertVars {
   #[default]
   InsertInto,
+  #[cfg(feature = "sqlite")]
   InsertOr,
   ReplaceInto,
+  #[cfg(feature = "mysql")]
+  InsertIgnore,
 }
 
 /// All available clauses to be used in [Insert::raw_before] and [Insert::raw_after] methods on [Insert] builder
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InsertClause {
   DefaultValues,
   InsertInto,
@@ -420,12 +851,22 @@ pub enum InsertClause {
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   InsertOr,
 
-  #[cfg(feature = "sqlite")]
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
   ReplaceInto,
+
+  #[cfg(feature = "mysql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+  InsertIgnore,
+
+  #[cfg(feature = "mysql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+  OnDuplicateKeyUpdate,
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum LogicalOperator {
   And,
   Or,
@@ -441,6 +882,152 @@ impl std::fmt::Display for LogicalOperator {
   }
 }
 
+/// Builder to contruct a [Merge] command. Available only for the crate feature `postgresql`.
+///
+/// Basic API
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Merge::new()
+///   .merge_into("users u")
+///   .using("new_users n")
+///   .on("u.login = n.login")
+///   .when_matched("UPDATE SET name = n.name")
+///   .when_not_matched("INSERT (login, name) VALUES (n.login, n.name)")
+///   .as_string();
+///
+/// # let expected = "\
+/// #   MERGE INTO users u \
+/// #   USING new_users n \
+/// #   ON u.login = n.login \
+/// #   WHEN MATCHED THEN UPDATE SET name = n.name \
+/// #   WHEN NOT MATCHED THEN INSERT (login, name) VALUES (n.login, n.name)\
+/// # ";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// MERGE INTO users u
+/// USING new_users n
+/// ON u.login = n.login
+/// WHEN MATCHED THEN UPDATE SET name = n.name
+/// WHEN NOT MATCHED THEN INSERT (login, name) VALUES (n.login, n.name)
+/// ```
+#[cfg(feature = "postgresql")]
+#[derive(Default, Clone)]
+pub struct Merge {
+  pub(crate) _merge_into: String,
+  pub(crate) _on: String,
+  pub(crate) _raw_after: Vec<(MergeClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
+  pub(crate) _raw_before: Vec<(MergeClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
+  pub(crate) _using: String,
+  pub(crate) _when: Vec<(MergeWhen, String)>,
+}
+
+/// All available clauses to be used in [Merge::raw_before] and [Merge::raw_after] methods on [Merge] builder
+#[cfg(feature = "postgresql")]
+#[derive(PartialEq, Clone)]
+pub enum MergeClause {
+  MergeInto,
+  On,
+  Using,
+  /// Works both to the `when_matched` and `when_not_matched` methods
+  When,
+}
+
+#[cfg(feature = "postgresql")]
+#[derive(PartialEq, Clone)]
+pub(crate) enum MergeWhen {
+  Matched,
+  NotMatched,
+}
+
+/// The placement of the null values of the [Select::order_by_with] method, the `Default` variant omits
+/// the `nulls` keyword and keeps the database default
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let select = sql::Select::new()
+///   .order_by_with("last_login", sql::Order::Desc, sql::NullsOrder::Last);
+///
+/// # let expected = "ORDER BY last_login DESC NULLS LAST";
+/// # assert_eq!(select.as_string(), expected);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NullsOrder {
+  /// Renders `NULLS FIRST`
+  First,
+  /// Renders `NULLS LAST`
+  Last,
+  /// Renders nothing
+  #[default]
+  Default,
+}
+
+/// The sort direction of the [Select::order_by_with] method
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let select = sql::Select::new()
+///   .order_by_with("login", sql::Order::Asc, sql::NullsOrder::Default);
+///
+/// # let expected = "ORDER BY login ASC";
+/// # assert_eq!(select.as_string(), expected);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Order {
+  /// Renders `ASC`
+  Asc,
+  /// Renders `DESC`
+  Desc,
+}
+
+/// The values bound to the `?` placeholders with the `bind` method of the builders.
+/// Available only for the crate feature `postgresql`.
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let (query, params) = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause("login = ?")
+///   .bind("foo")
+///   .into_parts();
+///
+/// # assert_eq!(query, "SELECT * FROM users WHERE login = $1");
+/// assert_eq!(params, vec![sql::ParamValue::Text("foo".to_string())]);
+/// # }
+/// ```
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamValue {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Text(String),
+}
+
 /// Builder to contruct a [Select] command.
 ///
 /// Basic API
@@ -476,18 +1063,31 @@ impl std::fmt::Display for LogicalOperator {
 /// ORDER BY created_at desc
 /// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Select {
+  pub(crate) _comment: Vec<String>,
+  pub(crate) _distinct: Distinct,
+  pub(crate) _explain: Explain,
+  pub(crate) _fetch: String,
   pub(crate) _from: Vec<String>,
-  pub(crate) _group_by: Vec<String>,
-  pub(crate) _having: Vec<String>,
-  pub(crate) _join: Vec<String>,
-  pub(crate) _order_by: Vec<String>,
+  pub(crate) _from_item: Vec<(usize, FromItem)>,
+  pub(crate) _group_by: Vec<GroupByItem>,
+  pub(crate) _having: Vec<(LogicalOperator, String)>,
+  pub(crate) _join: Vec<ClauseItem>,
+  pub(crate) _offset_rows: String,
+  pub(crate) _order_by: Vec<ClauseItem>,
+  pub(crate) _order_by_ignore_case: bool,
   pub(crate) _raw_after: Vec<(SelectClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(SelectClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
-  pub(crate) _select: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
-  pub(crate) _window: Vec<String>,
+  pub(crate) _select: Vec<ClauseItem>,
+  pub(crate) _terminated: bool,
+  pub(crate) _where: Vec<(LogicalOperator, ClauseItem)>,
+  pub(crate) _where_nested: Vec<(usize, LogicalOperator, NestedCondition)>,
+  pub(crate) _where_join_or: bool,
+  pub(crate) _window: Vec<(String, String)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _except: Vec<Self>,
@@ -495,28 +1095,53 @@ pub struct Select {
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _intersect: Vec<Self>,
 
-  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
   pub(crate) _limit: String,
 
-  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
   pub(crate) _offset: String,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _union: Vec<Self>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, Arc<dyn WithQuery>)>,
+  pub(crate) _union_all: Vec<Self>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[cfg_attr(feature = "serde", serde(with = "crate::with_query"))]
+  pub(crate) _with: Vec<(String, WithBody)>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with_recursive: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _for: String,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _for_wait: String,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _params: Vec<ParamValue>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _with_cycle: Vec<(String, String)>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _with_search: Vec<(String, String)>,
 }
 
 /// All available clauses to be used in [Select::raw_before] and [Select::raw_after] methods on [Select] builder
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectClause {
+  Fetch,
   From,
   GroupBy,
   Having,
   Join,
   Limit,
   Offset,
+  OffsetRows,
   OrderBy,
   Select,
   Where,
@@ -537,10 +1162,19 @@ pub enum SelectClause {
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   Union,
 
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+  UnionAll,
+
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   With,
+
+  #[cfg(feature = "postgresql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  For,
 }
 
 /// Builder to contruct a [Transaction] block.
@@ -590,6 +1224,8 @@ pub enum SelectClause {
 pub struct Transaction {
   pub(crate) _commit: Option<TransactionCommand>,
   pub(crate) _ordered_commands: Vec<Box<dyn TransactionQuery>>,
+  pub(crate) _raw_append: Vec<String>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _set_transaction: Option<TransactionCommand>,
   pub(crate) _start_transaction: Option<TransactionCommand>,
@@ -601,6 +1237,27 @@ pub struct Transaction {
   pub(crate) _end: Option<TransactionCommand>,
 }
 
+/// The conditions of the `where` clause stored as builders, they are rendered with the formatter of the outer query
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum NestedCondition {
+  /// A parenthesized group of conditions
+  Group(Condition),
+  Subquery(SubqueryCondition, Box<Select>),
+}
+
+/// The conditions of the `where` clause composed with a subquery
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum SubqueryCondition {
+  /// `column operator ALL (subquery)`
+  All(String, String),
+  /// `column operator ANY (subquery)`
+  Any(String, String),
+  Exists,
+  NotExists,
+}
+
 /// Commands used in to build a [Transaction]
 #[derive(PartialEq)]
 pub(crate) enum TrCmd {
@@ -631,6 +1288,53 @@ pub(crate) enum TrCmd {
 #[derive(PartialEq)]
 pub(crate) struct TransactionCommand(pub(crate) TrCmd, pub(crate) String);
 
+/// Builder to contruct a [Truncate] command. Available only for the crate feature `postgresql`.
+///
+/// Basic API
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Truncate::new()
+///   .truncate("users")
+///   .truncate("orders")
+///   .restart_identity()
+///   .cascade()
+///   .as_string();
+///
+/// # let expected = "TRUNCATE TABLE users, orders RESTART IDENTITY CASCADE";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// TRUNCATE TABLE users, orders RESTART IDENTITY CASCADE
+/// ```
+#[cfg(feature = "postgresql")]
+#[derive(Default, Clone)]
+pub struct Truncate {
+  pub(crate) _behavior: String,
+  pub(crate) _identity: String,
+  pub(crate) _raw_after: Vec<(TruncateParams, String)>,
+  pub(crate) _raw_append: Vec<String>,
+  pub(crate) _raw_before: Vec<(TruncateParams, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
+  pub(crate) _truncate: Vec<String>,
+}
+
+/// All available params to be used in [Truncate::raw_before] and [Truncate::raw_after] methods on [Truncate] builder
+#[cfg(feature = "postgresql")]
+#[derive(PartialEq, Clone)]
+pub enum TruncateParams {
+  Truncate,
+}
+
 /// Builder to contruct a [Update] command.
 ///
 /// Basic API
@@ -654,34 +1358,51 @@ pub(crate) struct TransactionCommand(pub(crate) TrCmd, pub(crate) String);
 /// UPDATE users SET name = 'Bar' WHERE id = $1
 /// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Update {
+  pub(crate) _comment: Vec<String>,
+  pub(crate) _explain: Explain,
   pub(crate) _raw_after: Vec<(UpdateClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(UpdateClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _set: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _terminated: bool,
+  pub(crate) _where: Vec<(LogicalOperator, ClauseItem)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _from: Vec<String>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  pub(crate) _join: Vec<ClauseItem>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _returning: Vec<ClauseItem>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(with = "crate::with_query"))]
+  pub(crate) _with: Vec<(String, WithBody)>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with_recursive: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _only: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _params: Vec<ParamValue>,
 
   #[cfg(not(feature = "sqlite"))]
   pub(crate) _update: String,
 
   #[cfg(feature = "sqlite")]
   pub(crate) _update: (UpdateVars, String),
-
-  #[cfg(feature = "sqlite")]
-  pub(crate) _join: Vec<String>,
 }
 
 #[cfg(feature = "sqlite")]
 #[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum UpdateVars {
   #[default]
   Update,
@@ -690,6 +1411,7 @@ pub(crate) enum UpdateVars {
 
 /// All available clauses to be used in [Update::raw_before] and [Update::raw_after] methods on [Update] builder
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UpdateClause {
   Set,
   Update,
@@ -700,6 +1422,11 @@ pub enum UpdateClause {
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   From,
 
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+  Join,
+
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
@@ -713,10 +1440,6 @@ pub enum UpdateClause {
   #[cfg(feature = "sqlite")]
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   UpdateOr,
-
-  #[cfg(feature = "sqlite")]
-  #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
-  Join,
 }
 
 /// Builder to contruct a [Values] command.
@@ -743,8 +1466,11 @@ pub enum UpdateClause {
 #[derive(Default, Clone)]
 pub struct Values {
   pub(crate) _raw_after: Vec<(ValuesClause, String)>,
+  pub(crate) _raw_append: Vec<String>,
   pub(crate) _raw_before: Vec<(ValuesClause, String)>,
+  pub(crate) _raw_prepend: Vec<String>,
   pub(crate) _raw: Vec<String>,
+  pub(crate) _terminated: bool,
   pub(crate) _values: Vec<String>,
 }
 
@@ -753,3 +1479,11 @@ pub struct Values {
 pub enum ValuesClause {
   Values,
 }
+
+/// The body of a query of the `with` clause, a builder added by the `with` method or the SQL added by the `with_raw` method
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+#[derive(Clone)]
+pub(crate) enum WithBody {
+  Query(Arc<dyn WithQuery>),
+  Raw(String),
+}
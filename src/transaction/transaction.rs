@@ -1,8 +1,10 @@
 use crate::{
+  behavior::TransactionQuery,
   concat::Concat,
   fmt,
   structure::{
-    AlterTable, CreateTable, Delete, DropTable, Insert, Select, TrCmd::*, Transaction, TransactionCommand, Update,
+    AlterTable, CreateTable, CreateView, Delete, DropTable, DropView, Insert, Select, TrCmd::*, Transaction,
+    TransactionCommand, Update,
   },
   utils::push_unique,
 };
@@ -10,6 +12,9 @@ use crate::{
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 use crate::structure::{CreateIndex, DropIndex};
 
+#[cfg(feature = "postgresql")]
+use crate::structure::{Merge, Truncate};
+
 impl Transaction {
   /// Gets the current state of the [Transaction] and returns it as string
   ///
@@ -40,6 +45,11 @@ impl Transaction {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the [Transaction] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    fmts.finish(self.concat(fmts))
+  }
+
   /// The `commit` command, this method will be always added at the end of the transaction and
   /// all consecutive call will override the previous value
   ///
@@ -105,6 +115,45 @@ impl Transaction {
     self
   }
 
+  /// Adds any builder that can be used in a transaction, the commands are rendered in the order they were added
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("isolation level serializable")
+  ///   .add(sql::Update::new().update("users").set("active = false"))
+  ///   .add(sql::Delete::new().delete_from("sessions"))
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION isolation level serializable; \
+  /// #   UPDATE users SET active = false; \
+  /// #   DELETE FROM sessions; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION isolation level serializable;
+  /// UPDATE users SET active = false;
+  /// DELETE FROM sessions;
+  /// COMMIT;
+  /// ```
+  pub fn add(mut self, query: impl TransactionQuery + 'static) -> Self {
+    let cmd = Box::new(query);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
   /// The `alter table` command, access the [AlterTable] for more info
   ///
   /// # Example
@@ -189,6 +238,47 @@ impl Transaction {
     self
   }
 
+  /// The `create view` command, access the [CreateView] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new().select("login").from("users");
+  /// let create_logins = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .as_select(select);
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .create_view(create_logins)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   CREATE VIEW user_logins AS SELECT login FROM users; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// CREATE VIEW user_logins AS SELECT login FROM users;
+  /// COMMIT;
+  /// ```
+  pub fn create_view(mut self, create_view: CreateView) -> Self {
+    let cmd = Box::new(create_view);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
   /// The `delete` command, access the [Delete] for more info
   ///
   /// # Example
@@ -269,6 +359,44 @@ impl Transaction {
     self._ordered_commands.push(cmd);
     self
   }
+  /// The `drop view` command, access the [DropView] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_logins = sql::DropView::new()
+  ///   .drop_view("user_logins");
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .drop_view(drop_logins)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   DROP VIEW user_logins; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// DROP VIEW user_logins;
+  /// COMMIT;
+  /// ```
+  pub fn drop_view(mut self, drop_view: DropView) -> Self {
+    let cmd = Box::new(drop_view);
+    self._ordered_commands.push(cmd);
+    self
+  }
 
   /// The `insert` command, access the [Insert] for more info
   ///
@@ -360,6 +488,62 @@ impl Transaction {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let transaction_query = sql::Transaction::new()
+  ///   .commit("")
+  ///   .raw_append("/* end of the transaction command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "COMMIT; /* end of the transaction command */";
+  /// # assert_eq!(transaction_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// COMMIT; /* end of the transaction command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](Transaction::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let transaction_query = sql::Transaction::new()
+  ///   .commit("")
+  ///   .raw_prepend("/* transaction command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* transaction command */ COMMIT;";
+  /// # assert_eq!(transaction_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* transaction command */ COMMIT;
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
   /// The `release savepoint` command
   ///
   /// # Example
@@ -743,6 +927,92 @@ impl Transaction {
   }
 }
 
+#[cfg(feature = "postgresql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl Transaction {
+  /// The `merge` command, access the [Merge] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let merge_users = sql::Merge::new()
+  ///   .merge_into("users u")
+  ///   .using("new_users n")
+  ///   .on("u.login = n.login")
+  ///   .when_matched("DELETE");
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .merge(merge_users)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DELETE; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// MERGE INTO users u USING new_users n ON u.login = n.login WHEN MATCHED THEN DELETE;
+  /// COMMIT;
+  /// ```
+  pub fn merge(mut self, merge: Merge) -> Self {
+    let cmd = Box::new(merge);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
+  /// The `truncate` command, access the [Truncate] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let truncate_users = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .restart_identity();
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .truncate(truncate_users)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   TRUNCATE TABLE users RESTART IDENTITY; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// TRUNCATE TABLE users RESTART IDENTITY;
+  /// COMMIT;
+  /// ```
+  pub fn truncate(mut self, truncate: Truncate) -> Self {
+    let cmd = Box::new(truncate);
+    self._ordered_commands.push(cmd);
+    self
+  }
+}
+
 impl std::fmt::Display for Transaction {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     write!(f, "{}", self.as_string())
@@ -14,6 +14,7 @@ impl Concat for Transaction {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -34,6 +35,8 @@ impl Concat for Transaction {
       query = self.concat_end(query, &fmts);
     }
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
@@ -47,20 +50,20 @@ impl Concat for TransactionCommand {
       format!("{space}{0}", self.1)
     };
     match self.0 {
-      Commit => format!("COMMIT{arg}"),
-      ReleaseSavepoint => format!("RELEASE SAVEPOINT{arg}"),
-      Rollback => format!("ROLLBACK{arg}"),
-      Savepoint => format!("SAVEPOINT{arg}"),
+      Commit => format!("{}{arg}", fmts.kw("COMMIT")),
+      ReleaseSavepoint => format!("{}{arg}", fmts.kw("RELEASE SAVEPOINT")),
+      Rollback => format!("{}{arg}", fmts.kw("ROLLBACK")),
+      Savepoint => format!("{}{arg}", fmts.kw("SAVEPOINT")),
 
       #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-      Begin => format!("BEGIN{arg}"),
+      Begin => format!("{}{arg}", fmts.kw("BEGIN")),
       #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-      End => format!("END{arg}"),
+      End => format!("{}{arg}", fmts.kw("END")),
 
       #[cfg(not(feature = "sqlite"))]
-      SetTransaction => format!("SET TRANSACTION{arg}"),
+      SetTransaction => format!("{}{arg}", fmts.kw("SET TRANSACTION")),
       #[cfg(not(feature = "sqlite"))]
-      StartTransaction => format!("START TRANSACTION{arg}"),
+      StartTransaction => format!("{}{arg}", fmts.kw("START TRANSACTION")),
     }
   }
 }
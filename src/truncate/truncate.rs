@@ -0,0 +1,431 @@
+use crate::{
+  behavior::TransactionQuery,
+  concat::Concat,
+  fmt,
+  structure::{Truncate, TruncateParams},
+  utils::{push_unique, terminate},
+};
+
+impl TransactionQuery for Truncate {}
+
+impl Truncate {
+  /// Gets the current state of the [Truncate] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Truncate] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// The `cascade` option, also truncates the tables that have foreign-key references to the truncated tables.
+  /// This method overrides the [restrict](Truncate::restrict) option
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users CASCADE";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users CASCADE
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._behavior = "CASCADE".to_string();
+    self
+  }
+
+  /// The `continue identity` option, the sequences owned by the columns are not changed.
+  /// This method overrides the [restart_identity](Truncate::restart_identity) option
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .continue_identity()
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users CONTINUE IDENTITY";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users CONTINUE IDENTITY
+  /// ```
+  pub fn continue_identity(mut self) -> Self {
+    self._identity = "CONTINUE IDENTITY".to_string();
+    self
+  }
+
+  /// Prints the current state of the [Truncate] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .debug()
+  ///   .as_string();
+  /// # }
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// TRUNCATE TABLE users
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Creates instance of the [Truncate] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [Truncate] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex truncate command.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .raw("/* truncate command */")
+  ///   .truncate("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* truncate command */ TRUNCATE TABLE users";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* truncate command */ TRUNCATE TABLE users
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_string());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .raw_after(sql::TruncateParams::Truncate, "/* end truncate */")
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users /* end truncate */";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users /* end truncate */
+  /// ```
+  pub fn raw_after(mut self, param: TruncateParams, raw_sql: &str) -> Self {
+    self._raw_after.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .raw_append("/* end of the truncate command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users /* end of the truncate command */";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users /* end of the truncate command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a raw SQL query before a specified parameter.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .raw_before(sql::TruncateParams::Truncate, "/* truncate command */")
+  ///   .truncate("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* truncate command */ TRUNCATE TABLE users";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* truncate command */ TRUNCATE TABLE users
+  /// ```
+  pub fn raw_before(mut self, param: TruncateParams, raw_sql: &str) -> Self {
+    self._raw_before.push((param, raw_sql.trim().to_string()));
+    self
+  }
+
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](Truncate::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .raw_prepend("/* truncate command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* truncate command */ TRUNCATE TABLE users";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* truncate command */ TRUNCATE TABLE users
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// The `restart identity` option, the sequences owned by the columns of the truncated tables are restarted.
+  /// This method overrides the [continue_identity](Truncate::continue_identity) option
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .restart_identity()
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users RESTART IDENTITY";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users RESTART IDENTITY
+  /// ```
+  pub fn restart_identity(mut self) -> Self {
+    self._identity = "RESTART IDENTITY".to_string();
+    self
+  }
+
+  /// The `restrict` option, refuses to truncate when any of the tables have foreign-key references from other tables.
+  /// This method overrides the [cascade](Truncate::cascade) option
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .cascade()
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users RESTRICT";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users RESTRICT
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._behavior = "RESTRICT".to_string();
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users;";
+  /// # assert_eq!(query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users;
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// Defines a table to be truncated, multiples call will concatenates all values
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .truncate("orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "TRUNCATE TABLE users, orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users, orders
+  /// ```
+  pub fn truncate(mut self, table: &str) -> Self {
+    let table = table.trim();
+    if table.is_empty() == false {
+      push_unique(&mut self._truncate, table.to_string());
+    }
+    self
+  }
+}
+
+impl std::fmt::Display for Truncate {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Truncate {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
@@ -0,0 +1,47 @@
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{Truncate, TruncateParams},
+};
+
+impl Concat for Truncate {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_string();
+
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_truncate(query, &fmts);
+
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
+    query.trim_end().to_string()
+  }
+}
+
+impl Truncate {
+  fn concat_truncate(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+
+    let sql = if self._truncate.is_empty() == false {
+      let table_names = self._truncate.join(comma);
+      let options = [&self._identity, &self._behavior]
+        .into_iter()
+        .filter(|option| option.is_empty() == false)
+        .map(|option| format!("{space}{}", fmts.kw(option)))
+        .collect::<String>();
+
+      format!("{}{space}{table_names}{options}{space}{lb}", fmts.kw("TRUNCATE TABLE"))
+    } else {
+      "".to_string()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      TruncateParams::Truncate,
+      sql,
+    )
+  }
+}
@@ -2,8 +2,8 @@ use crate::{
   behavior::TransactionQuery,
   concat::Concat,
   fmt,
-  structure::{LogicalOperator, Update, UpdateClause},
-  utils::push_unique,
+  structure::{BuilderError, ClauseItem, Explain, LogicalOperator, Update, UpdateClause},
+  utils::{block_comment, in_condition, push_unique, remove_raw_of_clause, terminate},
 };
 
 impl TransactionQuery for Update {}
@@ -31,7 +31,167 @@ impl Update {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Update] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("name = 'Bar'")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET name = 'Bar';";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET name = 'Bar';
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
+  /// Gets the current state of the [Update] and returns it as string, returns an error when a required clause is missing,
+  /// an [Update] must define the `update` and `set` clauses. The builders used with the `raw` method are not verified
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let result = sql::Update::new()
+  ///   .update("users")
+  ///   .try_as_string();
+  ///
+  /// assert_eq!(result, Err(sql::BuilderError::MissingClause("set")));
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuilderError> {
+    match self.missing_clause() {
+      Some(clause) => Err(BuilderError::MissingClause(clause)),
+      None => Ok(self.as_string()),
+    }
+  }
+
+  /// Removes the clause passed as argument and the raw SQL queries added with [raw_before](Update::raw_before)
+  /// and [raw_after](Update::raw_after) bound to it, clearing a clause that was never defined has no effect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let template = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'");
+  ///
+  /// let update = template.clear(sql::UpdateClause::Where);
+  ///
+  /// # let expected = "UPDATE users SET active = false";
+  /// # assert_eq!(update.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false
+  /// ```
+  pub fn clear(mut self, clause: UpdateClause) -> Self {
+    match clause {
+      UpdateClause::Set => self._set.clear(),
+      #[cfg(not(feature = "sqlite"))]
+      UpdateClause::Update => self.clear_update(),
+      #[cfg(feature = "sqlite")]
+      UpdateClause::Update => self.clear_update(UpdateVars::Update),
+      UpdateClause::Where => self._where.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      UpdateClause::From => self._from.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      UpdateClause::Join => self._join.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      UpdateClause::Returning => self._returning.clear(),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      UpdateClause::With => {
+        self._with.clear();
+        self._with_recursive = false;
+      }
+      #[cfg(feature = "sqlite")]
+      UpdateClause::UpdateOr => self.clear_update(UpdateVars::UpdateOr),
+    }
+    remove_raw_of_clause(&mut self._raw_before, &clause);
+    remove_raw_of_clause(&mut self._raw_after, &clause);
+    self
+  }
+
+  /// Adds a block comment at the start of the query, useful to trace the code that generated the query
+  /// in the database logs. The comment is rendered in a single line and a `*/` in the text can't close it early
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .comment("users#deactivate")
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* users#deactivate */ UPDATE users SET active = false";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* users#deactivate */ UPDATE users SET active = false
+  /// ```
+  pub fn comment(mut self, text: &str) -> Self {
+    if text.trim().is_empty() == false {
+      push_unique(&mut self._comment, block_comment(text));
+    }
+    self
+  }
+
+  /// Adds a block comment before the clause passed as argument, the comment is escaped like the
+  /// [comment](Self::comment) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .comment_on(sql::UpdateClause::Set, "keeps the login")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users /* keeps the login */ SET active = false";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users /* keeps the login */ SET active = false
+  /// ```
+  pub fn comment_on(self, clause: UpdateClause, text: &str) -> Self {
+    if text.trim().is_empty() {
+      return self;
+    }
+    self.raw_before(clause, &block_comment(text))
   }
 
   /// Prints the current state of the [Update] to the standard output in a more ease to read version.
@@ -61,7 +221,123 @@ impl Update {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
+    self
+  }
+
+  /// The `set` clause decrementing the column by a value or an expression, rendered as `column = column - by`.
+  /// This method overrides the previous assignment of the same column
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("products")
+  ///   .decrement("stock", "1")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE products SET stock = stock - 1";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE products SET stock = stock - 1
+  /// ```
+  pub fn decrement(mut self, column: &str, by: &str) -> Self {
+    let column = column.trim();
+    if column.is_empty() == false {
+      self.push_assignment(column, format!("{column} = {column} - {}", by.trim()));
+    }
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN` to show its execution plan, the prefix is placed before the `with` clause.
+  /// This method overrides the previous value of the [explain_analyze](Update::explain_analyze) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .set("login = 'foo'")
+  ///   .where_clause("id = 1")
+  ///   .explain()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN SET login = 'foo' WHERE id = 1";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN SET login = 'foo' WHERE id = 1
+  /// ```
+  pub fn explain(mut self) -> Self {
+    self._explain = Explain::Plain;
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN ANALYZE`, the query is executed to show its actual execution plan.
+  /// This method overrides the previous value of the [explain](Update::explain) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .set("login = 'foo'")
+  ///   .where_clause("id = 1")
+  ///   .explain_analyze()
+  ///   .as_string();
+  ///
+  /// # let expected = "EXPLAIN ANALYZE SET login = 'foo' WHERE id = 1";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN ANALYZE SET login = 'foo' WHERE id = 1
+  /// ```
+  pub fn explain_analyze(mut self) -> Self {
+    self._explain = Explain::Analyze;
+    self
+  }
+
+  /// The `set` clause incrementing the column by a value or an expression, rendered as `column = column + by`.
+  /// This method overrides the previous assignment of the same column
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("posts")
+  ///   .increment("views", "1")
+  ///   .increment("score", "$1 * 2")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE posts SET views = views + 1, score = score + $1 * 2";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE posts SET views = views + 1, score = score + $1 * 2
+  /// ```
+  pub fn increment(mut self, column: &str, by: &str) -> Self {
+    let column = column.trim();
+    if column.is_empty() == false {
+      self.push_assignment(column, format!("{column} = {column} + {}", by.trim()));
+    }
     self
   }
 
@@ -74,11 +350,15 @@ impl Update {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
-  /// Adds at the beginning a raw SQL query.
+  /// Adds at the beginning a raw SQL query, after the comment and the explain prefix.
+  /// Use the [raw_prepend](Update::raw_prepend) method to add it at the very start
   ///
   /// # Example
   ///
@@ -134,6 +414,35 @@ impl Update {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .raw_append("/* end of the update command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false /* end of the update command */";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false /* end of the update command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified clause.
   ///
   /// # Example
@@ -162,7 +471,38 @@ impl Update {
     self
   }
 
-  /// The `set` clause
+  /// Adds a raw SQL query at the very start, before the comment and the explain prefix,
+  /// unlike the [raw](Update::raw) method that is rendered after them.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .raw_prepend("/* update command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* update command */ UPDATE users SET active = false";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* update command */ UPDATE users SET active = false
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// The `set` clause, consecutive calls accumulate the assignments. An assignment in the form `column = value`
+  /// replaces a previous [increment](Update::increment) or [decrement](Update::decrement) of the same column
   ///
   /// # Example
   ///
@@ -182,7 +522,45 @@ impl Update {
   /// SET name = 'Bar'
   /// ```
   pub fn set(mut self, value: &str) -> Self {
-    push_unique(&mut self._set, value.trim().to_string());
+    self.push_set(value.trim().to_string());
+    self
+  }
+
+  /// The `set` clause from a list of column and value pairs, each pair is rendered as `column = value`.
+  /// A pair overrides the previous assignment of the same column and the pairs with an empty column are skipped
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .set("name = 'Foo'")
+  ///   .set_from([("name", "'Bar'"), ("active", "true")])
+  ///   .as_string();
+  ///
+  /// # let expected = "SET name = 'Bar', active = true";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SET name = 'Bar', active = true
+  /// ```
+  pub fn set_from<I, K, V>(mut self, pairs: I) -> Self
+  where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+  {
+    for (column, value) in pairs {
+      let column = column.as_ref().trim();
+      if column.is_empty() {
+        continue;
+      }
+      let assignment = format!("{column} = {}", value.as_ref().trim());
+      self.push_assignment(column, assignment);
+    }
     self
   }
 
@@ -257,7 +635,113 @@ impl Update {
   ///   AND status = 'deactivated'
   /// ```
   pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::And, ClauseItem::default().expr(condition.trim())),
+    );
+    self
+  }
+
+  /// Same as the [where_clause](Update::where_clause) method but receives an optional condition,
+  /// the builder is returned unchanged when the condition is `None` or empty
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login: Option<&str> = None;
+  /// let update_query = sql::Update::new()
+  ///   .where_clause("status = 'active'")
+  ///   .where_clause_opt(login)
+  ///   .where_clause_opt(Some("created_at >= $1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE status = 'active' AND created_at >= $1";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// WHERE
+  ///   status = 'active'
+  ///   AND created_at >= $1
+  /// ```
+  pub fn where_clause_opt(self, condition: Option<&str>) -> Self {
+    match condition {
+      Some(condition) if condition.trim().is_empty() == false => self.where_clause(condition),
+      _ => self,
+    }
+  }
+
+  /// The `where` clause with the condition `column IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always false `1 = 0` condition instead of the invalid `IN ()`.
+  /// This method is concatenated like the [where_clause](Update::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .set("active = false")
+  ///   .where_in("id", ["1", "2", "3"])
+  ///   .as_string();
+  ///
+  /// # let expected = "SET active = false WHERE id IN (1, 2, 3)";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// SET active = false
+  /// WHERE id IN (1, 2, 3)
+  /// ```
+  pub fn where_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, false);
+    if condition.is_empty() == false {
+      push_unique(&mut self._where, (LogicalOperator::And, condition));
+    }
+    self
+  }
+
+  /// The `where` clause with the condition `column NOT IN (v1, v2, ...)`, the values are rendered as is.
+  /// An empty list of values renders the always true `1 = 1` condition instead of the invalid `NOT IN ()`.
+  /// This method is concatenated like the [where_clause](Update::where_clause) method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let statuses = vec!["'banned'".to_string(), "'deleted'".to_string()];
+  /// let update_query = sql::Update::new()
+  ///   .set("active = false")
+  ///   .where_not_in("status", &statuses)
+  ///   .as_string();
+  ///
+  /// # let expected = "SET active = false WHERE status NOT IN ('banned', 'deleted')";
+  /// # assert_eq!(update_query, expected);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// SET active = false
+  /// WHERE status NOT IN ('banned', 'deleted')
+  /// ```
+  pub fn where_not_in<I, V>(mut self, column: &str, values: I) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+  {
+    let condition = in_condition(column, values, true);
+    if condition.is_empty() == false {
+      push_unique(&mut self._where, (LogicalOperator::And, condition));
+    }
     self
   }
 
@@ -285,21 +769,65 @@ impl Update {
   ///   OR login = 'bar'
   /// ```
   pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+    push_unique(
+      &mut self._where,
+      (LogicalOperator::Or, ClauseItem::default().expr(condition.trim())),
+    );
     self
   }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::behavior::WithQuery;
+use crate::{
+  behavior::{Join, WithQuery},
+  structure::WithBody,
+  utils::push_returning,
+};
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl WithQuery for Update {}
+impl WithQuery for Update {
+  #[cfg(feature = "postgresql")]
+  fn bound_params(&self) -> Vec<ParamValue> {
+    let nested_with = self._with.iter().flat_map(|(_, query)| query.bound_params());
+
+    nested_with.chain(self._params.iter().cloned()).collect()
+  }
+}
 
 #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Update {
+  /// The `cross join` clause, the join needs a table of the `from` clause to join with, without a table added with the
+  /// [Update::from] method the joined table is promoted to the `from` clause.
+  /// A cross join has no join condition so an `on` condition passed as argument is not rendered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .from("users")
+  ///   .cross_join("orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users CROSS JOIN orders";
+  /// # assert_eq!(update_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users CROSS JOIN orders
+  /// ```
+  pub fn cross_join(mut self, table: &str) -> Self {
+    self.push_cross_join(table);
+    self
+  }
+
   /// The `from` clause
   ///
   /// # Example
@@ -340,6 +868,84 @@ impl Update {
     self
   }
 
+  /// Gets the output names defined in the `returning` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .returning("id")
+  ///   .returning("login");
+  ///
+  /// assert_eq!(update.get_returning(), ["id", "login"]);
+  /// # }
+  /// ```
+  pub fn get_returning(&self) -> Vec<String> {
+    self._returning.iter().map(|item| item.to_string()).collect()
+  }
+
+  /// The `inner join` clause, the join needs a table of the `from` clause to join with, without a table added with the
+  /// [Update::from] method the joined table is promoted to the `from` clause and its `on` condition becomes the first
+  /// condition of the `where` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .from("users")
+  ///   .inner_join("orders on orders.owner_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users INNER JOIN orders on orders.owner_login = users.login";
+  /// # assert_eq!(update_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users INNER JOIN orders on orders.owner_login = users.login
+  /// ```
+  pub fn inner_join(mut self, table: &str) -> Self {
+    self.push_join("INNER JOIN", table);
+    self
+  }
+
+  /// The `left join` clause, the join needs a table of the `from` clause to join with, without a table added with the
+  /// [Update::from] method the joins are not rendered since an outer join can't be promoted to the `from` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .from("users")
+  ///   .left_join("orders on orders.owner_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users LEFT JOIN orders on orders.owner_login = users.login";
+  /// # assert_eq!(update_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users LEFT JOIN orders on orders.owner_login = users.login
+  /// ```
+  pub fn left_join(mut self, table: &str) -> Self {
+    self.push_join("LEFT JOIN", table);
+    self
+  }
+
   /// The `returning` clause
   ///
   /// # Example
@@ -363,7 +969,105 @@ impl Update {
   /// RETURNING name, login
   /// ```
   pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+    push_returning(&mut self._returning, ClauseItem::default().expr(output_name.trim()));
+    self
+  }
+
+  /// The `returning` clause with all the columns of the affected rows, the output names defined by the
+  /// [returning](Update::returning) method are replaced by `*` and an output name defined after this method replaces the `*`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .returning("id")
+  ///   .returning("login")
+  ///   .returning_all()
+  ///   .as_string();
+  ///
+  /// # let expected = "RETURNING *";
+  /// # assert_eq!(update_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// RETURNING *
+  /// ```
+  pub fn returning_all(mut self) -> Self {
+    push_returning(&mut self._returning, ClauseItem::default().expr("*"));
+    self
+  }
+
+  /// The `returning` clause with an expression and its alias, rendered as `expr AS alias`. An output name with the
+  /// same expression, like the one added by `returning("id")`, is replaced keeping its position
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .set("login = 'foo'")
+  ///   .returning_as("id", "pk")
+  ///   .returning("login");
+  ///
+  /// # let expected = "SET login = 'foo' RETURNING id AS pk, login";
+  /// # assert_eq!(update.to_string(), expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SET login = 'foo' RETURNING id AS pk, login
+  /// ```
+  pub fn returning_as(mut self, expr: &str, alias: &str) -> Self {
+    let (expr, alias) = (expr.trim(), alias.trim());
+    if expr.is_empty() == false && alias.is_empty() == false {
+      let output_name = ClauseItem::default()
+        .expr(expr)
+        .expr(" ")
+        .keyword("AS")
+        .expr(&format!(" {alias}"));
+      push_returning(&mut self._returning, output_name);
+    } else {
+      push_returning(&mut self._returning, ClauseItem::default().expr(expr));
+    }
+    self
+  }
+
+  /// The `right join` clause, the join needs a table of the `from` clause to join with, without a table added with the
+  /// [Update::from] method the joins are not rendered since an outer join can't be promoted to the `from` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .from("users")
+  ///   .right_join("orders on orders.owner_login = users.login")
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users RIGHT JOIN orders on orders.owner_login = users.login";
+  /// # assert_eq!(update_query, expected);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users RIGHT JOIN orders on orders.owner_login = users.login
+  /// ```
+  pub fn right_join(mut self, table: &str) -> Self {
+    self.push_join("RIGHT JOIN", table);
     self
   }
 
@@ -419,92 +1123,155 @@ impl Update {
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub fn with(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
     self
   }
-}
 
-#[cfg(feature = "sqlite")]
-use crate::structure::UpdateVars;
-
-#[cfg(any(doc, feature = "sqlite"))]
-#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
-impl Update {
-  /// The `cross join` clause
+  /// The `with` clause with the query written as SQL, the query is wrapped in `name AS (...)` in the original
+  /// order with the queries added by the `with` method
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(feature = "sqlite")]
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let update_query = sql::Update::new()
-  ///   .cross_join("orders")
+  /// let query = sql::Update::new()
+  ///   .with_raw("inactive_users", "select id from users where active = false")
+  ///   .update("orders")
+  ///   .set("status = 'canceled'")
+  ///   .where_clause("owner_id in (select id from inactive_users)")
   ///   .as_string();
   ///
-  /// # let expected = "CROSS JOIN orders";
-  /// # assert_eq!(update_query, expected);
+  /// # let expected = "WITH inactive_users AS (select id from users where active = false) UPDATE orders SET status = 'canceled' WHERE owner_id in (select id from inactive_users)";
+  /// # assert_eq!(query, expected);
   /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// CROSS JOIN orders
+  /// WITH inactive_users AS (select id from users where active = false) UPDATE orders SET status = 'canceled' WHERE owner_id in (select id from inactive_users)
   /// ```
-  pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let table = format!("CROSS JOIN {table}");
-      push_unique(&mut self._join, table);
-    }
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_raw(mut self, name: &str, sql: &str) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Raw(sql.trim().to_string())));
     self
   }
 
-  /// The `inner join` clause
+  /// The `with` clause marked as `recursive`, the query can reference itself by the `name`. When any query is
+  /// added by this method the whole `with` clause is rendered as `with recursive`
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(feature = "sqlite")]
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   /// # {
   /// # use sql_query_builder as sql;
-  /// let update_query = sql::Update::new()
-  ///   .inner_join("orders on orders.owner_login = users.login")
+  /// let base = sql::Select::new().select("1 as n");
+  /// let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+  ///
+  /// let query = sql::Update::new()
+  ///   .with_recursive("t", sql::Select::recursive(base, step))
+  ///   .update("numbers")
+  ///   .set("checked = true")
+  ///   .where_clause("n in (select n from t)")
   ///   .as_string();
   ///
-  /// # let expected = "INNER JOIN orders on orders.owner_login = users.login";
-  /// # assert_eq!(update_query, expected);
+  /// # let expected = "\
+  /// #   WITH RECURSIVE t AS (\
+  /// #     SELECT 1 as n \
+  /// #     UNION ALL \
+  /// #     SELECT n + 1 FROM t WHERE n < 10\
+  /// #   ) \
+  /// #   UPDATE numbers SET checked = true WHERE n in (select n from t)\
+  /// # ";
+  /// # assert_eq!(query, expected);
   /// # }
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// INNER JOIN orders on orders.owner_login = users.login
+  /// WITH RECURSIVE t AS (
+  ///   SELECT 1 as n
+  ///   UNION ALL
+  ///   SELECT n + 1 FROM t WHERE n < 10
+  /// )
+  /// UPDATE numbers SET checked = true WHERE n in (select n from t)
   /// ```
-  pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let table = format!("INNER JOIN {table}");
-      push_unique(&mut self._join, table);
-    }
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub fn with_recursive(mut self, name: &str, query: impl WithQuery + 'static) -> Self {
+    self
+      ._with
+      .push((name.trim().to_string(), WithBody::Query(std::sync::Arc::new(query))));
+    self._with_recursive = true;
     self
   }
+}
 
-  /// The `left join` clause
+#[cfg(feature = "postgresql")]
+use crate::param_value::number_placeholders;
+#[cfg(any(doc, feature = "postgresql"))]
+use crate::structure::ParamValue;
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl Update {
+  /// Binds a value to the next `?` placeholder of the query. The placeholders are rewritten to the numbered
+  /// form `$1`, `$2`, ... by the [into_parts](Update::into_parts) method, the numbering continues across
+  /// the nested builders of the `with` clause and the set operations
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(feature = "sqlite")]
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .set("name = ?")
+  ///   .bind("Foo")
+  ///   .where_clause("login = ?")
+  ///   .bind("foo")
+  ///   .into_parts();
+  ///
+  /// # assert_eq!(query, "UPDATE users SET name = $1 WHERE login = $2");
+  /// # assert_eq!(params, vec![sql::ParamValue::Text("Foo".to_string()), sql::ParamValue::Text("foo".to_string())]);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET name = $1 WHERE login = $2
+  /// ```
+  pub fn bind(mut self, value: impl Into<ParamValue>) -> Self {
+    self._params.push(value.into());
+    self
+  }
+
+  /// Prefixes the query with `EXPLAIN (options)`, the options are rendered as is.
+  /// This method overrides the previous value of the [explain](Update::explain) and
+  /// [explain_analyze](Update::explain_analyze) methods, an empty options is the same as the `explain` method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
-  ///   .left_join("orders on orders.owner_login = users.login")
+  ///   .set("login = 'foo'")
+  ///   .where_clause("id = 1")
+  ///   .explain_with("FORMAT JSON, ANALYZE")
   ///   .as_string();
   ///
-  /// # let expected = "LEFT JOIN orders on orders.owner_login = users.login";
+  /// # let expected = "EXPLAIN (FORMAT JSON, ANALYZE) SET login = 'foo' WHERE id = 1";
   /// # assert_eq!(update_query, expected);
   /// # }
   /// ```
@@ -512,30 +1279,43 @@ impl Update {
   /// Output
   ///
   /// ```sql
-  /// LEFT JOIN orders on orders.owner_login = users.login
+  /// EXPLAIN (FORMAT JSON, ANALYZE) SET login = 'foo' WHERE id = 1
   /// ```
-  pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let table = format!("LEFT JOIN {table}");
-      push_unique(&mut self._join, table);
-    }
+  pub fn explain_with(mut self, options: &str) -> Self {
+    let options = options.trim();
+    self._explain = if options.is_empty() {
+      Explain::Plain
+    } else {
+      Explain::Options(options.to_string())
+    };
     self
   }
 
-  /// The `right join` clause
+  /// Returns the query with the `?` placeholders rewritten to the numbered form and the values bound
+  /// with the [bind](Update::bind) method, including the values of the nested builders in the order of the placeholders.
+  /// The `?` inside single quoted strings, double quoted identifiers and comments are preserved, the `??` escapes
+  /// the `?`, `?|` and `?&` operators of the `jsonb` type and it's rendered as `?`
+  pub fn into_parts(self) -> (String, Vec<ParamValue>) {
+    let query = number_placeholders(&self.as_string());
+    (query, self.bound_params())
+  }
+
+  /// Adds the `only` keyword to the `update` clause, only the table defined will be updated excluding
+  /// the inheritance children. Calling this method more than once has no effect
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(feature = "sqlite")]
+  /// # #[cfg(feature = "postgresql")]
   /// # {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
-  ///   .right_join("orders on orders.owner_login = users.login")
+  ///   .update("cities")
+  ///   .only()
+  ///   .set("population = population + 1")
   ///   .as_string();
   ///
-  /// # let expected = "RIGHT JOIN orders on orders.owner_login = users.login";
+  /// # let expected = "UPDATE ONLY cities SET population = population + 1";
   /// # assert_eq!(update_query, expected);
   /// # }
   /// ```
@@ -543,17 +1323,20 @@ impl Update {
   /// Output
   ///
   /// ```sql
-  /// RIGHT JOIN orders on orders.owner_login = users.login
+  /// UPDATE ONLY cities SET population = population + 1
   /// ```
-  pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    if table.is_empty() == false {
-      let table = format!("RIGHT JOIN {table}");
-      push_unique(&mut self._join, table);
-    }
+  pub fn only(mut self) -> Self {
+    self._only = true;
     self
   }
+}
+
+#[cfg(feature = "sqlite")]
+use crate::structure::UpdateVars;
 
+#[cfg(any(doc, feature = "sqlite"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl Update {
   /// The `update` clause, this method overrides the previous value
   ///
   /// # Example
@@ -609,6 +1392,27 @@ impl std::fmt::Display for Update {
 impl std::fmt::Debug for Update {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
+  }
+}
+
+/// Two builders are equal when they render the same SQL with the [one_line](fmt::one_line) formatter,
+/// the order the methods were called and the values bound with the `bind` method are not compared
+impl PartialEq for Update {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_string() == other.as_string()
+  }
+}
+
+impl Eq for Update {}
+
+/// The hash of the rendered SQL, consistent with the [PartialEq] implementation
+impl std::hash::Hash for Update {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.as_string().hash(state);
   }
 }
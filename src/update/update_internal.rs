@@ -1,21 +1,35 @@
 use crate::{
   concat::{
-    concat_raw_before_after,
+    concat_explain, concat_raw_before_after,
     sql_standard::{ConcatFrom, ConcatJoin, ConcatWhere},
     Concat,
   },
   fmt,
   structure::{Update, UpdateClause},
+  utils::push_unique,
 };
 
 impl ConcatFrom<UpdateClause> for Update {}
 impl ConcatWhere<UpdateClause> for Update {}
 impl ConcatJoin<UpdateClause> for Update {}
 
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+use crate::{behavior::Join, structure::ClauseItem, utils::promote_first_join};
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+impl Join for Update {
+  fn join_items(&mut self) -> &mut Vec<ClauseItem> {
+    &mut self._join
+  }
+}
+
 impl Concat for Update {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
+    query = self.concat_raw(query, &fmts, &self._comment);
+    query = concat_explain(query, &fmts, &self._explain);
     query = self.concat_raw(query, &fmts, &self._raw);
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -35,13 +49,29 @@ impl Concat for Update {
       query = self.concat_update(query, &fmts);
     }
 
-    #[cfg(feature = "sqlite")]
+    #[cfg(all(feature = "sqlite", not(feature = "postgresql")))]
     {
       query = self.concat_update(&self._raw_before, &self._raw_after, query, &fmts, &self._update);
     }
 
+    #[cfg(all(feature = "sqlite", feature = "postgresql"))]
+    {
+      let update = self.update_with_only(&fmts);
+      query = self.concat_update(&self._raw_before, &self._raw_after, query, &fmts, &update);
+    }
+
     query = self.concat_set(query, &fmts);
 
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let promoted = promote_first_join(&self._from, &self._join, &self._where);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let (from, join, conditions) = match &promoted {
+      Some((from, join, conditions)) => (from, join, conditions),
+      None => (&self._from, &self._join, &self._where),
+    };
+    #[cfg(not(any(feature = "postgresql", feature = "sqlite")))]
+    let conditions = &self._where;
+
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_from(
@@ -50,11 +80,11 @@ impl Concat for Update {
         query,
         &fmts,
         UpdateClause::From,
-        &self._from,
+        from,
       );
     }
 
-    #[cfg(feature = "sqlite")]
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_join(
         &self._raw_before,
@@ -62,7 +92,7 @@ impl Concat for Update {
         query,
         &fmts,
         UpdateClause::Join,
-        &self._join,
+        join,
       );
     }
 
@@ -72,7 +102,7 @@ impl Concat for Update {
       query,
       &fmts,
       UpdateClause::Where,
-      &self._where,
+      conditions,
     );
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -87,11 +117,31 @@ impl Concat for Update {
       );
     }
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
 
 impl Update {
+  pub(crate) fn missing_clause(&self) -> Option<&'static str> {
+    #[cfg(not(feature = "sqlite"))]
+    let table_name = &self._update;
+    #[cfg(feature = "sqlite")]
+    let table_name = &self._update.1;
+
+    if self._raw.is_empty() == false {
+      return None;
+    }
+    if table_name.is_empty() {
+      return Some("update");
+    }
+    if self._set.is_empty() {
+      return Some("set");
+    }
+    None
+  }
+
   fn concat_set(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if self._set.is_empty() == false {
@@ -102,13 +152,48 @@ impl Update {
         .map(|item| item.as_str())
         .collect::<Vec<_>>()
         .join(comma);
-      format!("SET{space}{values}{space}{lb}")
+      format!("{}{space}{values}{space}{lb}", fmts.kw("SET"))
     } else {
       "".to_string()
     };
 
     concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, UpdateClause::Set, sql)
   }
+
+  /// Adds the assignment to the `set` clause replacing the previous assignment of the same column
+  pub(crate) fn push_assignment(&mut self, column: &str, assignment: String) {
+    let same_column = self._set.iter().position(|item| match item.split_once('=') {
+      Some((item_column, _)) => item_column.trim() == column,
+      None => false,
+    });
+
+    match same_column {
+      Some(index) => self._set[index] = assignment,
+      None => push_unique(&mut self._set, assignment),
+    }
+  }
+
+  /// Adds the value of the `set` method, the value only replaces a previous increment or decrement of the same
+  /// column, the other assignments of the same column are accumulated
+  pub(crate) fn push_set(&mut self, value: String) {
+    let increment = match value.split_once('=') {
+      Some((column, _)) if column.trim().is_empty() == false => {
+        let column = column.trim();
+        let plus = format!("{column} = {column} + ");
+        let minus = format!("{column} = {column} - ");
+        self
+          ._set
+          .iter()
+          .position(|item| item.starts_with(&plus) || item.starts_with(&minus))
+      }
+      _ => None,
+    };
+
+    match increment {
+      Some(index) => self._set[index] = value,
+      None => push_unique(&mut self._set, value),
+    }
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -117,7 +202,11 @@ use crate::concat::non_standard::{ConcatReturning, ConcatWith};
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl ConcatReturning<UpdateClause> for Update {}
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl ConcatWith<UpdateClause> for Update {}
+impl ConcatWith<UpdateClause> for Update {
+  fn is_recursive(&self) -> bool {
+    self._with_recursive
+  }
+}
 
 #[cfg(feature = "sqlite")]
 use crate::concat::sqlite::ConcatUpdate;
@@ -125,13 +214,52 @@ use crate::concat::sqlite::ConcatUpdate;
 #[cfg(feature = "sqlite")]
 impl ConcatUpdate for Update {}
 
+#[cfg(feature = "sqlite")]
+impl Update {
+  /// Removes the update clause only when it was defined by the method of the `vars` variant
+  pub(crate) fn clear_update(&mut self, vars: crate::structure::UpdateVars) {
+    if self._update.0 == vars {
+      self._update = Default::default();
+      #[cfg(feature = "postgresql")]
+      {
+        self._only = false;
+      }
+    }
+  }
+
+  /// The `only` keyword is attached to the table name because the `update` clause is shared with the `update_or` method
+  #[cfg(feature = "postgresql")]
+  fn update_with_only(&self, fmts: &fmt::Formatter) -> (crate::structure::UpdateVars, String) {
+    match &self._update {
+      (crate::structure::UpdateVars::Update, table_name) if self._only && table_name.is_empty() == false => (
+        crate::structure::UpdateVars::Update,
+        format!("{}{}{table_name}", fmts.kw("ONLY"), fmts.space),
+      ),
+      update => update.clone(),
+    }
+  }
+}
+
 #[cfg(not(feature = "sqlite"))]
 impl Update {
+  /// Removes the update clause and the `only` keyword attached to it
+  pub(crate) fn clear_update(&mut self) {
+    self._update.clear();
+    #[cfg(feature = "postgresql")]
+    {
+      self._only = false;
+    }
+  }
+
   fn concat_update(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._update.is_empty() == false {
       let table_name = &self._update;
-      format!("UPDATE{space}{table_name}{space}{lb}")
+      #[cfg(not(feature = "postgresql"))]
+      let keyword = fmts.kw("UPDATE");
+      #[cfg(feature = "postgresql")]
+      let keyword = fmts.kw(if self._only { "UPDATE ONLY" } else { "UPDATE" });
+      format!("{keyword}{space}{table_name}{space}{lb}")
     } else {
       "".to_string()
     };
@@ -1,8 +1,269 @@
 use std::cmp::PartialEq;
 
+use crate::structure::ClauseItem;
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+use crate::structure::{ClauseItemPart, LogicalOperator};
+
 pub(crate) fn push_unique<T: PartialEq>(list: &mut Vec<T>, value: T) {
   let prev_item = list.iter().find(|&item| *item == value);
   if prev_item.is_none() {
     list.push(value);
   }
 }
+
+/// Same as [push_unique] but the values are compared ignoring the ASCII case, the whitespaces are compared as is
+/// and the quoted identifiers and strings are compared exactly. The first value added is the one kept
+pub(crate) fn push_unique_ci<T: std::fmt::Display>(list: &mut Vec<T>, value: T) {
+  let text = value.to_string();
+  let prev_item = list
+    .iter()
+    .find(|item| eq_ignore_case_unquoted(&item.to_string(), &text));
+  if prev_item.is_none() {
+    list.push(value);
+  }
+}
+
+/// Compares the values ignoring the ASCII case outside of the quotes, `"Login"` and `"login"` are
+/// different identifiers so the double quoted identifiers and the single quoted strings are compared exactly
+fn eq_ignore_case_unquoted(a: &str, b: &str) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  let mut quote = None;
+  a.bytes().zip(b.bytes()).all(|(a, b)| {
+    match quote {
+      Some(quote_byte) if quote_byte == a => quote = None,
+      Some(_) => {}
+      None if a == b'"' || a == b'\'' => quote = Some(a),
+      None => return a.eq_ignore_ascii_case(&b),
+    }
+    a == b
+  })
+}
+
+/// The condition `column IN (v1, v2, ...)`, or `column NOT IN (...)` when negated, skipping the empty values.
+/// An empty list is the always false `1 = 0` predicate, or the always true `1 = 1` when negated, instead of the
+/// invalid `IN ()`. The operator is rendered with the keyword case of the formatter and the condition is empty
+/// when the column is empty
+pub(crate) fn in_condition<I, V>(column: &str, values: I, negated: bool) -> ClauseItem
+where
+  I: IntoIterator<Item = V>,
+  V: AsRef<str>,
+{
+  let column = column.trim();
+  if column.is_empty() {
+    return ClauseItem::default();
+  }
+
+  let values = values
+    .into_iter()
+    .map(|value| value.as_ref().trim().to_string())
+    .filter(|value| value.is_empty() == false)
+    .collect::<Vec<_>>();
+
+  let operator = if negated { "NOT IN" } else { "IN" };
+  match (values.is_empty(), negated) {
+    (true, false) => ClauseItem::default().expr("1 = 0"),
+    (true, true) => ClauseItem::default().expr("1 = 1"),
+    (false, _) => ClauseItem::default()
+      .expr(&format!("{column} "))
+      .keyword(operator)
+      .expr(&format!(" ({})", values.join(", "))),
+  }
+}
+
+/// Renders the text as a single line block comment, the line breaks are replaced by spaces and the `/*` and `*/`
+/// sequences are split so the text can't open a nested comment or close the comment early
+pub(crate) fn block_comment(text: &str) -> String {
+  let text = text
+    .replace(['\r', '\n'], " ")
+    .replace("*/", "* /")
+    .replace("/*", "/ *");
+  format!("/* {} */", text.trim())
+}
+
+pub(crate) fn remove_raw_of_clause<Clause: PartialEq>(raw_list: &mut Vec<(Clause, String)>, clause: &Clause) {
+  raw_list.retain(|item| item.0 != *clause);
+}
+
+/// Adds an output name to the `returning` clause, the `*` output name replaces all the others
+/// and an output name added after it replaces the `*`. An output name with the same expression
+/// of a previous one, like `id AS pk` and `id`, replaces the previous one keeping its position
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+pub(crate) fn push_returning(returning: &mut Vec<ClauseItem>, output_name: ClauseItem) {
+  if output_name.is_empty() {
+    return;
+  }
+
+  let text = output_name.to_string();
+  if text == "*" || returning.iter().any(|item| item.to_string() == "*") {
+    returning.clear();
+  }
+
+  let expression = output_expression(&text);
+  let same_expression = returning
+    .iter()
+    .position(|item| output_expression(&item.to_string()) == expression);
+  match same_expression {
+    Some(index) => returning[index] = output_name,
+    None => returning.push(output_name),
+  }
+}
+
+/// The expression of an output name without its alias, only an `AS` outside of parentheses and quotes
+/// is considered the alias, so `cast(id AS text)` is kept as is
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+pub(crate) fn output_expression(output_name: &str) -> &str {
+  let lowercase = output_name.to_ascii_lowercase();
+  let mut alias_index = None;
+  let mut depth = 0;
+  let mut quote = None;
+
+  for (index, byte) in lowercase.bytes().enumerate() {
+    match (quote, byte) {
+      (Some(quote_byte), _) if quote_byte == byte => quote = None,
+      (Some(_), _) => {}
+      (None, b'\'' | b'"') => quote = Some(byte),
+      (None, b'(') => depth += 1,
+      (None, b')') => depth -= 1,
+      (None, b' ') if depth == 0 && lowercase[index..].starts_with(" as ") => alias_index = Some(index),
+      _ => {}
+    }
+  }
+
+  match alias_index {
+    Some(index) => output_name[..index].trim_end(),
+    None => output_name,
+  }
+}
+
+/// Splits the text by the commas outside of parentheses and quotes, so `coalesce(a, b), c` has two items.
+/// The items are trimmed and the empty ones are skipped
+pub(crate) fn split_top_level_commas(text: &str) -> Vec<&str> {
+  let mut items = vec![];
+  let mut start = 0;
+  let mut depth = 0;
+  let mut quote = None;
+
+  for (index, byte) in text.bytes().enumerate() {
+    match (quote, byte) {
+      (Some(quote_byte), _) if quote_byte == byte => quote = None,
+      (Some(_), _) => {}
+      (None, b'\'' | b'"') => quote = Some(byte),
+      (None, b'(') => depth += 1,
+      (None, b')') => depth -= 1,
+      (None, b',') if depth == 0 => {
+        items.push(text[start..index].trim());
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  items.push(text[start..].trim());
+
+  items.into_iter().filter(|item| item.is_empty() == false).collect()
+}
+
+/// The table of a join without its `on` condition, only an `ON` outside of parentheses and quotes
+/// is considered the condition, so `lateral (select * from t join u on true) t` is kept as is
+pub(crate) fn without_on_condition(table: &str) -> &str {
+  split_on_condition(table).0
+}
+
+/// Splits a join in the table and its `on` condition, see [without_on_condition]
+fn split_on_condition(table: &str) -> (&str, Option<&str>) {
+  let lowercase = table.to_ascii_lowercase();
+  let bytes = lowercase.as_bytes();
+  let mut depth = 0;
+  let mut quote = None;
+
+  for (index, &byte) in bytes.iter().enumerate() {
+    match (quote, byte) {
+      (Some(quote_byte), _) if quote_byte == byte => quote = None,
+      (Some(_), _) => {}
+      (None, b'\'' | b'"') => quote = Some(byte),
+      (None, b'(') => depth += 1,
+      (None, b')') => depth -= 1,
+      (None, _) if depth == 0 && byte.is_ascii_whitespace() && lowercase[index + 1..].starts_with("on") => {
+        let next = bytes.get(index + 3).copied().unwrap_or(b' ');
+        if next.is_ascii_whitespace() || next == b'(' {
+          return (table[..index].trim_end(), Some(table[index + 3..].trim()));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  (table, None)
+}
+
+/// The tables, joins and where conditions of the `update from` and `delete using` commands. A join needs a table
+/// to join with, so without tables the first joined table is promoted to the list of tables when it's an inner or
+/// cross join and its `on` condition becomes the first where condition, an outer join has no equivalent and the
+/// joins are not rendered. Returns `None` when the items can be rendered as they are
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+pub(crate) fn promote_first_join(
+  tables: &Vec<String>,
+  joins: &Vec<ClauseItem>,
+  conditions: &Vec<(LogicalOperator, ClauseItem)>,
+) -> Option<(Vec<String>, Vec<ClauseItem>, Vec<(LogicalOperator, ClauseItem)>)> {
+  if tables.is_empty() == false {
+    return None;
+  }
+  let (first_join, other_joins) = joins.split_first()?;
+
+  let promotable = match first_join.0.first() {
+    Some(ClauseItemPart::Keyword(keyword)) => keyword == "INNER JOIN" || keyword == "CROSS JOIN",
+    _ => false,
+  };
+  if promotable == false {
+    return Some((vec![], vec![], conditions.clone()));
+  }
+
+  let join = first_join
+    .0
+    .iter()
+    .skip(1)
+    .map(|part| match part {
+      ClauseItemPart::Expr(text) | ClauseItemPart::Keyword(text) => text.as_str(),
+    })
+    .collect::<String>();
+  let (table, on_condition) = split_on_condition(join.trim());
+
+  let mut conditions = conditions
+    .iter()
+    .filter(|(_, condition)| condition.is_empty() == false)
+    .cloned()
+    .collect::<Vec<_>>();
+  if let Some(on_condition) = on_condition.filter(|condition| condition.is_empty() == false) {
+    // the previous conditions are grouped so an `or` doesn't take precedence over the join condition
+    let has_or = conditions
+      .iter()
+      .skip(1)
+      .any(|(log_op, _)| *log_op == LogicalOperator::Or);
+    if has_or {
+      let (_, first) = &mut conditions[0];
+      first.0.insert(0, ClauseItemPart::Expr("(".to_string()));
+      let (_, last) = conditions.last_mut().unwrap();
+      last.0.push(ClauseItemPart::Expr(")".to_string()));
+    }
+    let on_condition = if on_condition.to_ascii_lowercase().contains(" or ") {
+      format!("({on_condition})")
+    } else {
+      on_condition.to_string()
+    };
+    conditions.insert(0, (LogicalOperator::And, ClauseItem::default().expr(&on_condition)));
+  }
+
+  Some((vec![table.to_string()], other_joins.to_vec(), conditions))
+}
+
+/// Appends the `;` statement terminator to the query of a builder that called the `terminated` method,
+/// an empty query is never terminated
+pub(crate) fn terminate(mut query: String, terminated: bool) -> String {
+  if terminated && query.is_empty() == false {
+    query.push(';');
+  }
+  query
+}
@@ -2,7 +2,7 @@ use crate::{
   concat::Concat,
   fmt,
   structure::{Values, ValuesClause},
-  utils::push_unique,
+  utils::{push_unique, terminate},
 };
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -33,7 +33,12 @@ impl Values {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    terminate(self.concat(&fmts), self._terminated)
+  }
+
+  /// Gets the current state of the [Values] and returns it as string using the [fmt::Formatter] passed as argument
+  pub fn as_string_with(&self, fmts: &fmt::Formatter) -> String {
+    terminate(fmts.finish(self.concat(fmts)), self._terminated)
   }
 
   /// Prints the current state of the [Values] to the standard output in a more ease to read version.
@@ -61,7 +66,10 @@ impl Values {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -74,7 +82,10 @@ impl Values {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!(
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    );
     self
   }
 
@@ -133,6 +144,34 @@ impl Values {
     self
   }
 
+  /// Adds a raw SQL query at the very end, after all the clauses of the builder.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let values_query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .raw_append("/* end of the values command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'one') /* end of the values command */";
+  /// # assert_eq!(values_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one') /* end of the values command */
+  /// ```
+  pub fn raw_append(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_append, raw_sql.to_string());
+    }
+    self
+  }
+
   /// Adds a raw SQL query before a specified clause.
   ///
   /// # Example
@@ -161,6 +200,86 @@ impl Values {
     self
   }
 
+  /// Adds a raw SQL query at the very start, before the raw SQL added with the [raw](Values::raw) method.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let values_query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .raw_prepend("/* values command */")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* values command */ VALUES (1, 'one')";
+  /// # assert_eq!(values_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* values command */ VALUES (1, 'one')
+  /// ```
+  pub fn raw_prepend(mut self, raw_sql: &str) -> Self {
+    let raw_sql = raw_sql.trim();
+    if raw_sql.is_empty() == false {
+      push_unique(&mut self._raw_prepend, raw_sql.to_string());
+    }
+    self
+  }
+
+  /// Adds a single row to the `values` clause, consecutive calls accumulates rows. The tuple is rendered as is
+  /// so it should be wrapped in parentheses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let values_query = sql::Values::new()
+  ///   .row("(1, 'a')")
+  ///   .row("(2, 'b')")
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'a'), (2, 'b')";
+  /// # assert_eq!(values_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'a'), (2, 'b')
+  /// ```
+  pub fn row(mut self, tuple: &str) -> Self {
+    push_unique(&mut self._values, tuple.trim().to_string());
+    self
+  }
+
+  /// Appends the `;` statement terminator to the query rendered by this builder, the terminator is not added
+  /// when the query is empty or to the nested builders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Values::new()
+  ///   .values("('foo')")
+  ///   .terminated()
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES ('foo');";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ('foo');
+  /// ```
+  pub fn terminated(mut self) -> Self {
+    self._terminated = true;
+    self
+  }
+
   /// The `values` clause
   ///
   /// # Example
@@ -196,6 +315,10 @@ impl std::fmt::Display for Values {
 impl std::fmt::Debug for Values {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(
+      f,
+      "{}",
+      fmt::format(terminate(self.concat(&fmts), self._terminated), &fmts)
+    )
   }
 }
@@ -8,18 +8,21 @@ impl Concat for Values {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_string();
 
+    query = self.concat_raw(query, &fmts, &self._raw_prepend);
     query = self.concat_raw(query, &fmts, &self._raw);
     query = self.concat_values(query, &fmts);
 
+    query = self.concat_raw(query, &fmts, &self._raw_append);
+
     query.trim_end().to_string()
   }
 }
 
 impl Values {
   fn concat_values(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let fmt::Formatter { indent, lb, space, .. } = fmts;
     let sql = if self._values.is_empty() == false {
-      let sep = format!("{comma}{lb}");
+      let sep = format!("{}{indent}", fmts.line_comma());
       let values = self
         ._values
         .iter()
@@ -27,7 +30,8 @@ impl Values {
         .map(|item| item.as_str())
         .collect::<Vec<_>>()
         .join(&sep);
-      format!("VALUES{space}{lb}{values}{space}{lb}")
+      let values = format!("{indent}{values}");
+      format!("{}{space}{lb}{values}{space}{lb}", fmts.kw("VALUES"))
     } else {
       "".to_string()
     };
@@ -0,0 +1,107 @@
+use crate::{fmt, structure::WithBody};
+
+#[cfg(feature = "postgresql")]
+use crate::structure::ParamValue;
+
+impl WithBody {
+  pub(crate) fn concat(&self, fmts: &fmt::Formatter) -> String {
+    match self {
+      Self::Query(query) => query.concat(fmts),
+      Self::Raw(sql) => sql.clone(),
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) fn bound_params(&self) -> Vec<ParamValue> {
+    match self {
+      Self::Query(query) => query.bound_params(),
+      Self::Raw(_) => vec![],
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use serde_with_body::{deserialize, serialize};
+
+/// Serialization of the `with` clause used by the `serde` feature, the builder of each query can't be serialized
+/// so it's stored as the rendered SQL and restored as a raw SQL
+#[cfg(feature = "serde")]
+mod serde_with_body {
+  use crate::{fmt, structure::WithBody};
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+  #[cfg(feature = "postgresql")]
+  use crate::{behavior::WithQuery, concat::Concat, structure::ParamValue};
+  #[cfg(feature = "postgresql")]
+  use std::sync::Arc;
+
+  /// A query of the `with` clause restored with the values bound to it
+  #[cfg(feature = "postgresql")]
+  struct RawWithQuery {
+    query: String,
+    params: Vec<ParamValue>,
+  }
+
+  #[cfg(feature = "postgresql")]
+  impl Concat for RawWithQuery {
+    fn concat(&self, _fmts: &fmt::Formatter) -> String {
+      self.query.clone()
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  impl WithQuery for RawWithQuery {
+    fn bound_params(&self) -> Vec<ParamValue> {
+      self.params.clone()
+    }
+  }
+
+  /// The serialized form of each query of the `with` clause
+  #[derive(Serialize, Deserialize)]
+  struct WithItem {
+    name: String,
+    query: String,
+
+    #[cfg(feature = "postgresql")]
+    #[serde(default)]
+    params: Vec<ParamValue>,
+  }
+
+  pub(crate) fn serialize<S: Serializer>(items: &Vec<(String, WithBody)>, serializer: S) -> Result<S::Ok, S::Error> {
+    let fmts = fmt::one_line();
+    let items = items
+      .iter()
+      .map(|(name, query)| WithItem {
+        name: name.clone(),
+        query: query.concat(&fmts),
+        #[cfg(feature = "postgresql")]
+        params: query.bound_params(),
+      })
+      .collect::<Vec<_>>();
+
+    items.serialize(serializer)
+  }
+
+  pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<(String, WithBody)>, D::Error> {
+    let items = Vec::<WithItem>::deserialize(deserializer)?;
+
+    Ok(items.into_iter().map(|item| (item.name.clone(), body(item))).collect())
+  }
+
+  #[cfg(feature = "postgresql")]
+  fn body(item: WithItem) -> WithBody {
+    if item.params.is_empty() {
+      return WithBody::Raw(item.query);
+    }
+    let query: Arc<dyn WithQuery> = Arc::new(RawWithQuery {
+      query: item.query,
+      params: item.params,
+    });
+    WithBody::Query(query)
+  }
+
+  #[cfg(not(feature = "postgresql"))]
+  fn body(item: WithItem) -> WithBody {
+    WithBody::Raw(item.query)
+  }
+}
@@ -0,0 +1,165 @@
+mod select_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_comment_should_add_a_block_comment_at_the_start_of_the_query() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .comment("users#index")
+      .as_string();
+    let expected_query = "/* users#index */ SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_should_neutralize_the_end_of_comment_sequence_in_the_text() {
+    let query = sql::Select::new()
+      .select("id")
+      .comment("evil */ DROP TABLE users; /*")
+      .as_string();
+    let expected_query = "/* evil * / DROP TABLE users; / * */ SELECT id";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(query.matches("*/").count(), 1);
+  }
+
+  #[test]
+  fn method_comment_should_render_a_single_line_comment() {
+    let query = sql::Select::new()
+      .select("id")
+      .comment("first line\nsecond line")
+      .as_string_with(&sql::fmt::one_line());
+    let expected_query = "/* first line second line */ SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_should_be_rendered_before_the_raw_sql() {
+    let query = sql::Select::new()
+      .raw("/* raw */")
+      .comment("traced")
+      .select("id")
+      .as_string();
+    let expected_query = "/* traced */ /* raw */ SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_should_not_accumulate_empty_comments() {
+    let query = sql::Select::new().comment("  ").select("id").as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_should_not_disable_the_validation_of_the_builder() {
+    let result = sql::Select::new().comment("traced").from("users").try_as_string();
+
+    assert_eq!(result, Err(sql::BuilderError::MissingClause("select")));
+  }
+
+  #[test]
+  fn method_comment_on_should_add_a_block_comment_before_the_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true")
+      .comment_on(sql::SelectClause::Where, "only */ active")
+      .as_string();
+    let expected_query = "SELECT id FROM users /* only * / active */ WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod insert_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_comment_should_add_a_block_comment_at_the_start_of_the_query() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .comment("signup */")
+      .as_string();
+    let expected_query = "/* signup * / */ INSERT INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_on_should_add_a_block_comment_before_the_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .comment_on(sql::InsertClause::Values, "imported")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) /* imported */ VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod update_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_comment_should_add_a_block_comment_at_the_start_of_the_query() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .comment("users#deactivate")
+      .as_string();
+    let expected_query = "/* users#deactivate */ UPDATE users SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_on_should_add_a_block_comment_before_the_clause() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .comment_on(sql::UpdateClause::Set, "*/ keeps the login")
+      .as_string();
+    let expected_query = "UPDATE users /* * / keeps the login */ SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod delete_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_comment_should_add_a_block_comment_at_the_start_of_the_query() {
+    let query = sql::Delete::new()
+      .delete_from("sessions")
+      .comment("sessions#cleanup")
+      .as_string();
+    let expected_query = "/* sessions#cleanup */ DELETE FROM sessions";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_comment_on_should_add_a_block_comment_before_the_clause() {
+    let query = sql::Delete::new()
+      .delete_from("sessions")
+      .where_clause("expired = true")
+      .comment_on(sql::DeleteClause::Where, "cron job")
+      .as_string();
+    let expected_query = "DELETE FROM sessions /* cron job */ WHERE expired = true";
+
+    assert_eq!(query, expected_query);
+  }
+}
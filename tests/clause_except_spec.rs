@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod select_command {
   use pretty_assertions::assert_eq;
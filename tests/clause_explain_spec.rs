@@ -0,0 +1,250 @@
+mod select_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_should_prefix_the_query_with_the_explain_keyword() {
+    let query = sql::Select::new().select("id").from("users").explain().as_string();
+    let expected_query = "EXPLAIN SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_analyze_should_prefix_the_query_with_the_explain_analyze_keyword() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .explain_analyze()
+      .as_string();
+    let expected_query = "EXPLAIN ANALYZE SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_should_not_double_the_prefix_on_consecutive_calls() {
+    let query = sql::Select::new().explain().select("id").explain().as_string();
+    let expected_query = "EXPLAIN SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_and_explain_analyze_should_override_each_other() {
+    let analyze = sql::Select::new().select("id").explain().explain_analyze();
+    let plain = sql::Select::new().select("id").explain_analyze().explain();
+
+    assert_eq!(analyze.as_string(), "EXPLAIN ANALYZE SELECT id");
+    assert_eq!(plain.as_string(), "EXPLAIN SELECT id");
+  }
+
+  #[test]
+  fn method_explain_should_be_rendered_after_the_comment_and_before_the_raw_sql() {
+    let query = sql::Select::new()
+      .raw("/* raw */")
+      .comment("traced")
+      .explain()
+      .select("id")
+      .as_string();
+    let expected_query = "/* traced */ EXPLAIN /* raw */ SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_should_be_rendered_with_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .select("id")
+      .explain_analyze()
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "explain analyze select id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_should_be_placed_in_its_own_line_in_the_multiline_formatter() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .explain()
+      .as_string_with(&sql::fmt::multiline());
+    let expected_query = "EXPLAIN \nSELECT id \nFROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_render_clause_should_not_render_the_explain_prefix() {
+    let select = sql::Select::new().select("id").where_clause("id = 1").explain();
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::Where, &sql::fmt::one_line()),
+      "WHERE id = 1"
+    );
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_explain_should_be_placed_before_the_with_clause() {
+    let query = sql::Select::new()
+      .with("active", sql::Select::new().select("id").from("users"))
+      .select("id")
+      .from("active")
+      .explain()
+      .as_string();
+    let expected_query = "EXPLAIN WITH active AS (SELECT id FROM users) SELECT id FROM active";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod select_command_postgresql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_with_should_prefix_the_query_with_the_options() {
+    let query = sql::Select::new()
+      .select("id")
+      .explain_with(" FORMAT JSON, ANALYZE ")
+      .as_string();
+    let expected_query = "EXPLAIN (FORMAT JSON, ANALYZE) SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_with_should_override_the_explain_methods() {
+    let query = sql::Select::new()
+      .select("id")
+      .explain_analyze()
+      .explain_with("COSTS OFF")
+      .as_string();
+    let expected_query = "EXPLAIN (COSTS OFF) SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_with_should_render_the_plain_explain_when_the_options_are_empty() {
+    let query = sql::Select::new().select("id").explain_with("  ").as_string();
+    let expected_query = "EXPLAIN SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_with_should_not_recase_the_options() {
+    let query = sql::Select::new()
+      .select("id")
+      .explain_with("FORMAT JSON")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "explain (FORMAT JSON) select id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_with_should_be_available_on_the_other_builders() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .explain_with("ANALYZE");
+    let update = sql::Update::new().set("active = false").explain_with("ANALYZE");
+    let delete = sql::Delete::new().delete_from("users").explain_with("ANALYZE");
+
+    assert_eq!(
+      insert.as_string(),
+      "EXPLAIN (ANALYZE) INSERT INTO users (login) VALUES ('foo')"
+    );
+    assert_eq!(update.as_string(), "EXPLAIN (ANALYZE) SET active = false");
+    assert_eq!(delete.as_string(), "EXPLAIN (ANALYZE) DELETE FROM users");
+  }
+}
+
+mod insert_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_should_prefix_the_query_with_the_explain_keyword() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .explain()
+      .as_string();
+    let expected_query = "EXPLAIN INSERT INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_analyze_should_prefix_the_query_with_the_explain_analyze_keyword() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .explain()
+      .explain_analyze()
+      .as_string();
+    let expected_query = "EXPLAIN ANALYZE INSERT INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod update_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_should_prefix_the_query_with_the_explain_keyword() {
+    let query = sql::Update::new().set("active = false").explain().as_string();
+    let expected_query = "EXPLAIN SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_analyze_should_prefix_the_query_with_the_explain_analyze_keyword() {
+    let query = sql::Update::new()
+      .set("active = false")
+      .explain_analyze()
+      .explain_analyze()
+      .as_string();
+    let expected_query = "EXPLAIN ANALYZE SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod delete_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_should_prefix_the_query_with_the_explain_keyword() {
+    let query = sql::Delete::new()
+      .delete_from("sessions")
+      .where_clause("expired = true")
+      .explain()
+      .as_string();
+    let expected_query = "EXPLAIN DELETE FROM sessions WHERE expired = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_explain_analyze_should_prefix_the_query_with_the_explain_analyze_keyword() {
+    let query = sql::Delete::new()
+      .delete_from("sessions")
+      .where_clause("expired = true")
+      .explain_analyze()
+      .as_string();
+    let expected_query = "EXPLAIN ANALYZE DELETE FROM sessions WHERE expired = true";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,213 @@
+#[cfg(any(not(feature = "mssql"), feature = "postgresql", feature = "sqlite"))]
+mod select_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_fetch_should_add_the_fetch_clause() {
+    let query = sql::Select::new().fetch("10").as_string();
+    let expected_query = "FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_fetch_should_override_the_current_value() {
+    let query = sql::Select::new().fetch("10").fetch("20").as_string();
+    let expected_query = "FETCH NEXT 20 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_fetch_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().fetch("  10  ").as_string();
+    let expected_query = "FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_rows_should_add_the_offset_rows_clause() {
+    let query = sql::Select::new().offset_rows("20").as_string();
+    let expected_query = "OFFSET 20 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_rows_should_override_the_current_value() {
+    let query = sql::Select::new().offset_rows("20").offset_rows("40").as_string();
+    let expected_query = "OFFSET 40 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_fetch_should_be_after_offset_rows_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .fetch("10")
+      .offset_rows("20")
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_fetch_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::Fetch, "/* page */")
+      .fetch("10")
+      .as_string();
+    let expected_query = "/* page */ FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_offset_rows_clause() {
+    let query = sql::Select::new()
+      .offset_rows("20")
+      .raw_after(sql::SelectClause::OffsetRows, "/* page */")
+      .as_string();
+    let expected_query = "OFFSET 20 ROWS /* page */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+mod select_command_mssql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_fetch_should_add_the_offset_and_order_by_required_by_sql_server() {
+    let query = sql::Select::new().select("id").from("users").fetch("10").as_string();
+    let expected_query = "SELECT id FROM users ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_fetch_should_keep_the_order_by_of_the_query() {
+    let query = sql::Select::new().order_by("id").fetch("10").as_string();
+    let expected_query = "ORDER BY id OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_rows_should_add_the_order_by_required_by_sql_server() {
+    let query = sql::Select::new().offset_rows("20").as_string();
+    let expected_query = "ORDER BY (SELECT NULL) OFFSET 20 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_fetch_should_be_after_offset_rows_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .fetch("10")
+      .offset_rows("20")
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_should_be_rendered_as_fetch_next_when_offset_rows_is_defined() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset_rows("20")
+      .limit("10")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_the_offset_fetch_clause() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset_rows("20")
+      .fetch("10")
+      .raw_before(sql::SelectClause::Fetch, "/* page */")
+      .as_string();
+    let expected_query = "ORDER BY id /* page */ OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_offset_fetch_clause() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset_rows("20")
+      .fetch("10")
+      .raw_after(sql::SelectClause::OffsetRows, "/* page */")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY /* page */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+mod select_command_with_limit {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_fetch_should_clear_the_limit_clause() {
+    let query = sql::Select::new().select("id").limit("5").fetch("10").as_string();
+
+    assert_eq!(query.contains('5'), false);
+    assert_eq!(query.contains("FETCH NEXT 10 ROWS ONLY"), true);
+  }
+
+  #[test]
+  fn method_limit_should_clear_the_fetch_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .offset("20")
+      .fetch("10")
+      .limit("5")
+      .as_string();
+
+    assert_eq!(query.contains("FETCH NEXT 10"), false);
+    assert_eq!(query.contains('5'), true);
+  }
+
+  #[test]
+  fn method_offset_rows_should_clear_the_offset_clause() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset("5")
+      .offset_rows("20")
+      .fetch("10")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_clear_the_offset_rows_clause() {
+    let query = sql::Select::new().offset_rows("20").offset("5").as_string();
+
+    assert_eq!(query.contains("OFFSET 20"), false);
+    assert_eq!(query.contains("OFFSET 5"), true);
+  }
+}
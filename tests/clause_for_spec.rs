@@ -0,0 +1,127 @@
+#[cfg(feature = "postgresql")]
+mod select_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_for_update_should_add_the_for_update_clause() {
+    let query = sql::Select::new().for_update().as_string();
+    let expected_query = "FOR UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_share_should_add_the_for_share_clause() {
+    let query = sql::Select::new().for_share().as_string();
+    let expected_query = "FOR SHARE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_share_should_override_the_for_update_clause() {
+    let query = sql::Select::new().for_update().for_share().as_string();
+    let expected_query = "FOR SHARE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_update_should_override_the_for_share_clause() {
+    let query = sql::Select::new().for_share().for_update().as_string();
+    let expected_query = "FOR UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_skip_locked_should_add_the_option_to_the_for_clause() {
+    let query = sql::Select::new().skip_locked().for_update().as_string();
+    let expected_query = "FOR UPDATE SKIP LOCKED";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_nowait_should_add_the_option_to_the_for_clause() {
+    let query = sql::Select::new().for_share().nowait().as_string();
+    let expected_query = "FOR SHARE NOWAIT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_nowait_should_override_the_skip_locked_option() {
+    let query = sql::Select::new().for_update().skip_locked().nowait().as_string();
+    let expected_query = "FOR UPDATE NOWAIT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_skip_locked_should_not_be_rendered_without_the_for_clause() {
+    let query = sql::Select::new().select("id").skip_locked().nowait().as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_for_should_be_after_offset_clause() {
+    let query = sql::Select::new()
+      .for_update()
+      .skip_locked()
+      .offset("2")
+      .limit("1")
+      .order_by("created_at")
+      .where_clause("state = 'ready'")
+      .from("jobs")
+      .select("id")
+      .as_string();
+    let expected_query = "\
+      SELECT id \
+      FROM jobs \
+      WHERE state = 'ready' \
+      ORDER BY created_at \
+      LIMIT 1 \
+      OFFSET 2 \
+      FOR UPDATE SKIP LOCKED\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_update_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .for_update()
+      .skip_locked()
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "for update skip locked";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_for_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::For, "limit 1")
+      .for_update()
+      .as_string();
+    let expected_query = "limit 1 FOR UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_for_clause() {
+    let query = sql::Select::new()
+      .for_update()
+      .raw_after(sql::SelectClause::For, "of jobs")
+      .as_string();
+    let expected_query = "FOR UPDATE of jobs";
+
+    assert_eq!(query, expected_query);
+  }
+}
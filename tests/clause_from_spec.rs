@@ -74,6 +74,475 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_from_item_should_add_the_item_to_the_from_clause() {
+    let query = sql::Select::new().from_item(sql::FromItem::table("users")).as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_item_should_accumulate_values_with_the_from_method() {
+    let query = sql::Select::new()
+      .from("users u")
+      .from_item(sql::FromItem::table("addresses").alias("a"))
+      .as_string();
+    let expected_query = "FROM users u, addresses AS a";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_item_should_not_accumulate_items_with_the_same_content() {
+    let query = sql::Select::new()
+      .from_item(sql::FromItem::table("users").alias("u"))
+      .from_item(sql::FromItem::table("users").alias("u"))
+      .as_string();
+    let expected_query = "FROM users AS u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_item_should_render_the_subquery_with_the_formatter_of_the_query() {
+    let users = sql::Select::new().select("id").from("users");
+    let query = sql::Select::new()
+      .select("u.id")
+      .from_item(sql::FromItem::subquery(users).alias("u"))
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "select u.id from (select id from users) as u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_item_should_not_accumulate_empty_items() {
+    let query = sql::Select::new()
+      .from_item(sql::FromItem::table("  ").alias("u"))
+      .from("users")
+      .as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod from_subquery {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_from_subquery_should_add_the_subquery_with_the_alias() {
+    let active_users = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true");
+    let query = sql::Select::new()
+      .select("u.id")
+      .from_subquery(active_users, "u")
+      .as_string();
+    let expected_query = "SELECT u.id FROM (SELECT id FROM users WHERE active = true) AS u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_keep_the_position_among_the_from_tables() {
+    let orders = sql::Select::new().select("user_id").from("orders");
+    let query = sql::Select::new()
+      .from("users u")
+      .from_subquery(orders, "o")
+      .from("addresses a")
+      .as_string();
+    let expected_query = "FROM users u, (SELECT user_id FROM orders) AS o, addresses a";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_be_joined_to_a_base_table() {
+    let last_orders = sql::Select::new()
+      .select("user_id, max(created_at) AS created_at")
+      .from("orders")
+      .group_by("user_id");
+    let query = sql::Select::new()
+      .select("u.login, o.created_at")
+      .from_subquery(last_orders, "o")
+      .inner_join("users u ON u.id = o.user_id")
+      .where_clause("u.active = true")
+      .as_string();
+    let expected_query = "\
+      SELECT u.login, o.created_at \
+      FROM (SELECT user_id, max(created_at) AS created_at FROM orders GROUP BY user_id) AS o \
+      INNER JOIN users u ON u.id = o.user_id \
+      WHERE u.active = true\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_indent_the_subquery_in_multiline_format() {
+    let orders = sql::Select::new()
+      .select("user_id")
+      .from("orders")
+      .where_clause("paid = true");
+    let query = sql::Select::new()
+      .select("o.user_id")
+      .from_subquery(orders, "o")
+      .inner_join("users u ON u.id = o.user_id")
+      .as_string_with(&sql::fmt::multiline());
+    let expected_query = "\
+      SELECT o.user_id \n\
+      FROM (\n  \
+        SELECT user_id \n  \
+        FROM orders \n  \
+        WHERE\n    paid = true\n\
+      ) AS o \n\
+      INNER JOIN users u ON u.id = o.user_id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_has_clause_should_detect_the_from_subquery() {
+    let select = sql::Select::new().from_subquery(sql::Select::new().select("1"), "one");
+
+    assert_eq!(select.has_clause(sql::SelectClause::From), true);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_the_from_subquery() {
+    let query = sql::Select::new()
+      .from_subquery(sql::Select::new().select("1"), "one")
+      .raw_before(sql::SelectClause::From, "/* derived */")
+      .as_string();
+    let expected_query = "/* derived */ FROM (SELECT 1) AS one";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod from_item {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn from_item_should_be_displayable() {
+    let item = sql::FromItem::table("users").alias("u");
+
+    println!("{}", item);
+
+    assert_eq!(item.as_string(), "users AS u");
+  }
+
+  #[test]
+  fn from_item_should_compose_all_options_in_the_syntax_order() {
+    let item = sql::FromItem::table("  measurements  ")
+      .tablesample("  system  ", "  5  ")
+      .alias("  m  ")
+      .schema("  metrics  ")
+      .only()
+      .lateral()
+      .as_string();
+    let expected_item = "LATERAL ONLY metrics.measurements AS m TABLESAMPLE system (5)";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn method_descendants_should_render_the_star_after_the_source() {
+    let item = sql::FromItem::table("measurements")
+      .alias("m")
+      .schema("metrics")
+      .descendants()
+      .as_string();
+    let expected_item = "metrics.measurements * AS m";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn method_only_should_override_the_descendants_method() {
+    let item = sql::FromItem::table("measurements").descendants().only().as_string();
+    let expected_item = "ONLY measurements";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn method_descendants_should_override_the_only_method() {
+    let item = sql::FromItem::table("measurements").only().descendants().as_string();
+    let expected_item = "measurements *";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn from_item_should_render_subqueries_between_parentheses() {
+    let select = sql::Select::new().select("id").from("orders");
+    let item = sql::FromItem::subquery(select).lateral().alias("o").as_string();
+    let expected_item = "LATERAL (SELECT id FROM orders) AS o";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn from_item_should_render_function_calls() {
+    let item = sql::FromItem::function("unnest($1)").alias("ids").as_string();
+    let expected_item = "unnest($1) AS ids";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn method_alias_and_schema_should_override_the_previous_value() {
+    let item = sql::FromItem::table("users")
+      .schema("public")
+      .schema("auth")
+      .alias("a")
+      .alias("u")
+      .as_string();
+    let expected_item = "auth.users AS u";
+
+    assert_eq!(item, expected_item);
+  }
+
+  #[test]
+  fn method_tablesample_should_be_removed_when_method_is_empty() {
+    let item = sql::FromItem::table("users")
+      .tablesample("bernoulli", "10")
+      .tablesample("", "")
+      .as_string();
+    let expected_item = "users";
+
+    assert_eq!(item, expected_item);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod select_command_postgresql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_from_function_named_should_add_a_function_call_using_named_notation() {
+    let query = sql::Select::new()
+      .from_function_named(
+        "generate_report",
+        &[("start_date", "$1"), ("limit_rows", "100")],
+        "report",
+      )
+      .as_string();
+    let expected_query = "FROM generate_report(start_date => $1, limit_rows => 100) AS report";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_function_named_should_ignore_arguments_with_empty_name_and_omit_empty_alias() {
+    let query = sql::Select::new()
+      .from_function_named("  now_series  ", &[("", "1"), ("  step  ", "  '1 hour'  ")], "  ")
+      .as_string();
+    let expected_query = "FROM now_series(step => '1 hour')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_function_named_should_accumulate_values_with_the_from_method() {
+    let query = sql::Select::new()
+      .from("users u")
+      .from_function_named("user_stats", &[("user_id", "u.id")], "s")
+      .as_string();
+    let expected_query = "FROM users u, user_stats(user_id => u.id) AS s";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_function_named_should_not_accumulate_values_when_function_name_is_empty() {
+    let query = sql::Select::new()
+      .from_function_named("", &[("a", "1")], "f")
+      .from("users")
+      .as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_only_should_add_the_from_clause_with_the_only_keyword() {
+    let query = sql::Select::new().from_only("measurements").as_string();
+    let expected_query = "FROM ONLY measurements";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_only_should_attach_the_only_keyword_to_the_table_defined() {
+    let query = sql::Select::new()
+      .from("cities")
+      .from_only("  measurements  ")
+      .from("users")
+      .as_string();
+    let expected_query = "FROM cities, ONLY measurements, users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_only_should_not_accumulate_duplicated_tables() {
+    let query = sql::Select::new()
+      .from_only("cities c")
+      .from("capitals")
+      .from_only("cities c")
+      .as_string();
+    let expected_query = "FROM ONLY cities c, capitals";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_only_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Select::new().from_only("").from("users").as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sample_should_add_the_tablesample_after_the_table_name() {
+    let query = sql::Select::new()
+      .from_sample("big_table", "BERNOULLI", "10")
+      .as_string();
+    let expected_query = "FROM big_table TABLESAMPLE BERNOULLI (10)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sample_should_only_attach_the_sample_to_the_given_table() {
+    let query = sql::Select::new()
+      .from("users")
+      .from_sample("  orders  ", "  SYSTEM  ", "  2.5  ")
+      .from("addresses")
+      .as_string();
+    let expected_query = "FROM users, orders TABLESAMPLE SYSTEM (2.5), addresses";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sample_should_render_only_the_table_when_the_method_is_empty() {
+    let query = sql::Select::new().from_sample("big_table", " ", "10").as_string();
+    let expected_query = "FROM big_table";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sample_should_render_only_the_table_when_the_percent_is_empty() {
+    let query = sql::Select::new()
+      .from_sample("big_table", "BERNOULLI", "  ")
+      .as_string();
+    let expected_query = "FROM big_table";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sample_should_produce_the_same_output_of_the_from_sampled_method_without_alias() {
+    let sample = sql::Select::new().from_sample("big_table", "BERNOULLI", "10");
+    let sampled = sql::Select::new().from_sampled("big_table", "", "BERNOULLI", 10.0);
+
+    assert_eq!(sample.as_string(), sampled.as_string());
+  }
+
+  #[test]
+  fn method_from_sample_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Select::new()
+      .from_sample("", "BERNOULLI", "10")
+      .from("users")
+      .as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sampled_should_add_the_from_clause_with_alias_and_tablesample() {
+    let query = sql::Select::new()
+      .from_sampled("users", "u", "bernoulli", 10.0)
+      .as_string();
+    let expected_query = "FROM users AS u TABLESAMPLE bernoulli (10)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sampled_should_omit_the_alias_when_empty() {
+    let query = sql::Select::new().from_sampled("users", "", "system", 0.5).as_string();
+    let expected_query = "FROM users TABLESAMPLE system (0.5)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sampled_should_accumulate_values_with_the_from_method() {
+    let query = sql::Select::new()
+      .from("orders o")
+      .from_sampled("  users  ", "  u  ", "  bernoulli  ", 25.0)
+      .as_string();
+    let expected_query = "FROM orders o, users AS u TABLESAMPLE bernoulli (25)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_sampled_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Select::new()
+      .from_sampled("", "u", "bernoulli", 10.0)
+      .from("users")
+      .as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_only_and_from_sampled_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .from_only("measurements")
+      .from_sampled("users", "u", "BERNOULLI", 10.0)
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "from only measurements, users as u tablesample BERNOULLI (10)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_add_the_table_with_the_tablesample_system_rows_method() {
+    let query = sql::Select::new()
+      .from("orders")
+      .sample_rows("  users  ", 10)
+      .from("addresses")
+      .as_string();
+    let expected_query = "FROM orders, users TABLESAMPLE SYSTEM_ROWS (10), addresses";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Select::new().select("*").sample_rows(" ", 10).as_string();
+    let expected_query = "SELECT *";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -150,4 +619,24 @@ mod update_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn clause_from_should_be_between_the_set_and_where_clauses_and_before_returning() {
+    let query = sql::Update::new()
+      .returning("orders.id")
+      .where_clause("orders.owner_id = users.id")
+      .from("users")
+      .set("priority = users.priority")
+      .update("orders")
+      .as_string();
+    let expected_query = "\
+      UPDATE orders \
+      SET priority = users.priority \
+      FROM users \
+      WHERE orders.owner_id = users.id \
+      RETURNING orders.id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
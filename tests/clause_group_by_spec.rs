@@ -85,3 +85,128 @@ mod select_command {
     assert_eq!(query, expected_query);
   }
 }
+
+mod select_command_grouping_elements {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_group_by_cube_should_add_the_cube_grouping_element() {
+    let query = sql::Select::new().group_by_cube("brand, size").as_string();
+    let expected_query = "GROUP BY CUBE (brand, size)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_grouping_sets_should_add_the_grouping_sets_element() {
+    let query = sql::Select::new().grouping_sets("(brand), (size), ()").as_string();
+    let expected_query = "GROUP BY GROUPING SETS ((brand), (size), ())";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_grouping_sets_should_compose_after_the_group_by_method() {
+    let query = sql::Select::new()
+      .group_by("country")
+      .grouping_sets("(brand), (size)")
+      .group_by_cube("color")
+      .as_string();
+    let expected_query = "GROUP BY country, GROUPING SETS ((brand), (size)), CUBE (color)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_of_the_grouping_elements_should_not_accumulate_values_when_the_argument_is_empty() {
+    let query = sql::Select::new()
+      .group_by_cube("")
+      .group_by_rollup("  ")
+      .grouping_sets("")
+      .as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_of_the_grouping_elements_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().group_by_cube("  brand, size  ").as_string();
+    let expected_query = "GROUP BY CUBE (brand, size)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_grouping_elements() {
+    let query = sql::Select::new()
+      .grouping_sets("(brand), ()")
+      .raw_after(sql::SelectClause::GroupBy, "HAVING count(*) > 1")
+      .as_string();
+    let expected_query = "GROUP BY GROUPING SETS ((brand), ()) HAVING count(*) > 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(not(feature = "mysql"))]
+  #[test]
+  fn methods_of_the_grouping_elements_should_render_the_keywords_with_the_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .group_by("year")
+      .group_by_cube("brand")
+      .group_by_rollup("country, city")
+      .grouping_sets("(size), ()")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "group by year, cube (brand), rollup (country, city), grouping sets ((size), ())";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(not(feature = "mysql"))]
+  #[test]
+  fn method_group_by_rollup_should_add_the_rollup_grouping_element() {
+    let query = sql::Select::new()
+      .group_by("year")
+      .group_by_rollup("country, city")
+      .as_string();
+    let expected_query = "GROUP BY year, ROLLUP (country, city)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn method_group_by_rollup_should_add_the_with_rollup_modifier() {
+    let query = sql::Select::new()
+      .group_by("year")
+      .group_by_rollup("country, city")
+      .as_string();
+    let expected_query = "GROUP BY year, country, city WITH ROLLUP";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn method_group_by_rollup_should_render_the_with_rollup_modifier_at_the_end_of_the_clause() {
+    let query = sql::Select::new()
+      .group_by_rollup("country, city")
+      .group_by("year")
+      .as_string();
+    let expected_query = "GROUP BY country, city, year WITH ROLLUP";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn method_group_by_rollup_should_render_the_with_rollup_modifier_with_the_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .group_by_rollup("country, city")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "group by country, city with rollup";
+
+    assert_eq!(query, expected_query);
+  }
+}
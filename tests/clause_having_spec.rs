@@ -84,4 +84,76 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_having_and_should_concatenate_the_conditions_with_the_and_operator() {
+    let query = sql::Select::new()
+      .having("count(*) > 10")
+      .having_and("  sum(amount) > 100  ")
+      .as_string();
+    let expected_query = "HAVING count(*) > 10 AND sum(amount) > 100";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_or_should_concatenate_the_conditions_with_the_or_operator() {
+    let query = sql::Select::new()
+      .having("count(*) > 10")
+      .having_or("max(amount) > 1000")
+      .as_string();
+    let expected_query = "HAVING count(*) > 10 OR max(amount) > 1000";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_and_and_having_or_should_be_rendered_in_the_order_they_were_called() {
+    let query = sql::Select::new()
+      .having_and("count(*) > 10")
+      .having_or("max(amount) > 1000")
+      .having_and("(min(amount) > 0 OR bool_or(vip))")
+      .as_string();
+    let expected_query = "HAVING count(*) > 10 OR max(amount) > 1000 AND (min(amount) > 0 OR bool_or(vip))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_or_should_ignore_the_operator_of_the_first_condition() {
+    let query = sql::Select::new()
+      .having_or("count(*) > 10")
+      .having("sum(amount) > 100")
+      .as_string();
+    let expected_query = "HAVING count(*) > 10 AND sum(amount) > 100";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_or_should_not_accumulate_empty_conditions() {
+    let query = sql::Select::new().having("count(*) > 10").having_or("  ").as_string();
+    let expected_query = "HAVING count(*) > 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_having_should_be_after_group_by_and_before_window_and_order_by() {
+    let query = sql::Select::new()
+      .order_by("total desc")
+      .window("win AS (PARTITION BY status)")
+      .having_or("max(amount) > 1000")
+      .group_by("status")
+      .having("count(*) > 10")
+      .as_string();
+    let expected_query = "\
+      GROUP BY status \
+      HAVING max(amount) > 1000 AND count(*) > 10 \
+      WINDOW win AS (PARTITION BY status) \
+      ORDER BY total desc\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
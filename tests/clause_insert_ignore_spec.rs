@@ -0,0 +1,110 @@
+#[cfg(feature = "mysql")]
+mod insert_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_insert_ignore_should_add_a_insert_ignore_into_clause() {
+    let query = sql::Insert::new().insert_ignore("users (login, name)").as_string();
+    let expected_query = "INSERT IGNORE INTO users (login, name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_ignore_should_override_value_on_consecutive_calls() {
+    let query = sql::Insert::new()
+      .insert_ignore("users (login, name)")
+      .insert_ignore("orders (product_name, price)")
+      .as_string();
+    let expected_query = "INSERT IGNORE INTO orders (product_name, price)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_ignore_should_not_add_clause_when_argument_is_empty() {
+    let query = sql::Insert::new().insert_ignore("").as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_ignore_should_trim_space_of_the_argument() {
+    let query = sql::Insert::new().insert_ignore("  users (login)  ").as_string();
+    let expected_query = "INSERT IGNORE INTO users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_ignore_should_override_the_insert_into_and_replace_into_methods() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .replace_into("users (login)")
+      .insert_ignore("users (login)")
+      .values("('foo')")
+      .as_string();
+    let expected_query = "INSERT IGNORE INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_into_should_override_the_insert_ignore_method() {
+    let query = sql::Insert::new()
+      .insert_ignore("users (login)")
+      .insert_into("users (login)")
+      .as_string();
+    let expected_query = "INSERT INTO users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_replace_into_should_override_the_insert_ignore_method() {
+    let query = sql::Insert::new()
+      .insert_ignore("users (login)")
+      .replace_into("users (login)")
+      .as_string();
+    let expected_query = "REPLACE INTO users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_duplicate_key_update_should_be_after_the_insert_ignore_clause() {
+    let query = sql::Insert::new()
+      .insert_ignore("users (login)")
+      .values("('foo')")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .as_string();
+    let expected_query =
+      "INSERT IGNORE INTO users (login) VALUES ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_insert_ignore_clause() {
+    let query = sql::Insert::new()
+      .raw_before(sql::InsertClause::InsertIgnore, "/* import */")
+      .insert_ignore("users (login)")
+      .as_string();
+    let expected_query = "/* import */ INSERT IGNORE INTO users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_insert_ignore_clause() {
+    let query = sql::Insert::new()
+      .insert_ignore("users (login)")
+      .raw_after(sql::InsertClause::InsertIgnore, "values ('foo')")
+      .as_string();
+    let expected_query = "INSERT IGNORE INTO users (login) values ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -29,6 +29,30 @@ mod insert_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_insert_into_should_keep_the_column_list_as_written() {
+    let query = sql::Insert::new().insert_into("users(login,  name)").as_string();
+    let expected_query = "INSERT INTO users(login,  name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_into_should_accept_only_the_column_list() {
+    let query = sql::Insert::new().insert_into("(login, name)").as_string();
+    let expected_query = "INSERT INTO (login, name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_into_should_accept_a_schema_qualified_table_without_columns() {
+    let query = sql::Insert::new().insert_into("public.users").as_string();
+    let expected_query = "INSERT INTO public.users";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_insert_into_clause() {
     let query = sql::Insert::new()
@@ -84,6 +108,30 @@ mod insert_command_sqlite {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_insert_into_should_keep_the_column_list_as_written() {
+    let query = sql::Insert::new().insert_into("users(login,  name)").as_string();
+    let expected_query = "INSERT INTO users(login,  name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_into_should_accept_only_the_column_list() {
+    let query = sql::Insert::new().insert_into("(login, name)").as_string();
+    let expected_query = "INSERT INTO (login, name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_into_should_accept_a_schema_qualified_table_without_columns() {
+    let query = sql::Insert::new().insert_into("public.users").as_string();
+    let expected_query = "INSERT INTO public.users";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_insert_into_clause() {
     let query = sql::Insert::new()
@@ -26,7 +26,7 @@ mod raw_methods {
     }
   }
 
-  #[cfg(feature = "sqlite")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   mod update_command {
     use pretty_assertions::assert_eq;
     use sql_query_builder as sql;
@@ -34,10 +34,11 @@ mod raw_methods {
     #[test]
     fn method_raw_after_should_add_raw_sql_after_join_clause() {
       let query = sql::Update::new()
+        .from("users")
         .inner_join("orders ON orders.user_id = user.id")
         .raw_after(sql::UpdateClause::Join, "WHERE user.id = $1")
         .as_string();
-      let expected_query = "INNER JOIN orders ON orders.user_id = user.id WHERE user.id = $1";
+      let expected_query = "FROM users INNER JOIN orders ON orders.user_id = user.id WHERE user.id = $1";
 
       assert_eq!(expected_query, query);
     }
@@ -45,10 +46,41 @@ mod raw_methods {
     #[test]
     fn method_raw_before_should_add_raw_sql_before_join_clause() {
       let query = sql::Update::new()
-        .raw_before(sql::UpdateClause::Join, "FROM users")
+        .from("users")
+        .raw_before(sql::UpdateClause::Join, "CROSS JOIN addresses")
         .inner_join("orders ON orders.user_id = user.id")
         .as_string();
-      let expected_query = "FROM users INNER JOIN orders ON orders.user_id = user.id";
+      let expected_query = "FROM users CROSS JOIN addresses INNER JOIN orders ON orders.user_id = user.id";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_join_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .inner_join("orders ON orders.user_id = user.id")
+        .raw_after(sql::DeleteClause::Join, "WHERE user.id = $1")
+        .as_string();
+      let expected_query = "USING users INNER JOIN orders ON orders.user_id = user.id WHERE user.id = $1";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_join_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .raw_before(sql::DeleteClause::Join, "CROSS JOIN addresses")
+        .inner_join("orders ON orders.user_id = user.id")
+        .as_string();
+      let expected_query = "USING users CROSS JOIN addresses INNER JOIN orders ON orders.user_id = user.id";
 
       assert_eq!(expected_query, query);
     }
@@ -94,6 +126,29 @@ mod cross_join_clause {
       assert_eq!(expected_query, query);
     }
 
+    #[test]
+    fn method_cross_join_should_not_render_the_on_condition() {
+      let query = sql::Select::new()
+        .from("users")
+        .cross_join("addresses ON addresses.user_id = users.id")
+        .cross_join("orders o\non (o.user_id = users.id)")
+        .as_string();
+      let expected_query = "FROM users CROSS JOIN addresses CROSS JOIN orders o";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_should_keep_the_on_of_a_subquery_and_of_the_identifiers() {
+      let query = sql::Select::new()
+        .cross_join("(select * from a join b on a.id = b.id) ab")
+        .cross_join("\"count on\" online")
+        .as_string();
+      let expected_query = "CROSS JOIN (select * from a join b on a.id = b.id) ab CROSS JOIN \"count on\" online";
+
+      assert_eq!(expected_query, query);
+    }
+
     #[test]
     fn method_cross_join_by_should_trim_space_of_the_argument() {
       let query = sql::Select::new().cross_join("  orders  ").as_string();
@@ -122,15 +177,15 @@ mod cross_join_clause {
     }
   }
 
-  #[cfg(feature = "sqlite")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   mod update_command {
     use pretty_assertions::assert_eq;
     use sql_query_builder as sql;
 
     #[test]
     fn method_cross_join_should_add_the_cross_join_clause() {
-      let query = sql::Update::new().cross_join("addresses").as_string();
-      let expected_query = "CROSS JOIN addresses";
+      let query = sql::Update::new().from("users").cross_join("addresses").as_string();
+      let expected_query = "FROM users CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
@@ -138,11 +193,12 @@ mod cross_join_clause {
     #[test]
     fn method_cross_join_should_accumulate_values_on_consecutive_calls() {
       let query = sql::Update::new()
+        .from("users")
         .cross_join("addresses")
         .cross_join("orders")
         .as_string();
       let expected_query = "\
-        CROSS JOIN addresses \
+        FROM users CROSS JOIN addresses \
         CROSS JOIN orders\
       ";
 
@@ -152,19 +208,31 @@ mod cross_join_clause {
     #[test]
     fn method_cross_join_should_not_accumulate_values_when_table_name_is_empty() {
       let query = sql::Update::new()
+        .from("users")
         .cross_join("")
         .cross_join("orders")
         .cross_join("")
         .as_string();
-      let expected_query = "CROSS JOIN orders";
+      let expected_query = "FROM users CROSS JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_should_not_render_the_on_condition() {
+      let query = sql::Update::new()
+        .from("users")
+        .cross_join("addresses on addresses.user_id = users.id")
+        .as_string();
+      let expected_query = "FROM users CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_cross_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().cross_join("  orders  ").as_string();
-      let expected_query = "CROSS JOIN orders";
+      let query = sql::Update::new().from("users").cross_join("  orders  ").as_string();
+      let expected_query = "FROM users CROSS JOIN orders";
 
       assert_eq!(expected_query, query);
     }
@@ -172,10 +240,11 @@ mod cross_join_clause {
     #[test]
     fn method_cross_join_should_not_accumulate_arguments_with_the_same_content() {
       let query = sql::Update::new()
+        .from("users")
         .cross_join("addresses")
         .cross_join("addresses")
         .as_string();
-      let expected_query = "CROSS JOIN addresses";
+      let expected_query = "FROM users CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
@@ -187,6 +256,196 @@ mod cross_join_clause {
 
       assert_eq!(expected_query, query);
     }
+
+    #[test]
+    fn clause_cross_join_without_from_clause_should_promote_the_joined_table_to_the_from_clause() {
+      let query = sql::Update::new()
+        .update("users")
+        .set("active = false")
+        .cross_join("addresses")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false FROM addresses";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_cross_join_should_add_the_cross_join_clause() {
+      let query = sql::Delete::new().using("users").cross_join("addresses").as_string();
+      let expected_query = "USING users CROSS JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new()
+        .using("users")
+        .cross_join("addresses")
+        .cross_join("orders")
+        .as_string();
+      let expected_query = "\
+        USING users CROSS JOIN addresses \
+        CROSS JOIN orders\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_should_not_accumulate_values_when_table_name_is_empty() {
+      let query = sql::Delete::new()
+        .using("users")
+        .cross_join("")
+        .cross_join("orders")
+        .cross_join("")
+        .as_string();
+      let expected_query = "USING users CROSS JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_by_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new().using("users").cross_join("  orders  ").as_string();
+      let expected_query = "USING users CROSS JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_cross_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Delete::new()
+        .using("users")
+        .cross_join("addresses")
+        .cross_join("addresses")
+        .as_string();
+      let expected_query = "USING users CROSS JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_cross_join_should_be_after_using_clause() {
+      let query = sql::Delete::new().using("users").cross_join("addresses").as_string();
+      let expected_query = "USING users CROSS JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_cross_join_without_using_clause_should_promote_the_joined_table_to_the_using_clause() {
+      let query = sql::Delete::new()
+        .delete_from("users")
+        .cross_join("addresses")
+        .where_clause("users.login = addresses.login")
+        .as_string();
+      let expected_query = "DELETE FROM users USING addresses WHERE users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+}
+
+mod full_join_clause {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_full_join_should_add_the_full_outer_join_clause() {
+      let query = sql::Select::new()
+        .full_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "FULL OUTER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Select::new()
+        .full_join("addresses ON users.login = addresses.login")
+        .full_join("orders ON users.login = orders.login")
+        .as_string();
+      let expected_query = "\
+        FULL OUTER JOIN addresses ON users.login = addresses.login \
+        FULL OUTER JOIN orders ON users.login = orders.login\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_join_should_not_accumulate_values_when_table_name_is_empty() {
+      let query = sql::Select::new()
+        .full_join("")
+        .full_join("addresses ON users.login = addresses.login")
+        .full_join("")
+        .as_string();
+      let expected_query = "FULL OUTER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_join_should_trim_space_of_the_argument() {
+      let query = sql::Select::new()
+        .full_join("  addresses ON users.login = addresses.login  ")
+        .as_string();
+      let expected_query = "FULL OUTER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new()
+        .full_join("addresses ON users.login = addresses.login")
+        .full_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "FULL OUTER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_joins_should_render_mixed_join_types_in_the_order_of_the_calls() {
+      let query = sql::Select::new()
+        .from("users")
+        .full_join("orders ON orders.user_id = users.id")
+        .cross_join("regions")
+        .inner_join("addresses ON addresses.user_id = users.id")
+        .right_join("phones ON phones.user_id = users.id")
+        .left_join("roles ON roles.id = users.role_id")
+        .as_string();
+      let expected_query = "\
+        FROM users \
+        FULL OUTER JOIN orders ON orders.user_id = users.id \
+        CROSS JOIN regions \
+        INNER JOIN addresses ON addresses.user_id = users.id \
+        RIGHT JOIN phones ON phones.user_id = users.id \
+        LEFT JOIN roles ON roles.id = users.role_id\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_join_should_use_the_keyword_case_of_the_formatter() {
+      let query = sql::Select::new()
+        .full_join("addresses ON users.login = addresses.login")
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "full outer join addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
   }
 }
 
@@ -241,18 +500,180 @@ mod inner_join_clause {
 
     #[test]
     fn method_inner_join_should_not_accumulate_arguments_with_the_same_content() {
-      let query = sql::Select::new()
+      let query = sql::Select::new()
+        .inner_join("addresses")
+        .inner_join("addresses")
+        .as_string();
+      let expected_query = "INNER JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_inner_join_should_be_after_from_clause() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "FROM users INNER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_render_the_conditions_of_the_closure() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join_on("orders", |on| {
+          on.on("orders.user_id = users.id").and("orders.status = 'paid'")
+        })
+        .as_string();
+      let expected_query = "FROM users INNER JOIN orders ON orders.user_id = users.id AND orders.status = 'paid'";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_join_the_conditions_with_the_or_operator() {
+      let query = sql::Select::new()
+        .inner_join_on("contacts", |on| {
+          on.on("contacts.email = users.email").or("contacts.phone = users.phone")
+        })
+        .as_string();
+      let expected_query = "INNER JOIN contacts ON contacts.email = users.email OR contacts.phone = users.phone";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_keep_the_order_of_the_other_joins() {
+      let query = sql::Select::new()
+        .from("users")
+        .left_join("addresses ON addresses.user_id = users.id")
+        .inner_join_on("orders", |on| on.on("orders.user_id = users.id"))
+        .inner_join("payments ON payments.order_id = orders.id")
+        .as_string();
+      let expected_query = "\
+        FROM users \
+        LEFT JOIN addresses ON addresses.user_id = users.id \
+        INNER JOIN orders ON orders.user_id = users.id \
+        INNER JOIN payments ON payments.order_id = orders.id\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_not_add_the_join_when_the_conditions_are_empty() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join_on(" orders ", |on| on.on("  ").and(""))
+        .as_string();
+      let expected_query = "FROM users";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_not_add_the_join_when_the_closure_adds_no_condition() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join_on("orders", |on| on)
+        .as_string();
+      let expected_query = "FROM users";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_not_accumulate_values_when_table_is_empty() {
+      let query = sql::Select::new()
+        .inner_join_on("  ", |on| on.on("orders.user_id = users.id"))
+        .as_string();
+      let expected_query = "";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_on_should_produce_the_same_output_of_the_inner_join_method() {
+      let query = sql::Select::new()
+        .inner_join("orders ON orders.user_id = users.id")
+        .inner_join_on("orders", |on| on.on("orders.user_id = users.id"))
+        .as_string();
+      let expected_query = "INNER JOIN orders ON orders.user_id = users.id";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  mod update_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_inner_join_should_add_the_inner_join_clause() {
+      let query = sql::Update::new()
+        .from("users")
+        .inner_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "FROM users INNER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new()
+        .from("users")
+        .inner_join("addresses ON users.login = addresses.login")
+        .inner_join("orders ON users.login = orders.login")
+        .as_string();
+      let expected_query = "\
+        FROM users INNER JOIN addresses ON users.login = addresses.login \
+        INNER JOIN orders ON users.login = orders.login\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_should_not_accumulate_values_when_table_expression_is_empty() {
+      let query = sql::Update::new()
+        .from("users")
+        .inner_join("")
+        .inner_join("orders ON users.login = orders.login")
+        .inner_join("")
+        .as_string();
+      let expected_query = "FROM users INNER JOIN orders ON users.login = orders.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_by_should_trim_space_of_the_argument() {
+      let query = sql::Update::new().from("users").inner_join("  orders  ").as_string();
+      let expected_query = "FROM users INNER JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Update::new()
+        .from("users")
         .inner_join("addresses")
         .inner_join("addresses")
         .as_string();
-      let expected_query = "INNER JOIN addresses";
+      let expected_query = "FROM users INNER JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn clause_inner_join_should_be_after_from_clause() {
-      let query = sql::Select::new()
+      let query = sql::Update::new()
         .from("users")
         .inner_join("addresses ON users.login = addresses.login")
         .as_string();
@@ -260,31 +681,52 @@ mod inner_join_clause {
 
       assert_eq!(expected_query, query);
     }
+
+    #[test]
+    fn clause_inner_join_without_from_clause_should_promote_the_joined_table_and_its_condition() {
+      let query = sql::Update::new()
+        .update("orders")
+        .set("status = 'closed'")
+        .inner_join("users ON users.id = orders.user_id")
+        .inner_join("addresses ON addresses.user_id = users.id")
+        .where_clause("users.active = false")
+        .as_string();
+      let expected_query = "\
+        UPDATE orders SET status = 'closed' \
+        FROM users \
+        INNER JOIN addresses ON addresses.user_id = users.id \
+        WHERE users.id = orders.user_id AND users.active = false\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
   }
 
-  #[cfg(feature = "sqlite")]
-  mod update_command {
+  #[cfg(feature = "postgresql")]
+  mod delete_command {
     use pretty_assertions::assert_eq;
     use sql_query_builder as sql;
 
     #[test]
     fn method_inner_join_should_add_the_inner_join_clause() {
-      let query = sql::Update::new()
+      let query = sql::Delete::new()
+        .using("users")
         .inner_join("addresses ON users.login = addresses.login")
         .as_string();
-      let expected_query = "INNER JOIN addresses ON users.login = addresses.login";
+      let expected_query = "USING users INNER JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_inner_join_should_accumulate_values_on_consecutive_calls() {
-      let query = sql::Update::new()
+      let query = sql::Delete::new()
+        .using("users")
         .inner_join("addresses ON users.login = addresses.login")
         .inner_join("orders ON users.login = orders.login")
         .as_string();
       let expected_query = "\
-        INNER JOIN addresses ON users.login = addresses.login \
+        USING users INNER JOIN addresses ON users.login = addresses.login \
         INNER JOIN orders ON users.login = orders.login\
       ";
 
@@ -293,42 +735,61 @@ mod inner_join_clause {
 
     #[test]
     fn method_inner_join_should_not_accumulate_values_when_table_expression_is_empty() {
-      let query = sql::Update::new()
+      let query = sql::Delete::new()
+        .using("users")
         .inner_join("")
         .inner_join("orders ON users.login = orders.login")
         .inner_join("")
         .as_string();
-      let expected_query = "INNER JOIN orders ON users.login = orders.login";
+      let expected_query = "USING users INNER JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_inner_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().inner_join("  orders  ").as_string();
-      let expected_query = "INNER JOIN orders";
+      let query = sql::Delete::new().using("users").inner_join("  orders  ").as_string();
+      let expected_query = "USING users INNER JOIN orders";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_inner_join_should_not_accumulate_arguments_with_the_same_content() {
-      let query = sql::Update::new()
+      let query = sql::Delete::new()
+        .using("users")
         .inner_join("addresses")
         .inner_join("addresses")
         .as_string();
-      let expected_query = "INNER JOIN addresses";
+      let expected_query = "USING users INNER JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
-    fn clause_inner_join_should_be_after_from_clause() {
-      let query = sql::Update::new()
-        .from("users")
+    fn clause_inner_join_should_be_after_using_clause() {
+      let query = sql::Delete::new()
+        .using("users")
         .inner_join("addresses ON users.login = addresses.login")
         .as_string();
-      let expected_query = "FROM users INNER JOIN addresses ON users.login = addresses.login";
+      let expected_query = "USING users INNER JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_inner_join_without_using_clause_should_promote_the_joined_table_and_its_condition() {
+      let query = sql::Delete::new()
+        .delete_from("orders")
+        .inner_join("users ON users.id = orders.user_id")
+        .where_clause("users.active = false")
+        .where_or("orders.total = 0")
+        .as_string();
+      let expected_query = "\
+        DELETE FROM orders \
+        USING users \
+        WHERE users.id = orders.user_id AND (users.active = false OR orders.total = 0)\
+      ";
 
       assert_eq!(expected_query, query);
     }
@@ -407,7 +868,7 @@ mod left_join_clause {
     }
   }
 
-  #[cfg(feature = "sqlite")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   mod update_command {
     use pretty_assertions::assert_eq;
     use sql_query_builder as sql;
@@ -415,9 +876,10 @@ mod left_join_clause {
     #[test]
     fn method_left_join_should_add_the_left_join_clause() {
       let query = sql::Update::new()
+        .from("users")
         .left_join("addresses ON users.login = addresses.login")
         .as_string();
-      let expected_query = "LEFT JOIN addresses ON users.login = addresses.login";
+      let expected_query = "FROM users LEFT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
     }
@@ -425,11 +887,12 @@ mod left_join_clause {
     #[test]
     fn method_left_join_should_accumulate_values_on_consecutive_calls() {
       let query = sql::Update::new()
+        .from("users")
         .left_join("addresses ON users.login = addresses.login")
         .left_join("orders ON users.login = orders.login")
         .as_string();
       let expected_query = "\
-        LEFT JOIN addresses ON users.login = addresses.login \
+        FROM users LEFT JOIN addresses ON users.login = addresses.login \
         LEFT JOIN orders ON users.login = orders.login\
       ";
 
@@ -439,19 +902,20 @@ mod left_join_clause {
     #[test]
     fn method_left_join_should_not_accumulate_values_when_table_expression_is_empty() {
       let query = sql::Update::new()
+        .from("users")
         .left_join("")
         .left_join("orders ON users.login = orders.login")
         .left_join("")
         .as_string();
-      let expected_query = "LEFT JOIN orders ON users.login = orders.login";
+      let expected_query = "FROM users LEFT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_left_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().left_join("  orders  ").as_string();
-      let expected_query = "LEFT JOIN orders";
+      let query = sql::Update::new().from("users").left_join("  orders  ").as_string();
+      let expected_query = "FROM users LEFT JOIN orders";
 
       assert_eq!(expected_query, query);
     }
@@ -459,10 +923,11 @@ mod left_join_clause {
     #[test]
     fn method_left_join_should_not_accumulate_arguments_with_the_same_content() {
       let query = sql::Update::new()
+        .from("users")
         .left_join("addresses")
         .left_join("addresses")
         .as_string();
-      let expected_query = "LEFT JOIN addresses";
+      let expected_query = "FROM users LEFT JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
@@ -477,6 +942,106 @@ mod left_join_clause {
 
       assert_eq!(expected_query, query);
     }
+
+    #[test]
+    fn clause_left_join_without_from_clause_should_not_be_rendered() {
+      let query = sql::Update::new()
+        .update("users")
+        .set("active = false")
+        .left_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_left_join_should_add_the_left_join_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .left_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "USING users LEFT JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new()
+        .using("users")
+        .left_join("addresses ON users.login = addresses.login")
+        .left_join("orders ON users.login = orders.login")
+        .as_string();
+      let expected_query = "\
+        USING users LEFT JOIN addresses ON users.login = addresses.login \
+        LEFT JOIN orders ON users.login = orders.login\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_should_not_accumulate_values_when_table_expression_is_empty() {
+      let query = sql::Delete::new()
+        .using("users")
+        .left_join("")
+        .left_join("orders ON users.login = orders.login")
+        .left_join("")
+        .as_string();
+      let expected_query = "USING users LEFT JOIN orders ON users.login = orders.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_by_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new().using("users").left_join("  orders  ").as_string();
+      let expected_query = "USING users LEFT JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Delete::new()
+        .using("users")
+        .left_join("addresses")
+        .left_join("addresses")
+        .as_string();
+      let expected_query = "USING users LEFT JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_left_join_should_be_after_using_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .left_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "USING users LEFT JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_left_join_without_using_clause_should_not_be_rendered() {
+      let query = sql::Delete::new()
+        .delete_from("users")
+        .left_join("addresses ON users.login = addresses.login")
+        .where_clause("users.active = false")
+        .as_string();
+      let expected_query = "DELETE FROM users WHERE users.active = false";
+
+      assert_eq!(expected_query, query);
+    }
   }
 }
 
@@ -552,7 +1117,7 @@ mod right_join_clause {
     }
   }
 
-  #[cfg(feature = "sqlite")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   mod update_command {
     use pretty_assertions::assert_eq;
     use sql_query_builder as sql;
@@ -560,9 +1125,10 @@ mod right_join_clause {
     #[test]
     fn method_right_join_should_add_the_right_join_clause() {
       let query = sql::Update::new()
+        .from("users")
         .right_join("addresses ON users.login = addresses.login")
         .as_string();
-      let expected_query = "RIGHT JOIN addresses ON users.login = addresses.login";
+      let expected_query = "FROM users RIGHT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
     }
@@ -570,11 +1136,12 @@ mod right_join_clause {
     #[test]
     fn method_right_join_should_accumulate_values_on_consecutive_calls() {
       let query = sql::Update::new()
+        .from("users")
         .right_join("addresses ON users.login = addresses.login")
         .right_join("orders ON users.login = orders.login")
         .as_string();
       let expected_query = "\
-        RIGHT JOIN addresses ON users.login = addresses.login \
+        FROM users RIGHT JOIN addresses ON users.login = addresses.login \
         RIGHT JOIN orders ON users.login = orders.login\
       ";
 
@@ -584,19 +1151,20 @@ mod right_join_clause {
     #[test]
     fn method_right_join_should_not_accumulate_values_when_table_expression_is_empty() {
       let query = sql::Update::new()
+        .from("users")
         .right_join("")
         .right_join("orders ON users.login = orders.login")
         .right_join("")
         .as_string();
-      let expected_query = "RIGHT JOIN orders ON users.login = orders.login";
+      let expected_query = "FROM users RIGHT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
     }
 
     #[test]
     fn method_right_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().right_join("  orders  ").as_string();
-      let expected_query = "RIGHT JOIN orders";
+      let query = sql::Update::new().from("users").right_join("  orders  ").as_string();
+      let expected_query = "FROM users RIGHT JOIN orders";
 
       assert_eq!(expected_query, query);
     }
@@ -604,10 +1172,11 @@ mod right_join_clause {
     #[test]
     fn method_right_join_should_not_accumulate_arguments_with_the_same_content() {
       let query = sql::Update::new()
+        .from("users")
         .right_join("addresses")
         .right_join("addresses")
         .as_string();
-      let expected_query = "RIGHT JOIN addresses";
+      let expected_query = "FROM users RIGHT JOIN addresses";
 
       assert_eq!(expected_query, query);
     }
@@ -622,5 +1191,195 @@ mod right_join_clause {
 
       assert_eq!(expected_query, query);
     }
+
+    #[test]
+    fn clause_right_join_without_from_clause_should_not_be_rendered() {
+      let query = sql::Update::new()
+        .update("users")
+        .set("active = false")
+        .right_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_right_join_should_add_the_right_join_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .right_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "USING users RIGHT JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_right_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new()
+        .using("users")
+        .right_join("addresses ON users.login = addresses.login")
+        .right_join("orders ON users.login = orders.login")
+        .as_string();
+      let expected_query = "\
+        USING users RIGHT JOIN addresses ON users.login = addresses.login \
+        RIGHT JOIN orders ON users.login = orders.login\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_right_join_should_not_accumulate_values_when_table_expression_is_empty() {
+      let query = sql::Delete::new()
+        .using("users")
+        .right_join("")
+        .right_join("orders ON users.login = orders.login")
+        .right_join("")
+        .as_string();
+      let expected_query = "USING users RIGHT JOIN orders ON users.login = orders.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_right_join_by_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new().using("users").right_join("  orders  ").as_string();
+      let expected_query = "USING users RIGHT JOIN orders";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_right_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Delete::new()
+        .using("users")
+        .right_join("addresses")
+        .right_join("addresses")
+        .as_string();
+      let expected_query = "USING users RIGHT JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_right_join_should_be_after_using_clause() {
+      let query = sql::Delete::new()
+        .using("users")
+        .right_join("addresses ON users.login = addresses.login")
+        .as_string();
+      let expected_query = "USING users RIGHT JOIN addresses ON users.login = addresses.login";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn clause_right_join_without_using_clause_should_not_be_rendered() {
+      let query = sql::Delete::new()
+        .delete_from("users")
+        .right_join("addresses ON users.login = addresses.login")
+        .where_clause("users.active = false")
+        .as_string();
+      let expected_query = "DELETE FROM users WHERE users.active = false";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+}
+
+mod lateral_join_clause {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_left_join_lateral_should_add_the_lateral_keyword() {
+      let query = sql::Select::new()
+        .left_join_lateral("(SELECT * FROM orders WHERE orders.user_id = users.id) o ON true")
+        .as_string();
+      let expected_query = "LEFT JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id) o ON true";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_inner_join_lateral_should_add_the_lateral_keyword() {
+      let query = sql::Select::new()
+        .inner_join_lateral("generate_series(1, users.seats) s ON true")
+        .as_string();
+      let expected_query = "INNER JOIN LATERAL generate_series(1, users.seats) s ON true";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_lateral_should_not_accumulate_values_when_expression_is_empty() {
+      let query = sql::Select::new()
+        .left_join_lateral("  ")
+        .inner_join_lateral("")
+        .as_string();
+      let expected_query = "";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_lateral_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new()
+        .left_join_lateral("f(users.id) f ON true")
+        .left_join_lateral("  f(users.id) f ON true  ")
+        .as_string();
+      let expected_query = "LEFT JOIN LATERAL f(users.id) f ON true";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_joins_should_keep_the_order_of_the_calls_with_lateral_joins() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join("addresses ON addresses.user_id = users.id")
+        .left_join_lateral("(SELECT * FROM orders WHERE orders.user_id = users.id) o ON true")
+        .left_join("phones ON phones.user_id = users.id")
+        .inner_join_lateral("f(o.id) f ON true")
+        .as_string();
+      let expected_query = "\
+        FROM users \
+        INNER JOIN addresses ON addresses.user_id = users.id \
+        LEFT JOIN LATERAL (SELECT * FROM orders WHERE orders.user_id = users.id) o ON true \
+        LEFT JOIN phones ON phones.user_id = users.id \
+        INNER JOIN LATERAL f(o.id) f ON true\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_left_join_lateral_should_use_the_keyword_case_of_the_formatter() {
+      let query = sql::Select::new()
+        .left_join_lateral("f(users.id) f ON true")
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "left join lateral f(users.id) f ON true";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_the_lateral_join() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::Join, "from users")
+        .left_join_lateral("f(users.id) f ON true")
+        .as_string();
+      let expected_query = "from users LEFT JOIN LATERAL f(users.id) f ON true";
+
+      assert_eq!(expected_query, query);
+    }
   }
 }
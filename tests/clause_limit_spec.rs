@@ -27,6 +27,41 @@ mod select_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_limit_should_accept_an_sql_expression() {
+    let query = sql::Select::new()
+      .limit("3")
+      .limit("(SELECT n FROM config)")
+      .as_string();
+    let expected_query = "LIMIT (SELECT n FROM config)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_value_should_add_the_limit_clause() {
+    let query = sql::Select::new().limit_value(3).as_string();
+    let expected_query = "LIMIT 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_value_should_override_the_value_of_the_limit_method() {
+    let query = sql::Select::new().limit("ALL").limit_value(30).as_string();
+    let expected_query = "LIMIT 30";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_should_override_the_value_of_the_limit_value_method() {
+    let query = sql::Select::new().limit_value(30).limit("ALL").as_string();
+    let expected_query = "LIMIT ALL";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn clause_limit_should_be_after_order_by_clause() {
     let query = sql::Select::new().order_by("created_at desc").limit("42").as_string();
@@ -57,3 +92,126 @@ mod select_command {
     assert_eq!(query, expected_query);
   }
 }
+
+#[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+mod select_command_mssql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_limit_should_add_the_top_keyword_to_the_select_clause() {
+    let query = sql::Select::new().select("id, login").limit("3").as_string();
+    let expected_query = "SELECT TOP 3 id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_should_override_the_current_value() {
+    let query = sql::Select::new().select("id").limit("3").limit("4").as_string();
+    let expected_query = "SELECT TOP 4 id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_should_add_the_select_keyword_when_there_is_no_projection() {
+    let query = sql::Select::new().limit("3").as_string();
+    let expected_query = "SELECT TOP 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_value_should_be_rendered_as_fetch_next_when_offset_value_is_defined() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .limit_value(10)
+      .offset_value(20)
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_should_be_rendered_as_fetch_next_when_offset_is_defined() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .limit("10")
+      .offset("20")
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_limit_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::Limit, "/* limit */")
+      .order_by("id")
+      .offset("20")
+      .limit("10")
+      .as_string();
+    let expected_query = "ORDER BY id /* limit */ OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset("20")
+      .limit("10")
+      .raw_after(sql::SelectClause::Limit, "/* end limit */")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY /* end limit */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_the_top_keyword() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .limit("10")
+      .raw_before(sql::SelectClause::Limit, "/* limit */")
+      .as_string();
+    let expected_query = "SELECT /* limit */ TOP 10 id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_top_keyword() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .limit("10")
+      .raw_after(sql::SelectClause::Limit, "PERCENT")
+      .as_string();
+    let expected_query = "SELECT TOP 10 PERCENT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_add_the_order_by_required_by_sql_server() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .offset("20")
+      .limit("10")
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY (SELECT NULL) OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -27,6 +27,41 @@ mod select_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_offset_should_accept_an_sql_expression() {
+    let query = sql::Select::new()
+      .offset("3")
+      .offset("(SELECT n FROM config)")
+      .as_string();
+    let expected_query = "OFFSET (SELECT n FROM config)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_value_should_add_the_offset_clause() {
+    let query = sql::Select::new().offset_value(3).as_string();
+    let expected_query = "OFFSET 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_value_should_override_the_value_of_the_offset_method() {
+    let query = sql::Select::new().offset("ALL").offset_value(30).as_string();
+    let expected_query = "OFFSET 30";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_override_the_value_of_the_offset_value_method() {
+    let query = sql::Select::new().offset_value(30).offset("ALL").as_string();
+    let expected_query = "OFFSET ALL";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn clause_offset_should_be_after_limit_clause() {
     let query = sql::Select::new().limit("500").offset("100").as_string();
@@ -57,3 +92,78 @@ mod select_command {
     assert_eq!(query, expected_query);
   }
 }
+
+#[cfg(all(feature = "mssql", not(any(feature = "postgresql", feature = "sqlite"))))]
+mod select_command_mssql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_offset_should_add_the_offset_rows_clause() {
+    let query = sql::Select::new().order_by("id").offset("100").as_string();
+    let expected_query = "ORDER BY id OFFSET 100 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_override_the_current_value() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset("100")
+      .offset("200")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 200 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_offset_should_be_after_order_by_clause() {
+    let query = sql::Select::new().order_by("created_at desc").offset("42").as_string();
+    let expected_query = "ORDER BY created_at desc OFFSET 42 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_offset_clause() {
+    let query = sql::Select::new()
+      .order_by("id")
+      .offset("10")
+      .raw_after(sql::SelectClause::Offset, "/* end offset */")
+      .as_string();
+    let expected_query = "ORDER BY id OFFSET 10 ROWS /* end offset */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_add_the_order_by_required_by_sql_server() {
+    let query = sql::Select::new().select("id").from("users").offset("10").as_string();
+    let expected_query = "SELECT id FROM users ORDER BY (SELECT NULL) OFFSET 10 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_of_the_order_by_should_be_rendered_after_the_order_by_required_by_sql_server() {
+    let query = sql::Select::new()
+      .offset("10")
+      .raw_after(sql::SelectClause::OrderBy, "/* order */")
+      .as_string();
+    let expected_query = "ORDER BY (SELECT NULL) /* order */ OFFSET 10 ROWS";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_render_the_order_by_required_by_sql_server_with_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .offset("10")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "order by (select null) offset 10 rows";
+
+    assert_eq!(query, expected_query);
+  }
+}
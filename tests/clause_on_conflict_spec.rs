@@ -61,4 +61,326 @@ mod insert_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_on_conflict_do_nothing_should_render_the_columns_of_the_conflict_target() {
+    let query = sql::Insert::new()
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["login", " email "]))
+      .as_string();
+    let expected_query = "ON CONFLICT (login, email) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_nothing_should_not_render_an_empty_list_of_columns() {
+    let query = sql::Insert::new()
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&[]))
+      .as_string();
+    let expected_query = "ON CONFLICT DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_nothing_should_render_the_constraint_of_the_conflict_target() {
+    let query = sql::Insert::new()
+      .on_conflict_do_nothing(sql::ConflictTarget::constraint("users_login_key"))
+      .as_string();
+    let expected_query = "ON CONFLICT ON CONSTRAINT users_login_key DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_render_the_assignments() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update(
+        sql::ConflictTarget::columns(&["id"]),
+        [("name", "EXCLUDED.name"), ("login", "EXCLUDED.login")],
+      )
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, login = EXCLUDED.login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_skip_assignments_without_column() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update(
+        sql::ConflictTarget::columns(&["id"]),
+        [("  ", "EXCLUDED.login"), (" name ", " EXCLUDED.name ")],
+      )
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_where_should_add_the_predicate_of_the_do_update_action() {
+    let query = sql::Insert::new()
+      .on_conflict_where("users.active = true")
+      .on_conflict_do_update(sql::ConflictTarget::columns(&["id"]), [("name", "EXCLUDED.name")])
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name WHERE users.active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_where_should_not_be_rendered_with_other_actions() {
+    let query = sql::Insert::new()
+      .on_conflict_where("users.active = true")
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["id"]))
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_not_render_the_action_without_conflict_target() {
+    let query = sql::Insert::new()
+      .insert_into("users (id, name)")
+      .values("(1, 'Foo')")
+      .on_conflict_do_update(sql::ConflictTarget::columns(&[" "]), [("name", "EXCLUDED.name")])
+      .as_string();
+    let expected_query = "INSERT INTO users (id, name) VALUES (1, 'Foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_keep_the_previous_value_when_the_conflict_target_is_empty() {
+    let query = sql::Insert::new()
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["id"]))
+      .on_conflict_do_update(sql::ConflictTarget::constraint(""), [("name", "EXCLUDED.name")])
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_override_the_on_conflict_method() {
+    let query = sql::Insert::new()
+      .on_conflict("DO NOTHING")
+      .on_conflict_do_update(sql::ConflictTarget::columns(&["id"]), [("name", "EXCLUDED.name")])
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_should_override_the_structured_methods() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update(sql::ConflictTarget::columns(&["id"]), [("name", "EXCLUDED.name")])
+      .on_conflict("DO NOTHING")
+      .as_string();
+    let expected_query = "ON CONFLICT DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_nothing_should_override_the_do_update_action() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update(sql::ConflictTarget::columns(&["id"]), [("name", "EXCLUDED.name")])
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["login"]))
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update(
+        sql::ConflictTarget::constraint("users_pkey"),
+        [("name", "EXCLUDED.name")],
+      )
+      .on_conflict_where("active")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "on conflict on constraint users_pkey do update set name = EXCLUDED.name where active";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_structured_on_conflict_clause() {
+    let query = sql::Insert::new()
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["id"]))
+      .raw_after(sql::InsertClause::OnConflict, "RETURNING id")
+      .as_string();
+    let expected_query = "ON CONFLICT (id) DO NOTHING RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod insert_command_excluded {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_conflict_do_update_excluded_should_assign_the_excluded_values() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name", " email "])
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name, email = EXCLUDED.email";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_excluded_should_skip_empty_columns() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["", "name"])
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_excluded_should_override_the_on_conflict_method() {
+    let query = sql::Insert::new()
+      .on_conflict("DO NOTHING")
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name"])
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_do_update_excluded_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Insert::new()
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name"])
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "on conflict (login) do update set name = excluded.name";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod insert_command_upsert {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_upsert_should_update_the_columns_on_conflict_of_a_single_column_key() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name, email)")
+      .values("('foo', 'Foo', 'foo@mail.com')")
+      .upsert("login", &["name", "email"])
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login, name, email) \
+      VALUES ('foo', 'Foo', 'foo@mail.com') \
+      ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name, email = EXCLUDED.email\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_update_the_columns_on_conflict_of_a_multi_column_key() {
+    let query = sql::Insert::new()
+      .insert_into("users (tenant_id, login, name)")
+      .values("(1, 'foo', 'Foo')")
+      .upsert(" tenant_id , login ", &["name"])
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (tenant_id, login, name) \
+      VALUES (1, 'foo', 'Foo') \
+      ON CONFLICT (tenant_id, login) DO UPDATE SET name = EXCLUDED.name\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_do_nothing_when_there_are_no_update_columns() {
+    let query = sql::Insert::new().upsert("login", &[]).as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_do_nothing_when_all_update_columns_are_empty() {
+    let query = sql::Insert::new().upsert("login", &["", " "]).as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_override_the_previous_on_conflict_clause() {
+    let query = sql::Insert::new()
+      .on_conflict("DO NOTHING")
+      .upsert("login", &["name"])
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Insert::new()
+      .upsert("tenant_id, login", &["name", "email"])
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "on conflict (tenant_id, login) do update set name = excluded.name, email = excluded.email";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod insert_command_excluded_sqlite {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_conflict_do_update_excluded_should_render_the_excluded_table_as_the_sqlite_documentation() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo')")
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name"])
+      .as_string_with(&sql::fmt::one_line().with_keyword_case(sql::fmt::KeywordCase::Preserve));
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      VALUES ('foo', 'Foo') \
+      ON CONFLICT (login) DO UPDATE SET name = excluded.name\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_returning_should_be_after_the_on_conflict_do_update_excluded_clause() {
+    let query = sql::Insert::new()
+      .returning("id")
+      .on_conflict_do_update_excluded(sql::ConflictTarget::columns(&["login"]), &["name"])
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = EXCLUDED.name RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_upsert_should_render_the_excluded_table_as_the_sqlite_documentation() {
+    let query = sql::Insert::new()
+      .upsert("tenant_id, login", &["name"])
+      .as_string_with(&sql::fmt::one_line().with_keyword_case(sql::fmt::KeywordCase::Preserve));
+    let expected_query = "ON CONFLICT (tenant_id, login) DO UPDATE SET name = excluded.name";
+
+    assert_eq!(query, expected_query);
+  }
 }
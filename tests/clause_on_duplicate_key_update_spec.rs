@@ -0,0 +1,122 @@
+#[cfg(feature = "mysql")]
+mod insert_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_duplicate_key_update_should_add_a_on_duplicate_key_update_clause() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_duplicate_key_update_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .on_duplicate_key_update("name = VALUES(name)")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login), name = VALUES(name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_duplicate_key_update_should_not_accumulate_values_when_assignment_is_empty() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .on_duplicate_key_update("  ")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_duplicate_key_update_should_trim_space_of_the_argument() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("  login = VALUES(login)  ")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_duplicate_key_update_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_duplicate_key_update_should_replace_the_on_conflict_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_conflict("DO NOTHING")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_duplicate_key_update_should_be_after_values_clause() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .values("('foo')")
+      .insert_into("users (login)")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_duplicate_key_update_should_be_after_select_clause() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .select(sql::Select::new().select("login").from("admins"))
+      .insert_into("users (login)")
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login) \
+      SELECT login FROM admins \
+      ON DUPLICATE KEY UPDATE login = VALUES(login)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_on_duplicate_key_update_clause() {
+    let query = sql::Insert::new()
+      .raw_before(sql::InsertClause::OnDuplicateKeyUpdate, "values ('foo')")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .as_string();
+    let expected_query = "values ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_on_duplicate_key_update_clause() {
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = VALUES(login)")
+      .raw_after(sql::InsertClause::OnDuplicateKeyUpdate, ", name = VALUES(name)")
+      .as_string();
+    let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login) , name = VALUES(name)";
+
+    assert_eq!(query, expected_query);
+  }
+}
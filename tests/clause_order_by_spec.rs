@@ -89,4 +89,207 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_order_by_asc_should_sort_the_column_in_ascending_order() {
+    let query = sql::Select::new().order_by_asc("  login ").as_string();
+    let expected_query = "ORDER BY login ASC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_desc_should_sort_the_column_in_descending_order() {
+    let query = sql::Select::new().order_by_desc("created_at").as_string();
+    let expected_query = "ORDER BY created_at DESC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_with_should_render_each_combination_of_direction_and_nulls_order() {
+    let cases = [
+      (sql::Order::Asc, sql::NullsOrder::Default, "ORDER BY name ASC"),
+      (sql::Order::Asc, sql::NullsOrder::First, "ORDER BY name ASC NULLS FIRST"),
+      (sql::Order::Asc, sql::NullsOrder::Last, "ORDER BY name ASC NULLS LAST"),
+      (sql::Order::Desc, sql::NullsOrder::Default, "ORDER BY name DESC"),
+      (
+        sql::Order::Desc,
+        sql::NullsOrder::First,
+        "ORDER BY name DESC NULLS FIRST",
+      ),
+      (sql::Order::Desc, sql::NullsOrder::Last, "ORDER BY name DESC NULLS LAST"),
+    ];
+
+    for (order, nulls, expected_query) in cases {
+      let query = sql::Select::new().order_by_with("name", order, nulls).as_string();
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  #[test]
+  fn method_order_by_with_should_accumulate_values_with_the_order_by_method() {
+    let query = sql::Select::new()
+      .order_by("random()")
+      .order_by_with("last_login", sql::Order::Desc, sql::NullsOrder::Last)
+      .order_by_asc("login")
+      .as_string();
+    let expected_query = "ORDER BY random(), last_login DESC NULLS LAST, login ASC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_with_should_not_accumulate_values_with_the_same_content() {
+    let query = sql::Select::new()
+      .order_by_desc("id")
+      .order_by_with("id", sql::Order::Desc, sql::NullsOrder::Default)
+      .as_string();
+    let expected_query = "ORDER BY id DESC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_with_should_not_accumulate_values_when_column_is_empty() {
+    let query = sql::Select::new()
+      .order_by_asc("  ")
+      .order_by_with("", sql::Order::Desc, sql::NullsOrder::First)
+      .as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_with_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .order_by_with("Login", sql::Order::Desc, sql::NullsOrder::Last)
+      .order_by("id ASC")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "order by Login desc nulls last, id ASC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_should_accumulate_values_that_differ_only_by_case_by_default() {
+    let query = sql::Select::new().order_by("ID desc").order_by("id DESC").as_string();
+    let expected_query = "ORDER BY ID desc, id DESC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_ignore_case_should_collapse_values_that_differ_only_by_case() {
+    let query = sql::Select::new()
+      .order_by_ignore_case()
+      .order_by("ID desc")
+      .order_by("id DESC")
+      .order_by_desc("Id")
+      .as_string();
+    let expected_query = "ORDER BY ID desc";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_ignore_case_should_keep_the_first_of_the_values_already_added() {
+    let query = sql::Select::new()
+      .order_by("Login")
+      .order_by("created_at")
+      .order_by("LOGIN")
+      .order_by_ignore_case()
+      .as_string();
+    let expected_query = "ORDER BY Login, created_at";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_ignore_case_should_not_normalize_the_whitespaces() {
+    let query = sql::Select::new()
+      .order_by_ignore_case()
+      .order_by("id  desc")
+      .order_by("ID DESC")
+      .as_string();
+    let expected_query = "ORDER BY id  desc, ID DESC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_ignore_case_should_compare_the_quoted_identifiers_exactly() {
+    let query = sql::Select::new()
+      .order_by_ignore_case()
+      .order_by("\"Login\" DESC")
+      .order_by("\"login\" desc")
+      .order_by("\"Login\" desc")
+      .as_string();
+    let expected_query = "ORDER BY \"Login\" DESC, \"login\" desc";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(all(feature = "sqlite", not(feature = "postgresql")))]
+mod select_command_sqlite {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_sample_rows_should_select_from_a_derived_table_of_random_rows() {
+    let query = sql::Select::new().select("*").sample_rows("users", 10).as_string();
+    let expected_query = "SELECT * FROM (SELECT * FROM users ORDER BY random() LIMIT 10) AS users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_not_change_the_order_by_and_limit_clauses() {
+    let query = sql::Select::new()
+      .limit("500")
+      .order_by("created_at desc")
+      .sample_rows("users", 10)
+      .as_string();
+    let expected_query = "\
+      FROM (SELECT * FROM users ORDER BY random() LIMIT 10) AS users \
+      ORDER BY created_at desc \
+      LIMIT 500\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_name_the_derived_table_as_the_alias_of_the_table() {
+    let query = sql::Select::new()
+      .select("u.login")
+      .sample_rows("main.users u", 10)
+      .as_string();
+    let expected_query = "SELECT u.login FROM (SELECT * FROM main.users ORDER BY random() LIMIT 10) AS u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_name_the_derived_table_as_the_table_without_the_schema() {
+    let query = sql::Select::new().select("*").sample_rows("main.users", 10).as_string();
+    let expected_query = "SELECT * FROM (SELECT * FROM main.users ORDER BY random() LIMIT 10) AS users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_sample_rows_should_not_change_the_query_when_table_name_is_empty() {
+    let query = sql::Select::new()
+      .select("*")
+      .limit("5")
+      .sample_rows("", 10)
+      .as_string();
+    let expected_query = "SELECT * LIMIT 5";
+
+    assert_eq!(query, expected_query);
+  }
 }
@@ -1,4 +1,4 @@
-#[cfg(not(feature = "sqlite"))]
+#[cfg(any(feature = "postgresql", not(feature = "sqlite")))]
 mod insert_command {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
@@ -62,4 +62,48 @@ mod insert_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_overriding_system_should_add_the_overriding_system_value_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (id, login)")
+      .overriding_system()
+      .values("(1, 'foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users (id, login) OVERRIDING SYSTEM VALUE VALUES (1, 'foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_overriding_user_should_add_the_overriding_user_value_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (id, login)")
+      .overriding_user()
+      .values("(1, 'foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users (id, login) OVERRIDING USER VALUE VALUES (1, 'foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_overriding_user_should_override_the_previous_value() {
+    let query = sql::Insert::new().overriding_system().overriding_user().as_string();
+    let expected_query = "OVERRIDING USER VALUE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_overriding_system_should_be_removed_by_the_clear_method() {
+    let query = sql::Insert::new()
+      .insert_into("users (id)")
+      .overriding_system()
+      .clear(sql::InsertClause::Overriding)
+      .as_string();
+    let expected_query = "INSERT INTO users (id)";
+
+    assert_eq!(query, expected_query);
+  }
 }
@@ -1,4 +1,4 @@
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
 mod insert_command {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
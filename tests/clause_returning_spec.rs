@@ -55,6 +55,153 @@ mod delete_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_returning_all_should_add_the_returning_clause_with_all_columns() {
+    let query = sql::Delete::new().returning_all().as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_all_should_replace_the_previous_output_names() {
+    let query = sql::Delete::new()
+      .returning("id")
+      .returning("login")
+      .returning_all()
+      .as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_replace_the_star_after_the_returning_all_method() {
+    let query = sql::Delete::new().returning_all().returning("id").as_string();
+    let expected_query = "RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_not_accumulate_the_same_output_name_after_the_returning_all_method() {
+    let query = sql::Delete::new()
+      .returning_all()
+      .returning("id")
+      .returning("id")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_with_the_star_output_name_should_not_be_mixed_with_other_names() {
+    let query = sql::Delete::new()
+      .returning("id")
+      .returning("  *  ")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_the_output_names_in_the_order_they_were_added() {
+    let delete = sql::Delete::new()
+      .returning("id")
+      .returning("")
+      .returning("  login  ")
+      .returning("id");
+
+    assert_eq!(delete.get_returning(), ["id", "login"]);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_an_empty_list_when_returning_is_not_defined() {
+    let delete = sql::Delete::new();
+
+    assert!(delete.get_returning().is_empty());
+  }
+
+  #[test]
+  fn method_returning_as_should_add_the_expression_with_its_alias() {
+    let query = sql::Delete::new()
+      .returning_as(" id ", " pk ")
+      .returning_as("lower(login)", "login")
+      .as_string();
+    let expected_query = "RETURNING id AS pk, lower(login) AS login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_add_the_expression_when_the_alias_is_empty() {
+    let query = sql::Delete::new().returning_as("id", "").as_string();
+    let expected_query = "RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::Delete::new().returning_as("", "pk").as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_replace_the_output_name_of_the_same_expression_keeping_the_order() {
+    let query = sql::Delete::new()
+      .returning("id")
+      .returning("login")
+      .returning_as("id", "pk")
+      .as_string();
+    let expected_query = "RETURNING id AS pk, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_render_the_alias_keyword_with_the_case_of_the_formatter() {
+    let query = sql::Delete::new()
+      .returning_as("id", "pk")
+      .returning("login")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "returning id as pk, login";
+
+    assert_eq!(query, expected_query);
+
+    let output_names = sql::Delete::new().returning_as("id", "pk").get_returning();
+    assert_eq!(output_names, ["id AS pk"]);
+  }
+
+  #[test]
+  fn method_returning_should_replace_the_aliased_output_name_of_the_same_expression() {
+    let query = sql::Delete::new()
+      .returning("id AS pk")
+      .returning("name")
+      .returning("id")
+      .as_string();
+    let expected_query = "RETURNING id, name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_not_consider_the_as_inside_parentheses_an_alias() {
+    let query = sql::Delete::new()
+      .returning("cast(id AS text)")
+      .returning("cast(id AS int)")
+      .as_string();
+    let expected_query = "RETURNING cast(id AS text), cast(id AS int)";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_returning_clause() {
     let query = sql::Delete::new()
@@ -140,6 +287,114 @@ mod insert_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_returning_all_should_add_the_returning_clause_with_all_columns() {
+    let query = sql::Insert::new().returning_all().as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_all_should_replace_the_previous_output_names() {
+    let query = sql::Insert::new()
+      .returning("id")
+      .returning("login")
+      .returning_all()
+      .as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_replace_the_star_after_the_returning_all_method() {
+    let query = sql::Insert::new().returning_all().returning("id").as_string();
+    let expected_query = "RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_not_accumulate_the_same_output_name_after_the_returning_all_method() {
+    let query = sql::Insert::new()
+      .returning_all()
+      .returning("id")
+      .returning("id")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_with_the_star_output_name_should_not_be_mixed_with_other_names() {
+    let query = sql::Insert::new()
+      .returning("id")
+      .returning("  *  ")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_the_output_names_in_the_order_they_were_added() {
+    let insert = sql::Insert::new()
+      .returning("id")
+      .returning("")
+      .returning("  login  ")
+      .returning("id");
+
+    assert_eq!(insert.get_returning(), ["id", "login"]);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_an_empty_list_when_returning_is_not_defined() {
+    let insert = sql::Insert::new();
+
+    assert!(insert.get_returning().is_empty());
+  }
+
+  #[test]
+  fn method_returning_as_should_add_the_expression_with_its_alias() {
+    let query = sql::Insert::new()
+      .returning("login")
+      .returning_as("id", "pk")
+      .as_string();
+    let expected_query = "RETURNING login, id AS pk";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_replace_the_output_name_of_the_same_expression_keeping_the_order() {
+    let query = sql::Insert::new()
+      .returning_as("id", "pk")
+      .returning("login")
+      .returning_as("id", "user_id")
+      .as_string();
+    let expected_query = "RETURNING id AS user_id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_render_the_alias_keyword_with_the_case_of_the_formatter() {
+    let query = sql::Insert::new()
+      .returning_as("id", "pk")
+      .returning("login")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "returning id as pk, login";
+
+    assert_eq!(query, expected_query);
+
+    let output_names = sql::Insert::new().returning_as("id", "pk").get_returning();
+    assert_eq!(output_names, ["id AS pk"]);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_returning_clause() {
     let query = sql::Insert::new()
@@ -208,6 +463,114 @@ mod update_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_returning_all_should_add_the_returning_clause_with_all_columns() {
+    let query = sql::Update::new().returning_all().as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_all_should_replace_the_previous_output_names() {
+    let query = sql::Update::new()
+      .returning("id")
+      .returning("login")
+      .returning_all()
+      .as_string();
+    let expected_query = "RETURNING *";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_replace_the_star_after_the_returning_all_method() {
+    let query = sql::Update::new().returning_all().returning("id").as_string();
+    let expected_query = "RETURNING id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_should_not_accumulate_the_same_output_name_after_the_returning_all_method() {
+    let query = sql::Update::new()
+      .returning_all()
+      .returning("id")
+      .returning("id")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_with_the_star_output_name_should_not_be_mixed_with_other_names() {
+    let query = sql::Update::new()
+      .returning("id")
+      .returning("  *  ")
+      .returning("login")
+      .as_string();
+    let expected_query = "RETURNING login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_the_output_names_in_the_order_they_were_added() {
+    let update = sql::Update::new()
+      .returning("id")
+      .returning("")
+      .returning("  login  ")
+      .returning("id");
+
+    assert_eq!(update.get_returning(), ["id", "login"]);
+  }
+
+  #[test]
+  fn method_get_returning_should_return_an_empty_list_when_returning_is_not_defined() {
+    let update = sql::Update::new();
+
+    assert!(update.get_returning().is_empty());
+  }
+
+  #[test]
+  fn method_returning_as_should_add_the_expression_with_its_alias() {
+    let query = sql::Update::new()
+      .returning("login")
+      .returning_as("id", "pk")
+      .as_string();
+    let expected_query = "RETURNING login, id AS pk";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_replace_the_output_name_of_the_same_expression_keeping_the_order() {
+    let query = sql::Update::new()
+      .returning_as("id", "pk")
+      .returning("login")
+      .returning_as("id", "user_id")
+      .as_string();
+    let expected_query = "RETURNING id AS user_id, login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_returning_as_should_render_the_alias_keyword_with_the_case_of_the_formatter() {
+    let query = sql::Update::new()
+      .returning_as("id", "pk")
+      .returning("login")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "returning id as pk, login";
+
+    assert_eq!(query, expected_query);
+
+    let output_names = sql::Update::new().returning_as("id", "pk").get_returning();
+    assert_eq!(output_names, ["id AS pk"]);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_returning_clause() {
     let query = sql::Update::new()
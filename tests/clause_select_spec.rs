@@ -71,6 +71,91 @@ mod insert_command {
 
     assert_eq!(expected_query, query);
   }
+
+  #[test]
+  fn method_insert_select_should_produce_the_same_output_of_the_insert_into_and_select_calls() {
+    let backup = sql::Select::new()
+      .select("login, name")
+      .from("users_bk")
+      .where_clause("active = true");
+    let query = sql::Insert::new()
+      .insert_select("users (login, name)", backup.clone())
+      .as_string();
+    let expected_query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .select(backup)
+      .as_string();
+
+    assert_eq!(expected_query, query);
+    assert_eq!(
+      "INSERT INTO users (login, name) SELECT login, name FROM users_bk WHERE active = true",
+      query
+    );
+  }
+
+  #[test]
+  fn method_insert_select_should_override_the_previous_insert_into_and_select() {
+    let query = sql::Insert::new()
+      .insert_into("admins (login)")
+      .select(sql::Select::new().select("login").from("admins_bk"))
+      .insert_select("users (login)", sql::Select::new().select("login").from("users_bk"))
+      .as_string();
+    let expected_query = "INSERT INTO users (login) SELECT login FROM users_bk";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn clause_select_should_not_be_wrapped_in_parentheses_by_default() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("admins"));
+    let expected_query = "INSERT INTO users (login) SELECT login FROM admins";
+
+    assert_eq!(expected_query, insert.as_string_with(&sql::fmt::one_line()));
+  }
+
+  #[test]
+  fn clause_select_should_be_wrapped_in_parentheses_when_the_formatter_enables_paren_subqueries() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("admins"))
+      .on_conflict("do nothing");
+    let fmts = sql::fmt::one_line().with_paren_subqueries(true);
+    let expected_query = "INSERT INTO users (login) (SELECT login FROM admins) ON CONFLICT do nothing";
+
+    assert_eq!(expected_query, insert.as_string_with(&fmts));
+  }
+
+  #[test]
+  fn clause_select_should_be_wrapped_in_parentheses_in_the_multiline_formatter() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("admins"));
+    let fmts = sql::fmt::multiline().with_paren_subqueries(true);
+    let expected_query = "INSERT INTO users (login) \n(SELECT login \nFROM admins)";
+
+    assert_eq!(expected_query, insert.as_string_with(&fmts));
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  #[allow(deprecated)]
+  fn clause_select_should_wrap_the_whole_union_in_parentheses_when_the_formatter_enables_paren_subqueries() {
+    let insert = sql::Insert::new().insert_into("users (login)").select(
+      sql::Select::new()
+        .select("login")
+        .from("admins")
+        .union(sql::Select::new().select("login").from("guests")),
+    );
+    let fmts = sql::fmt::one_line().with_paren_subqueries(true);
+    let expected_query = "\
+      INSERT INTO users (login) \
+      ((SELECT login FROM admins) UNION (SELECT login FROM guests))\
+    ";
+
+    assert_eq!(expected_query, insert.as_string_with(&fmts));
+  }
 }
 
 mod select_command {
@@ -124,6 +209,147 @@ mod select_command {
     assert_eq!(expected_query, query);
   }
 
+  #[test]
+  fn method_select_as_should_add_the_expression_with_the_alias() {
+    let query = sql::Select::new().select_as("count(id)", "total").as_string();
+    let expected_query = "SELECT count(id) AS total";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_as_should_accumulate_values_with_the_select_method_in_order() {
+    let query = sql::Select::new()
+      .select("id")
+      .select_as("  first_name || ' ' || last_name ", "  full_name  ")
+      .select("login")
+      .select_as("created_at::date", "signup_date")
+      .as_string();
+    let expected_query =
+      "SELECT id, first_name || ' ' || last_name AS full_name, login, created_at::date AS signup_date";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_as_should_not_accumulate_fragments_with_the_same_content() {
+    let query = sql::Select::new()
+      .select_as("count(id)", "total")
+      .select("count(id) AS total")
+      .select_as("count(id)", "total")
+      .as_string();
+    let expected_query = "SELECT count(id) AS total";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_as_should_omit_the_alias_when_empty() {
+    let query = sql::Select::new().select_as("login", " ").as_string();
+    let expected_query = "SELECT login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_as_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::Select::new().select_as("  ", "total").select("id").as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_coalesce_should_add_a_coalesce_function_to_the_select_clause() {
+    let query = sql::Select::new()
+      .select_coalesce(&["nickname", "name"], "display_name")
+      .as_string();
+    let expected_query = "SELECT COALESCE(nickname, name) AS display_name";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_coalesce_should_accumulate_values_with_the_select_method() {
+    let query = sql::Select::new()
+      .select("id")
+      .select_coalesce(&["  nickname ", "", "'anonymous'"], "  display_name  ")
+      .select("login")
+      .as_string();
+    let expected_query = "SELECT id, COALESCE(nickname, 'anonymous') AS display_name, login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_coalesce_should_omit_the_alias_when_empty() {
+    let query = sql::Select::new().select_coalesce(&["price", "0"], "").as_string();
+    let expected_query = "SELECT COALESCE(price, 0)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_coalesce_should_not_accumulate_values_when_expressions_are_empty() {
+    let query = sql::Select::new()
+      .select_coalesce(&[], "display_name")
+      .select_coalesce(&["", " "], "display_name")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_as_and_select_coalesce_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Select::new()
+      .select_as("count(id)", "total")
+      .select_coalesce(&["nickname", "login"], "display_name")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "select count(id) as total, coalesce(nickname, login) as display_name";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_select_count_over_should_add_a_count_window_function_to_the_select_clause() {
+    let query = sql::Select::new()
+      .select_count_over("customer_id", "created_at", "orders_so_far")
+      .as_string();
+    let expected_query = "SELECT count(*) OVER (PARTITION BY customer_id ORDER BY created_at) AS orders_so_far";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_count_over_should_omit_the_empty_sub_clauses() {
+    let query = sql::Select::new()
+      .select_count_over("", "  created_at  ", "  running  ")
+      .select_count_over("  customer_id  ", "", "per_customer")
+      .select_count_over("", "", "")
+      .as_string();
+    let expected_query = "\
+      SELECT count(*) OVER (ORDER BY created_at) AS running, \
+      count(*) OVER (PARTITION BY customer_id) AS per_customer, \
+      count(*) OVER ()\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_count_over_should_accumulate_values_with_the_select_method() {
+    let query = sql::Select::new()
+      .select("id")
+      .select_count_over("", "id", "n")
+      .select("total")
+      .as_string();
+    let expected_query = "SELECT id, count(*) OVER (ORDER BY id) AS n, total";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_select_clause() {
     let query = sql::Select::new()
@@ -145,4 +371,171 @@ mod select_command {
 
     assert_eq!(expected_query, query);
   }
+
+  #[test]
+  fn method_distinct_should_render_the_distinct_keyword_once() {
+    let query = sql::Select::new()
+      .distinct()
+      .select("login")
+      .distinct()
+      .select("email")
+      .as_string();
+    let expected_query = "SELECT DISTINCT login, email";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_should_be_rendered_once_before_all_the_projection_fragments() {
+    let query = sql::Select::new()
+      .select("id")
+      .select_as("lower(email)", "email")
+      .distinct()
+      .select("login, name")
+      .from("users")
+      .as_string();
+    let expected_query = "SELECT DISTINCT id, lower(email) AS email, login, name FROM users";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(query.matches("DISTINCT").count(), 1);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_distinct_of_the_select_clause() {
+    let query = sql::Select::new()
+      .distinct()
+      .select("login")
+      .clear(sql::SelectClause::Select)
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod select_command_postgresql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_distinct_on_should_render_the_columns_after_the_select_keyword() {
+    let query = sql::Select::new().select("id, login").distinct_on("login").as_string();
+    let expected_query = "SELECT DISTINCT ON (login) id, login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Select::new()
+      .distinct_on("login")
+      .distinct_on("  email  ")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT ON (login, email) id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_keep_the_expression_with_commas_whole() {
+    let query = sql::Select::new()
+      .distinct_on("coalesce(nickname, login)")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT ON (coalesce(nickname, login)) id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_not_accumulate_values_with_the_same_content() {
+    let query = sql::Select::new()
+      .distinct_on("login")
+      .distinct_on("login")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT ON (login) id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_override_the_distinct_method() {
+    let query = sql::Select::new()
+      .distinct()
+      .distinct_on("login")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT ON (login) id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_should_override_the_distinct_on_method() {
+    let query = sql::Select::new()
+      .distinct_on("login")
+      .distinct()
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_not_add_the_value_when_the_argument_is_empty() {
+    let query = sql::Select::new()
+      .distinct_on("login")
+      .distinct_on("  ")
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT DISTINCT ON (login) id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_distinct_on_should_work_with_the_order_by_clause() {
+    let query = sql::Select::new()
+      .distinct_on("user_id")
+      .select("user_id, created_at")
+      .from("orders")
+      .order_by("user_id, created_at desc")
+      .as_string();
+    let expected_query = "\
+      SELECT DISTINCT ON (user_id) user_id, created_at \
+      FROM orders \
+      ORDER BY user_id, created_at desc\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_validate_should_check_the_columns_of_the_distinct_on_method() {
+    let warnings = sql::Select::new()
+      .distinct_on("user_id")
+      .select("user_id")
+      .order_by("created_at")
+      .validate();
+
+    assert_eq!(
+      warnings,
+      vec!["distinct on expressions must match the leftmost order by expressions".to_string()]
+    );
+  }
+  #[test]
+  fn method_validate_should_compare_the_distinct_on_expressions_with_commas_whole() {
+    let warnings = sql::Select::new()
+      .distinct_on("coalesce(nickname, login)")
+      .select("id")
+      .order_by("coalesce(nickname, login) desc, id")
+      .validate();
+
+    assert!(warnings.is_empty());
+  }
 }
@@ -42,6 +42,130 @@ mod update_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_set_from_should_render_each_pair_as_an_assignment() {
+    let query = sql::Update::new()
+      .set_from([("name", "'Bar'"), ("login", "'bar'")])
+      .as_string();
+    let expected_query = "SET name = 'Bar', login = 'bar'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_from_should_accept_owned_strings() {
+    let changes = vec![("name".to_string(), "'Bar'".to_string())];
+    let query = sql::Update::new().set_from(changes).as_string();
+    let expected_query = "SET name = 'Bar'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_from_should_override_the_assignment_of_the_same_column() {
+    let query = sql::Update::new()
+      .set("name = 'Foo'")
+      .set("login = 'foo'")
+      .set_from([("name", "'Bar'")])
+      .set_from([("login", "'baz'"), ("login", "'bar'")])
+      .as_string();
+    let expected_query = "SET name = 'Bar', login = 'bar'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_from_should_skip_pairs_with_an_empty_column() {
+    let query = sql::Update::new()
+      .set_from([("  ", "'Foo'"), (" name ", " 'Bar' ")])
+      .as_string();
+    let expected_query = "SET name = 'Bar'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_should_accumulate_the_assignments_of_the_same_column() {
+    let query = sql::Update::new()
+      .set("count = 1")
+      .set("name = 'Foo'")
+      .set("count = 5")
+      .as_string();
+    let expected_query = "SET count = 1, name = 'Foo', count = 5";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_increment_should_add_the_column_plus_a_literal() {
+    let query = sql::Update::new().increment("count", "1").as_string();
+    let expected_query = "SET count = count + 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_increment_should_add_the_column_plus_an_expression() {
+    let query = sql::Update::new().increment(" balance ", " $1 * rate ").as_string();
+    let expected_query = "SET balance = balance + $1 * rate";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_decrement_should_add_the_column_minus_the_value() {
+    let query = sql::Update::new()
+      .decrement("stock", "1")
+      .decrement("reserved", "(select count(*) from orders)")
+      .as_string();
+    let expected_query = "SET stock = stock - 1, reserved = reserved - (select count(*) from orders)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_increment_should_accumulate_values_with_the_set_method() {
+    let query = sql::Update::new()
+      .set("name = 'Foo'")
+      .increment("count", "1")
+      .as_string();
+    let expected_query = "SET name = 'Foo', count = count + 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_should_override_a_previous_increment_of_the_same_column() {
+    let query = sql::Update::new()
+      .increment("count", "1")
+      .decrement("stock", "1")
+      .set("count = 5")
+      .as_string();
+    let expected_query = "SET count = 5, stock = stock - 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_increment_should_override_the_assignment_of_the_same_column() {
+    let query = sql::Update::new()
+      .set("count = 5")
+      .increment("count", "1")
+      .increment("count", "2")
+      .as_string();
+    let expected_query = "SET count = count + 2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_increment_should_not_accumulate_values_when_column_is_empty() {
+    let query = sql::Update::new().increment("  ", "1").decrement("", "1").as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_set_clause() {
     let query = sql::Update::new()
@@ -0,0 +1,147 @@
+#![allow(deprecated)]
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod select_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_union_all_should_add_the_union_all_clause() {
+    let select_users = sql::Select::new().select("login").from("users");
+    let select_addresses = sql::Select::new().select("login").from("addresses");
+    let query = select_users.union_all(select_addresses).as_string();
+    let expected_query = "(SELECT login FROM users) UNION ALL (SELECT login FROM addresses)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_all_should_accumulate_values_on_consecutive_calls() {
+    let select_users = sql::Select::new().select("login").from("users");
+    let select_addresses = sql::Select::new().select("login").from("addresses");
+    let select_orders = sql::Select::new().select("login").from("orders");
+    let query = select_users
+      .union_all(select_addresses)
+      .union_all(select_orders)
+      .as_string();
+    let expected_query = "\
+      (SELECT login FROM users) \
+      UNION ALL \
+      (SELECT login FROM addresses) \
+      UNION ALL \
+      (SELECT login FROM orders)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_union_all_should_be_after_union_clause() {
+    let select_addresses = sql::Select::new().select("login").from("addresses");
+    let select_orders = sql::Select::new().select("login").from("orders");
+    let query = sql::Select::new()
+      .select("login")
+      .union_all(select_orders)
+      .union(select_addresses)
+      .as_string();
+    let expected_query = "\
+      ((SELECT login) \
+      UNION \
+      (SELECT login FROM addresses)) \
+      UNION ALL \
+      (SELECT login FROM orders)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_recursive_should_combine_the_base_and_step_selects_using_union_all() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new()
+      .select("n + 1")
+      .from("counter")
+      .where_clause("n < 10");
+    let query = sql::Select::recursive(base, step).as_string();
+    let expected_query = "SELECT 1 as n UNION ALL SELECT n + 1 FROM counter WHERE n < 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_recursive_should_be_used_as_a_cte_body() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new()
+      .select("n + 1")
+      .from("counter")
+      .where_clause("n < 10");
+    let query = sql::Select::new()
+      .with("counter", sql::Select::recursive(base, step))
+      .select("n")
+      .from("counter")
+      .as_string();
+    let expected_query = "\
+      WITH counter AS (\
+        SELECT 1 as n UNION ALL SELECT n + 1 FROM counter WHERE n < 10\
+      ) \
+      SELECT n \
+      FROM counter\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_union_all_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::UnionAll, "select name from orders")
+      .union_all(sql::Select::new().select("name"))
+      .as_string();
+    let expected_query = "(select name from orders) UNION ALL (SELECT name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_union_all_clause() {
+    let query = sql::Select::new()
+      .select("name")
+      .union_all(sql::Select::new().select("name"))
+      .raw_after(sql::SelectClause::UnionAll, "/* the name */")
+      .as_string();
+    let expected_query = "(SELECT name) UNION ALL (SELECT name) /* the name */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod select_command_sqlite {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_recursive_should_render_the_terms_without_parentheses_as_required_by_sqlite() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new()
+      .select("n + 1")
+      .from("counter")
+      .where_clause("n < 10");
+    let query = sql::Select::new()
+      .with_recursive("counter", sql::Select::recursive(base, step))
+      .select("n")
+      .from("counter")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE counter AS (\
+        SELECT 1 as n \
+        UNION ALL \
+        SELECT n + 1 FROM counter WHERE n < 10\
+      ) \
+      SELECT n \
+      FROM counter\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
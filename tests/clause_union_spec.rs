@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod select_command {
   use pretty_assertions::assert_eq;
@@ -76,4 +78,95 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_union_as_should_rename_the_output_columns_of_the_first_select() {
+    let left = sql::Select::new().select("id, customer_login").from("orders");
+    let right = sql::Select::new().select("id, seller_login").from("products");
+    let query = left.union_as(right, &["pk", "login"]).as_string();
+    let expected_query = "\
+      SELECT id AS pk, customer_login AS login FROM orders \
+      UNION \
+      SELECT id, seller_login FROM products\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_as_should_keep_the_column_name_when_the_alias_is_empty() {
+    let left = sql::Select::new().select("id").select("customer_login").from("orders");
+    let right = sql::Select::new().select("id, seller_login").from("products");
+    let query = left.union_as(right, &["  ", "  login  "]).as_string();
+    let expected_query = "\
+      SELECT id, customer_login AS login FROM orders \
+      UNION \
+      SELECT id, seller_login FROM products\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_as_should_replace_the_previous_alias_of_the_column() {
+    let left = sql::Select::new()
+      .select_as("customer_login", "customer")
+      .select("coalesce(nickname, name) AS name")
+      .from("orders");
+    let right = sql::Select::new().select("seller_login, name").from("products");
+    let query = left.union_as(right, &["login", "display_name"]).as_string();
+    let expected_query = "\
+      SELECT customer_login AS login, coalesce(nickname, name) AS display_name FROM orders \
+      UNION \
+      SELECT seller_login, name FROM products\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_as_should_apply_the_order_by_and_limit_to_the_compound() {
+    let left = sql::Select::new().select("customer_login").from("orders");
+    let right = sql::Select::new().select("seller_login").from("products");
+    let query = left
+      .union_as(right, &["login"])
+      .order_by("login")
+      .limit("10")
+      .as_string();
+    let expected_query = "\
+      SELECT customer_login AS login FROM orders \
+      UNION \
+      SELECT seller_login FROM products \
+      ORDER BY login \
+      LIMIT 10\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_as_should_render_the_alias_with_the_keyword_case_of_the_formatter() {
+    let left = sql::Select::new().select("customer_login").from("orders");
+    let right = sql::Select::new().select("seller_login").from("products");
+    let query = left
+      .union_as(right, &["login"])
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "\
+      select customer_login as login from orders \
+      union \
+      select seller_login from products\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_union_as_should_be_the_same_as_a_compound_union_when_aliases_are_empty() {
+    let left = sql::Select::new().select("customer_login").from("orders");
+    let right = sql::Select::new().select("seller_login").from("products");
+    let query = left.union_as(right, &[]).as_string();
+    let expected_query = "SELECT customer_login FROM orders UNION SELECT seller_login FROM products";
+
+    assert_eq!(query, expected_query);
+  }
 }
@@ -47,4 +47,70 @@ mod update_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_add_the_only_keyword_after_the_update_keyword() {
+    let query = sql::Update::new()
+      .update("cities")
+      .only()
+      .set("population = population + 1")
+      .as_string();
+    let expected_query = "UPDATE ONLY cities SET population = population + 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_not_be_enabled_by_default() {
+    let query = sql::Update::new().update("cities").set("population = 0").as_string();
+    let expected_query = "UPDATE cities SET population = 0";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_not_duplicate_the_keyword_on_consecutive_calls() {
+    let query = sql::Update::new().only().update("cities").only().as_string();
+    let expected_query = "UPDATE ONLY cities";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_not_render_the_keyword_without_the_update_clause() {
+    let query = sql::Update::new().only().set("population = 0").as_string();
+    let expected_query = "SET population = 0";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_be_removed_when_the_update_clause_is_cleared() {
+    let query = sql::Update::new()
+      .update("cities")
+      .only()
+      .clear(sql::UpdateClause::Update)
+      .update("capitals")
+      .as_string();
+    let expected_query = "UPDATE capitals";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_only_should_render_the_keyword_case_of_the_formatter() {
+    let query = sql::Update::new()
+      .update("cities")
+      .only()
+      .as_string_with(&sql::fmt::one_line().with_keyword_case(sql::fmt::KeywordCase::Lower));
+    let expected_query = "update only cities";
+
+    assert_eq!(query, expected_query);
+  }
 }
@@ -0,0 +1,119 @@
+#[cfg(feature = "postgresql")]
+mod delete_command {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_add_the_using_clause() {
+    let query = sql::Delete::new().using("users").as_string();
+    let expected_query = "USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Delete::new().using("users").using("addresses").as_string();
+    let expected_query = "USING users, addresses";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Delete::new().using("").using("users").using("").as_string();
+    let expected_query = "USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_trim_space_of_the_argument() {
+    let query = sql::Delete::new().using("  users  ").as_string();
+    let expected_query = "USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Delete::new().using("users").using("users").as_string();
+    let expected_query = "USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_using_should_be_after_delete_from_clause() {
+    let query = sql::Delete::new().using("users").delete_from("orders").as_string();
+    let expected_query = "DELETE FROM orders USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_where_should_be_after_the_joins_of_the_using_clause() {
+    let query = sql::Delete::new()
+      .where_clause("users.active = false")
+      .inner_join("orders ON orders.owner_id = users.id")
+      .using("users")
+      .delete_from("products")
+      .as_string();
+    let expected_query = "\
+      DELETE FROM products \
+      USING users \
+      INNER JOIN orders ON orders.owner_id = users.id \
+      WHERE users.active = false\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_using_clause() {
+    let query = sql::Delete::new()
+      .raw_before(sql::DeleteClause::Using, "delete from orders")
+      .using("users")
+      .as_string();
+    let expected_query = "delete from orders USING users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_using_clause() {
+    let query = sql::Delete::new()
+      .using("users")
+      .raw_after(sql::DeleteClause::Using, "where users.active = false")
+      .as_string();
+    let expected_query = "USING users where users.active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_using_should_be_rendered_between_the_with_and_returning_clauses() {
+    let query = sql::Delete::new()
+      .returning("orders.id")
+      .where_clause("orders.owner_id = inactive.id")
+      .using("inactive")
+      .delete_from("orders")
+      .with(
+        "inactive",
+        sql::Select::new()
+          .select("id")
+          .from("users")
+          .where_clause("active = false"),
+      )
+      .as_string();
+    let expected_query = "\
+      WITH inactive AS (SELECT id FROM users WHERE active = false) \
+      DELETE FROM orders \
+      USING inactive \
+      WHERE orders.owner_id = inactive.id \
+      RETURNING orders.id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
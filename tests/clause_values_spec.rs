@@ -74,6 +74,281 @@ mod insert_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_values_from_should_produce_the_same_output_of_consecutive_values_calls() {
+    let query = sql::Insert::new()
+      .values_from(["('foo', 'Foo')", "('bar', 'Bar')"])
+      .as_string();
+    let expected_query = sql::Insert::new()
+      .values("('foo', 'Foo')")
+      .values("('bar', 'Bar')")
+      .as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_from_should_accept_owned_strings() {
+    let logins = ["foo", "bar"];
+    let query = sql::Insert::new()
+      .values_from(logins.iter().map(|login| format!("('{login}')")))
+      .as_string();
+    let expected_query = "VALUES ('foo'), ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_from_should_skip_empty_rows() {
+    let query = sql::Insert::new()
+      .values_from(["", "('foo')", "   ", "('bar')"])
+      .as_string();
+    let expected_query = "VALUES ('foo'), ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_from_should_not_accumulate_rows_with_the_same_content() {
+    let query = sql::Insert::new()
+      .values("('foo')")
+      .values_from(["  ('foo')  ", "('bar')", "('bar')"])
+      .as_string();
+    let expected_query = "VALUES ('foo'), ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_tuple_should_wrap_the_cells_in_parentheses() {
+    let query = sql::Insert::new().values_tuple("'foo', 'Foo'").as_string();
+    let expected_query = "VALUES ('foo', 'Foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_tuple_should_accumulate_rows_with_the_values_method() {
+    let query = sql::Insert::new()
+      .values("('foo', 'Foo')")
+      .values_tuple("  'bar', 'Bar'  ")
+      .values_tuple("'foo', 'Foo'")
+      .as_string();
+    let expected_query = "VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_tuple_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::Insert::new().values_tuple("  ").values_tuple("'bar'").as_string();
+    let expected_query = "VALUES ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  struct User {
+    login: &'static str,
+    name: &'static str,
+  }
+
+  impl sql::IntoRow for User {
+    fn into_row(&self) -> String {
+      if self.login.is_empty() {
+        return "".to_string();
+      }
+      format!("('{}', '{}')", self.login, self.name)
+    }
+  }
+
+  #[test]
+  fn method_values_iter_should_add_a_row_for_each_item() {
+    let users = vec![
+      User {
+        login: "foo",
+        name: "Foo",
+      },
+      User {
+        login: "bar",
+        name: "Bar",
+      },
+    ];
+    let query = sql::Insert::new().values_iter(users).as_string();
+    let expected_query = "VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_iter_should_accept_references_of_the_items() {
+    let users = vec![User {
+      login: "foo",
+      name: "Foo",
+    }];
+    let query = sql::Insert::new()
+      .values("('baz', 'Baz')")
+      .values_iter(&users)
+      .values_iter(users.iter())
+      .as_string();
+    let expected_query = "VALUES ('baz', 'Baz'), ('foo', 'Foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_iter_should_skip_the_empty_rows() {
+    let users = [
+      User {
+        login: "",
+        name: "Nobody",
+      },
+      User {
+        login: "foo",
+        name: "Foo",
+      },
+    ];
+    let query = sql::Insert::new().values_iter(&users).as_string();
+    let expected_query = "VALUES ('foo', 'Foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_with_defaults_should_render_the_none_values_as_default() {
+    let query = sql::Insert::new()
+      .values_with_defaults(&[None, Some("'foo'"), None, Some(" true ")])
+      .as_string();
+    let expected_query = "VALUES (DEFAULT, 'foo', DEFAULT, true)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_with_defaults_should_accumulate_rows_with_the_values_method() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo')")
+      .values_with_defaults(&[Some("'bar'"), None])
+      .values_with_defaults(&[None, None])
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      VALUES ('foo', 'Foo'), ('bar', DEFAULT), (DEFAULT, DEFAULT)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_with_defaults_should_not_accumulate_values_when_the_row_is_empty() {
+    let query = sql::Insert::new()
+      .values_with_defaults(&[])
+      .values_with_defaults(&[Some("'bar'")])
+      .as_string();
+    let expected_query = "VALUES ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_values_should_remove_all_rows_of_the_values_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .clear_values()
+      .as_string();
+    let expected_query = "INSERT INTO users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_values_should_remove_the_raw_sql_bound_to_the_values_clause() {
+    let query = sql::Insert::new()
+      .raw_before(sql::InsertClause::Values, "/* before values */")
+      .values("('foo')")
+      .raw_after(sql::InsertClause::Values, ", ('bar')")
+      .raw_after(sql::InsertClause::InsertInto, "/* after insert into */")
+      .insert_into("users (login)")
+      .clear_values()
+      .as_string();
+    let expected_query = "INSERT INTO users (login) /* after insert into */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_values_should_allow_new_rows_to_be_added() {
+    let query = sql::Insert::new()
+      .values("('foo')")
+      .clear_values()
+      .values("('foo')")
+      .as_string();
+    let expected_query = "VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_split_by_bytes_should_keep_each_statement_under_the_budget() {
+    let queries = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .values("('baz')")
+      .split_by_bytes(50)
+      .iter()
+      .map(|insert| insert.as_string())
+      .collect::<Vec<_>>();
+    let expected_queries = vec![
+      "INSERT INTO users (login) VALUES ('foo'), ('bar')",
+      "INSERT INTO users (login) VALUES ('baz')",
+    ];
+
+    assert_eq!(queries, expected_queries);
+    assert!(queries.iter().all(|query| query.len() <= 50));
+  }
+
+  #[test]
+  fn method_split_by_bytes_should_copy_the_other_clauses_to_each_statement() {
+    let queries = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .raw_after(sql::InsertClause::Values, "/* batch */")
+      .split_by_bytes(1)
+      .iter()
+      .map(|insert| insert.as_string())
+      .collect::<Vec<_>>();
+    let expected_queries = vec![
+      "INSERT INTO users (login) VALUES ('foo') /* batch */",
+      "INSERT INTO users (login) VALUES ('bar') /* batch */",
+    ];
+
+    assert_eq!(queries, expected_queries);
+  }
+
+  #[test]
+  fn method_split_by_bytes_should_place_a_row_greater_than_the_budget_in_its_own_statement() {
+    let inserts = sql::Insert::new()
+      .values("('foo', 'Foo')")
+      .values("('bar', 'Bar')")
+      .split_by_bytes(5);
+
+    assert_eq!(inserts.len(), 2);
+    assert_eq!(inserts[0].as_string(), "VALUES ('foo', 'Foo')");
+    assert_eq!(inserts[1].as_string(), "VALUES ('bar', 'Bar')");
+  }
+
+  #[test]
+  fn method_split_by_bytes_should_return_a_copy_of_the_builder_when_there_are_no_values() {
+    let inserts = sql::Insert::new().insert_into("users (login)").split_by_bytes(10);
+
+    assert_eq!(inserts.len(), 1);
+    assert_eq!(inserts[0].as_string(), "INSERT INTO users (login)");
+  }
+
   #[test]
   fn clause_values_should_be_after_insert_into_clause() {
     let query = sql::Insert::new()
@@ -140,6 +415,34 @@ mod values_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_row_should_add_a_row_to_the_values_clause() {
+    let query = sql::Values::new().row("(1, 'a')").as_string();
+    let expected_query = "VALUES (1, 'a')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_row_should_accumulate_rows_with_the_values_method() {
+    let query = sql::Values::new()
+      .row(" (1, 'a') ")
+      .values("(2, 'b'), (3, 'c')")
+      .row("(4, 'd')")
+      .as_string();
+    let expected_query = "VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_row_should_not_accumulate_values_when_tuple_is_empty() {
+    let query = sql::Values::new().row("").row("(1, 'a')").row("  ").as_string();
+    let expected_query = "VALUES (1, 'a')";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_before_should_add_raw_sql_before_values_clause() {
     let query = sql::Values::new()
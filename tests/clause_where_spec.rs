@@ -445,6 +445,40 @@ mod where_and {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn methods_where_and_and_where_or_should_render_the_operators_in_call_order() {
+      let query = sql::Select::new()
+        .where_and("active = true")
+        .where_or("login = 'foo'")
+        .where_and("admin = true")
+        .as_string();
+      let expected_query = "WHERE active = true OR login = 'foo' AND admin = true";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_or_should_ignore_the_operator_of_the_first_condition() {
+      let query = sql::Select::new()
+        .where_or("login = 'foo'")
+        .where_and("active = true")
+        .as_string();
+      let expected_query = "WHERE login = 'foo' AND active = true";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_parenthesize_the_conditions_of_a_mixed_chain() {
+      let query = sql::Select::new()
+        .where_group(|c| c.and("active = true").or("login = 'foo'"))
+        .where_and("admin = true")
+        .as_string();
+      let expected_query = "WHERE (active = true OR login = 'foo') AND admin = true";
+
+      assert_eq!(query, expected_query);
+    }
   }
 
   mod update_command {
@@ -461,6 +495,116 @@ mod where_and {
   }
 }
 
+mod where_clause_opt {
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_clause_opt_should_add_the_condition_when_it_is_present() {
+      let query = sql::Delete::new().where_clause_opt(Some("login = $1")).as_string();
+      let expected_query = "WHERE login = $1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_keep_the_and_chain_when_the_condition_is_none() {
+      let query = sql::Delete::new()
+        .where_clause("login = $1")
+        .where_clause_opt(None)
+        .where_clause("status = 'active'")
+        .as_string();
+      let expected_query = "WHERE login = $1 AND status = 'active'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_not_add_a_where_clause_when_the_condition_is_empty() {
+      let query = sql::Delete::new()
+        .where_clause_opt(Some("   "))
+        .where_clause_opt(None)
+        .as_string();
+      let expected_query = "";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_clause_opt_should_add_the_condition_when_it_is_present() {
+      let query = sql::Select::new().where_clause_opt(Some("login = $1")).as_string();
+      let expected_query = "WHERE login = $1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_keep_the_and_chain_when_the_condition_is_none() {
+      let query = sql::Select::new()
+        .where_clause("login = $1")
+        .where_clause_opt(None)
+        .where_clause("status = 'active'")
+        .as_string();
+      let expected_query = "WHERE login = $1 AND status = 'active'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_not_add_a_where_clause_when_the_condition_is_empty() {
+      let query = sql::Select::new()
+        .where_clause_opt(Some("   "))
+        .where_clause_opt(None)
+        .as_string();
+      let expected_query = "";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_clause_opt_should_add_the_condition_when_it_is_present() {
+      let query = sql::Update::new().where_clause_opt(Some("login = $1")).as_string();
+      let expected_query = "WHERE login = $1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_keep_the_and_chain_when_the_condition_is_none() {
+      let query = sql::Update::new()
+        .where_clause("login = $1")
+        .where_clause_opt(None)
+        .where_clause("status = 'active'")
+        .as_string();
+      let expected_query = "WHERE login = $1 AND status = 'active'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_clause_opt_should_not_add_a_where_clause_when_the_condition_is_empty() {
+      let query = sql::Update::new()
+        .where_clause_opt(Some("   "))
+        .where_clause_opt(None)
+        .as_string();
+      let expected_query = "";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
 mod where_or {
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   mod create_index_command {
@@ -875,3 +1019,676 @@ mod where_or {
     }
   }
 }
+
+mod where_group {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_group_should_add_a_parenthesized_group_of_conditions() {
+      let query = sql::Select::new()
+        .where_group(|c| c.or("login = $1").or("email = $1"))
+        .as_string();
+      let expected_query = "WHERE (login = $1 OR email = $1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_be_concatenated_using_the_and_operator() {
+      let query = sql::Select::new()
+        .where_clause("active = true")
+        .where_group(|c| c.and("login = $1").or("email = $1"))
+        .where_or("admin = true")
+        .as_string();
+      let expected_query = "WHERE active = true AND (login = $1 OR email = $1) OR admin = true";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_mix_the_and_and_or_operators_in_the_group() {
+      let query = sql::Select::new()
+        .where_group(|c| c.and("status = 'paid'").and("total > 0").or("refunded = true"))
+        .as_string();
+      let expected_query = "WHERE (status = 'paid' AND total > 0 OR refunded = true)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_trim_space_and_ignore_empty_conditions() {
+      let query = sql::Select::new()
+        .where_group(|c| c.or("").or("  login = $1  ").or(""))
+        .as_string();
+      let expected_query = "WHERE (login = $1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_not_add_the_where_clause_when_the_group_is_empty() {
+      let query = sql::Select::new().select("id").where_group(|c| c.and("")).as_string();
+      let expected_query = "SELECT id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_not_accumulate_groups_with_the_same_content() {
+      let query = sql::Select::new()
+        .where_group(|c| c.or("a = 1").or("b = 1"))
+        .where_group(|c| c.or("a = 1").or("b = 1"))
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR b = 1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_render_the_group_before_the_next_conditions() {
+      let query = sql::Select::new()
+        .where_group(|c| c.or("a = 1").or("b = 2"))
+        .where_clause("c = 3")
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_nest_the_parentheses_of_the_nested_groups() {
+      let query = sql::Select::new()
+        .where_group(|c| {
+          c.or("a = 1")
+            .or_group(|c| c.and("b = 2").and_group(|c| c.or("c = 3").or("d = 4")))
+        })
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR (b = 2 AND (c = 3 OR d = 4)))";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_be_concatenated_using_the_or_operator_after_the_where_join_or_method() {
+      let query = sql::Select::new()
+        .where_clause("admin = true")
+        .where_join_or()
+        .where_group(|c| c.and("active = true").and("login = $1"))
+        .as_string();
+      let expected_query = "WHERE admin = true OR (active = true AND login = $1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_render_the_group_with_the_formatter_of_the_query() {
+      let query = sql::Select::new()
+        .select("id")
+        .where_group(|c| c.or("a = 1").or_group(|c| c.and("b = 2").and("c = 3")))
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "select id where (a = 1 or (b = 2 and c = 3))";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod condition_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_and_group_should_add_a_parenthesized_group_using_the_and_operator() {
+      let condition = sql::Condition::new()
+        .and("active = true")
+        .and_group(|c| c.or("login = $1").or("email = $1"))
+        .as_string();
+      let expected_condition = "active = true AND (login = $1 OR email = $1)";
+
+      assert_eq!(condition, expected_condition);
+    }
+
+    #[test]
+    fn method_or_group_should_add_a_parenthesized_group_using_the_or_operator() {
+      let condition = sql::Condition::new()
+        .or("admin = true")
+        .or_group(|c| c.and("active = true").and("login = $1"))
+        .as_string();
+      let expected_condition = "admin = true OR (active = true AND login = $1)";
+
+      assert_eq!(condition, expected_condition);
+    }
+
+    #[test]
+    fn method_and_group_should_omit_the_operator_when_was_the_first_condition() {
+      let condition = sql::Condition::new()
+        .and_group(|c| c.or("a = 1").or("b = 2"))
+        .as_string();
+      let expected_condition = "(a = 1 OR b = 2)";
+
+      assert_eq!(condition, expected_condition);
+    }
+
+    #[test]
+    fn method_or_group_should_ignore_an_empty_group() {
+      let condition = sql::Condition::new()
+        .and("a = 1")
+        .or_group(|c| c.and("  "))
+        .and_group(|c| c)
+        .as_string();
+      let expected_condition = "a = 1";
+
+      assert_eq!(condition, expected_condition);
+    }
+  }
+}
+
+mod where_join_or {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_join_or_should_switch_the_operator_of_the_next_where_clause_calls() {
+      let query = sql::Select::new()
+        .where_clause("active = true")
+        .where_join_or()
+        .where_clause("login = 'foo'")
+        .where_clause("login = 'bar'")
+        .as_string();
+      let expected_query = "WHERE active = true OR login = 'foo' OR login = 'bar'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_join_and_should_restore_the_and_operator() {
+      let query = sql::Select::new()
+        .where_join_or()
+        .where_clause("login = 'foo'")
+        .where_clause("login = 'bar'")
+        .where_join_and()
+        .where_clause("active = true")
+        .as_string();
+      let expected_query = "WHERE login = 'foo' OR login = 'bar' AND active = true";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_join_or_should_not_affect_the_explicit_operator_methods() {
+      let query = sql::Select::new()
+        .where_join_or()
+        .where_clause("login = 'foo'")
+        .where_and("active = true")
+        .where_join_and()
+        .where_or("admin = true")
+        .as_string();
+      let expected_query = "WHERE login = 'foo' AND active = true OR admin = true";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_join_or_should_omit_the_operator_of_the_first_condition() {
+      let query = sql::Select::new()
+        .where_join_or()
+        .where_clause("login = 'foo'")
+        .as_string();
+      let expected_query = "WHERE login = 'foo'";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+mod where_quantified_comparison {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_any_should_add_the_any_comparison_with_the_subquery_inline() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new().where_any("price", "=", paid_orders).as_string();
+      let expected_query = "WHERE price = ANY (SELECT total FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_all_should_add_the_all_comparison_with_the_subquery_inline() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new().where_all("price", ">", paid_orders).as_string();
+      let expected_query = "WHERE price > ALL (SELECT total FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_any_and_where_all_should_trim_space_of_the_arguments() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new()
+        .where_any("  price  ", "  <>  ", paid_orders)
+        .as_string();
+      let expected_query = "WHERE price <> ANY (SELECT total FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_any_and_where_all_should_accumulate_using_the_and_operator() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let refunds = sql::Select::new().select("amount").from("refunds");
+      let query = sql::Select::new()
+        .where_clause("active = true")
+        .where_any("price", "=", paid_orders)
+        .where_all("price", ">=", refunds)
+        .as_string();
+      let expected_query = "\
+        WHERE active = true \
+        AND price = ANY (SELECT total FROM orders) \
+        AND price >= ALL (SELECT amount FROM refunds)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_any_and_where_all_should_use_the_or_operator_after_the_where_join_or_method() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let refunds = sql::Select::new().select("amount").from("refunds");
+      let query = sql::Select::new()
+        .where_join_or()
+        .where_clause("active = true")
+        .where_any("price", "=", paid_orders)
+        .where_all("price", ">=", refunds)
+        .as_string();
+      let expected_query = "\
+        WHERE active = true \
+        OR price = ANY (SELECT total FROM orders) \
+        OR price >= ALL (SELECT amount FROM refunds)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_any_should_keep_the_position_among_the_where_conditions() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new()
+        .where_clause("active = true")
+        .where_any("price", "=", paid_orders)
+        .where_clause("stock > 0")
+        .as_string();
+      let expected_query = "WHERE active = true AND price = ANY (SELECT total FROM orders) AND stock > 0";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_all_should_render_the_subquery_with_the_formatter_of_the_query() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new()
+        .from("products")
+        .where_all("price", ">", paid_orders)
+        .as_string_with(&sql::fmt::multiline().with_keyword_case(sql::fmt::KeywordCase::Lower));
+      let expected_query = "\
+        from products \n\
+        where\n  \
+          price > all (\n    \
+            select total \n    \
+            from orders\n  \
+          )\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[cfg(feature = "postgresql")]
+    #[test]
+    fn method_where_any_should_keep_the_params_of_the_subquery() {
+      let paid_orders = sql::Select::new()
+        .select("total")
+        .from("orders")
+        .where_clause("status = ?")
+        .bind("paid");
+      let (query, params) = sql::Select::new()
+        .from("products")
+        .where_any("price", "=", paid_orders)
+        .into_parts();
+      let expected_query = "FROM products WHERE price = ANY (SELECT total FROM orders WHERE status = $1)";
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, vec![sql::ParamValue::Text("paid".to_string())]);
+    }
+
+    #[test]
+    fn method_where_any_should_not_accumulate_arguments_with_the_same_content() {
+      let paid_orders = sql::Select::new().select("total").from("orders");
+      let query = sql::Select::new()
+        .where_any("price", "=", paid_orders.clone())
+        .where_any("price", "=", paid_orders)
+        .as_string();
+      let expected_query = "WHERE price = ANY (SELECT total FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+mod where_in {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_in_should_add_the_in_condition_with_the_values() {
+      let query = sql::Select::new().where_in("id", ["1", "2", "3"]).as_string();
+      let expected_query = "WHERE id IN (1, 2, 3)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_accept_owned_strings() {
+      let ids = (1..=3).map(|id| id.to_string()).collect::<Vec<_>>();
+      let query = sql::Select::new().where_in("id", ids).as_string();
+      let expected_query = "WHERE id IN (1, 2, 3)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_render_an_always_false_condition_when_the_values_are_empty() {
+      let query = sql::Select::new()
+        .where_in("id", Vec::<&str>::new())
+        .where_in("login", ["  ", ""])
+        .as_string();
+      let expected_query = "WHERE 1 = 0";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_trim_space_of_the_arguments() {
+      let query = sql::Select::new().where_in("  id  ", [" 1 ", "", " 2"]).as_string();
+      let expected_query = "WHERE id IN (1, 2)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_not_add_the_condition_when_the_column_is_empty() {
+      let query = sql::Select::new().where_in("  ", ["1"]).as_string();
+      let expected_query = "";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_accumulate_with_the_where_clause_method() {
+      let query = sql::Select::new()
+        .where_clause("active = true")
+        .where_in("id", ["1", "2"])
+        .where_not_in("status", ["'banned'"])
+        .as_string();
+      let expected_query = "WHERE active = true AND id IN (1, 2) AND status NOT IN ('banned')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_not_in_should_add_the_not_in_condition_with_the_values() {
+      let query = sql::Select::new()
+        .where_not_in("status", ["'banned'", "'deleted'"])
+        .as_string();
+      let expected_query = "WHERE status NOT IN ('banned', 'deleted')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_not_in_should_render_an_always_true_condition_when_the_values_are_empty() {
+      let query = sql::Select::new()
+        .where_not_in("status", Vec::<String>::new())
+        .as_string();
+      let expected_query = "WHERE 1 = 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_render_the_operator_with_the_keyword_case_of_the_formatter() {
+      let query = sql::Select::new()
+        .where_in("id", ["1", "2"])
+        .where_not_in("status", ["'banned'"])
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "where id in (1, 2) and status not in ('banned')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_in_should_add_the_in_condition_with_the_values() {
+      let query = sql::Delete::new()
+        .delete_from("sessions")
+        .where_in("user_id", ["$1", "$2"])
+        .as_string();
+      let expected_query = "DELETE FROM sessions WHERE user_id IN ($1, $2)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_render_an_always_false_condition_when_the_values_are_empty() {
+      let query = sql::Delete::new()
+        .delete_from("sessions")
+        .where_in("user_id", Vec::<&str>::new())
+        .as_string();
+      let expected_query = "DELETE FROM sessions WHERE 1 = 0";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_not_in_should_add_the_not_in_condition_with_the_values() {
+      let query = sql::Delete::new()
+        .delete_from("sessions")
+        .where_not_in("user_id", ["1"])
+        .where_not_in("device", Vec::<&str>::new())
+        .as_string();
+      let expected_query = "DELETE FROM sessions WHERE user_id NOT IN (1) AND 1 = 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_render_the_operator_with_the_keyword_case_of_the_formatter() {
+      let query = sql::Delete::new()
+        .delete_from("sessions")
+        .where_in("user_id", ["$1"])
+        .where_not_in("device", ["'web'"])
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "delete from sessions where user_id in ($1) and device not in ('web')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_in_should_add_the_in_condition_with_the_values() {
+      let query = sql::Update::new()
+        .set("active = false")
+        .where_in("id", ["1", "2"])
+        .as_string();
+      let expected_query = "SET active = false WHERE id IN (1, 2)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_not_in_should_render_an_always_true_condition_when_the_values_are_empty() {
+      let query = sql::Update::new()
+        .set("active = false")
+        .where_not_in("id", Vec::<&str>::new())
+        .as_string();
+      let expected_query = "SET active = false WHERE 1 = 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_in_should_render_the_operator_with_the_keyword_case_of_the_formatter() {
+      let query = sql::Update::new()
+        .set("active = false")
+        .where_in("id", ["1", "2"])
+        .where_not_in("role", ["'admin'"])
+        .as_string_with(&sql::fmt::one_line_lowercase());
+      let expected_query = "set active = false where id in (1, 2) and role not in ('admin')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+mod where_exists {
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    fn user_orders() -> sql::Select {
+      sql::Select::new()
+        .select("1")
+        .from("orders o")
+        .where_clause("o.user_id = u.id")
+    }
+
+    #[test]
+    fn method_where_exists_should_add_the_correlated_subquery_referencing_the_outer_alias() {
+      let query = sql::Select::new()
+        .select("u.login")
+        .from("users u")
+        .where_exists(user_orders())
+        .as_string();
+      let expected_query = "\
+        SELECT u.login \
+        FROM users u \
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_not_exists_should_add_the_negated_subquery() {
+      let query = sql::Select::new()
+        .from("users u")
+        .where_not_exists(user_orders())
+        .as_string();
+      let expected_query = "FROM users u WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_exists_should_keep_the_insertion_order_with_the_other_conditions() {
+      let query = sql::Select::new()
+        .where_clause("u.active = true")
+        .where_exists(user_orders())
+        .where_or("u.admin = true")
+        .where_not_exists(sql::Select::new().select("1").from("bans b"))
+        .as_string();
+      let expected_query = "\
+        WHERE u.active = true \
+        AND EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id) \
+        OR u.admin = true \
+        AND NOT EXISTS (SELECT 1 FROM bans b)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_exists_should_be_the_first_condition_when_added_before_the_others() {
+      let query = sql::Select::new()
+        .where_exists(user_orders())
+        .where_clause("u.active = true")
+        .as_string();
+      let expected_query = "\
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id) \
+        AND u.active = true\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_exists_should_use_the_or_operator_after_the_where_join_or_method() {
+      let query = sql::Select::new()
+        .where_clause("u.admin = true")
+        .where_join_or()
+        .where_exists(user_orders())
+        .as_string();
+      let expected_query = "\
+        WHERE u.admin = true \
+        OR EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_exists_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new()
+        .where_exists(user_orders())
+        .where_exists(user_orders())
+        .as_string();
+      let expected_query = "WHERE EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_exists_should_indent_the_subquery_in_the_multiline_formatter() {
+      let query = sql::Select::new()
+        .select("u.login")
+        .from("users u")
+        .where_clause("u.active = true")
+        .where_exists(user_orders())
+        .as_string_with(&sql::fmt::multiline());
+      let expected_query = "\
+        SELECT u.login \n\
+        FROM users u \n\
+        WHERE\n  u.active = true \n  \
+        AND EXISTS (\n    \
+        SELECT 1 \n    \
+        FROM orders o \n    \
+        WHERE\n      o.user_id = u.id\n  \
+        )\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_clear_should_remove_the_exists_subqueries_of_the_where_clause() {
+      let select = sql::Select::new()
+        .where_exists(user_orders())
+        .clear(sql::SelectClause::Where);
+
+      assert_eq!(select.as_string(), "");
+      assert_eq!(select.has_clause(sql::SelectClause::Where), false);
+    }
+  }
+}
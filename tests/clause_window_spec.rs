@@ -79,4 +79,61 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_window_as_should_add_a_named_window() {
+    let query = sql::Select::new()
+      .window_as("w", "PARTITION BY x ORDER BY y")
+      .as_string();
+    let expected_query = "WINDOW w AS (PARTITION BY x ORDER BY y)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_as_should_accumulate_windows_with_different_names() {
+    let query = sql::Select::new()
+      .window_as("w1", "PARTITION BY x")
+      .window_as("w2", "ORDER BY y")
+      .window("w3 as (order by z)")
+      .as_string();
+    let expected_query = "WINDOW w1 AS (PARTITION BY x), w2 AS (ORDER BY y), w3 as (order by z)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_as_should_replace_the_definition_of_a_window_with_the_same_name() {
+    let query = sql::Select::new()
+      .window_as("w", "PARTITION BY x")
+      .window_as("other", "ORDER BY z")
+      .window_as("  w  ", "  ORDER BY y  ")
+      .as_string();
+    let expected_query = "WINDOW w AS (ORDER BY y), other AS (ORDER BY z)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_as_should_not_add_a_window_without_name() {
+    let query = sql::Select::new()
+      .window_as("", "PARTITION BY x")
+      .window_as("w", "ORDER BY y")
+      .as_string();
+    let expected_query = "WINDOW w AS (ORDER BY y)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_window_as_should_be_before_order_by_clause() {
+    let query = sql::Select::new()
+      .order_by("login")
+      .window_as("w", "PARTITION BY x")
+      .having("count(*) > 1")
+      .as_string();
+    let expected_query = "HAVING count(*) > 1 WINDOW w AS (PARTITION BY x) ORDER BY login";
+
+    assert_eq!(query, expected_query);
+  }
 }
@@ -29,6 +29,33 @@ mod delete_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_with_raw_should_add_the_with_clause() {
+    let query = sql::Delete::new()
+      .with_raw("user_ids", "select id from users")
+      .delete_from("orders")
+      .as_string();
+    let expected_query = "WITH user_ids AS (select id from users) DELETE FROM orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_should_add_the_with_recursive_clause() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+    let query = sql::Delete::new()
+      .with_recursive("t", sql::Select::recursive(base, step))
+      .delete_from("numbers")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE t AS (SELECT 1 as n UNION ALL SELECT n + 1 FROM t WHERE n < 10) \
+      DELETE FROM numbers\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_with_should_accept_inline_argument() {
     let query = sql::Delete::new()
@@ -138,6 +165,33 @@ mod delete_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn clause_delete_from_should_be_after_multiples_with_queries() {
+    let stale = sql::Select::new()
+      .select("id")
+      .from("sessions")
+      .where_clause("updated_at < now() - interval '1 day'");
+    let guests = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("guest = true");
+    let query = sql::Delete::new()
+      .with("stale", stale)
+      .with("guests", guests)
+      .delete_from("carts")
+      .where_clause("session_id in (select id from stale)")
+      .where_or("user_id in (select id from guests)")
+      .as_string();
+    let expected_query = "\
+      WITH stale AS (SELECT id FROM sessions WHERE updated_at < now() - interval '1 day'), \
+           guests AS (SELECT id FROM users WHERE guest = true) \
+      DELETE FROM carts \
+      WHERE session_id in (select id from stale) OR user_id in (select id from guests)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -169,6 +223,33 @@ mod insert_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_with_raw_should_add_the_with_clause() {
+    let query = sql::Insert::new()
+      .with_raw("user_ids", "select id from users")
+      .insert_into("orders")
+      .as_string();
+    let expected_query = "WITH user_ids AS (select id from users) INSERT INTO orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_should_add_the_with_recursive_clause() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+    let query = sql::Insert::new()
+      .with_recursive("t", sql::Select::recursive(base, step))
+      .insert_into("numbers")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE t AS (SELECT 1 as n UNION ALL SELECT n + 1 FROM t WHERE n < 10) \
+      INSERT INTO numbers\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_with_should_accept_inline_argument() {
     let query = sql::Insert::new()
@@ -403,6 +484,256 @@ mod select_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_with_raw_should_add_the_with_clause() {
+    let query = sql::Select::new()
+      .with_raw("user_list", "select login from users")
+      .as_string();
+    let expected_query = "WITH user_list AS (select login from users)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_raw_should_keep_the_order_with_the_queries_of_the_with_method() {
+    let query = sql::Select::new()
+      .with("user_list", sql::Select::new().select("id, login").from("users"))
+      .with_raw("user_ids", "select id from user_list")
+      .with("admin_ids", sql::Select::new().select("id").from("admins"))
+      .as_string();
+    let expected_query = "\
+      WITH user_list AS (SELECT id, login FROM users), \
+      user_ids AS (select id from user_list), \
+      admin_ids AS (SELECT id FROM admins)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_raw_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::Select::new()
+      .with_raw("user_list", "")
+      .with_raw("user_ids", "select id from users")
+      .with_raw("user_list2", "   ")
+      .as_string();
+    let expected_query = "WITH user_ids AS (select id from users)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_raw_should_trim_space_of_the_arguments() {
+    let query = sql::Select::new()
+      .with_raw("  date  ", "  select current_date  ")
+      .as_string();
+    let expected_query = "WITH date AS (select current_date)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_raw_should_indent_like_the_queries_of_the_with_method() {
+    let query = sql::Select::new()
+      .with_raw("user_list", "select login from users")
+      .select("login")
+      .from("user_list")
+      .as_string_with(&sql::fmt::multiline());
+    let expected_query = "WITH \nuser_list AS (\n  select login from users\n) \nSELECT login \nFROM user_list";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_should_indent_the_nested_queries_by_the_indent_of_the_formatter() {
+    let admins = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("admin = true");
+    let user_list = sql::Select::new()
+      .with("admins", admins)
+      .select("login")
+      .from("users")
+      .where_clause("id in (select id from admins)");
+    let query = sql::Select::new()
+      .with("user_list", user_list)
+      .select("login")
+      .from("user_list")
+      .as_string_with(&sql::fmt::multiline_with_indent("    "));
+    let expected_query = "\
+      WITH \n\
+      user_list AS (\n\
+      \x20   WITH \n\
+      \x20   admins AS (\n\
+      \x20       SELECT id \n\
+      \x20       FROM users \n\
+      \x20       WHERE\n\
+      \x20           admin = true\n\
+      \x20   ) \n\
+      \x20   SELECT login \n\
+      \x20   FROM users \n\
+      \x20   WHERE\n\
+      \x20       id in (select id from admins)\n\
+      ) \n\
+      SELECT login \n\
+      FROM user_list\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_should_add_the_with_recursive_clause() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+    let query = sql::Select::new()
+      .with_recursive("t", sql::Select::recursive(base, step))
+      .select("n")
+      .from("t")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE t AS (\
+        SELECT 1 as n \
+        UNION ALL \
+        SELECT n + 1 FROM t WHERE n < 10\
+      ) \
+      SELECT n \
+      FROM t\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_should_mark_the_whole_with_clause_as_recursive() {
+    let query = sql::Select::new()
+      .with("user_list", sql::Select::new().select("id").from("users"))
+      .with_recursive("t", sql::Select::new().select("1 as n"))
+      .with_raw("orders_list", "select * from orders")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE \
+      user_list AS (SELECT id FROM users), \
+      t AS (SELECT 1 as n), \
+      orders_list AS (select * from orders)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_should_not_mark_the_with_clause_as_recursive() {
+    let query = sql::Select::new()
+      .with("user_list", sql::Select::new().select("id").from("users"))
+      .as_string();
+    let expected_query = "WITH user_list AS (SELECT id FROM users)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod select_command_postgresql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn tree() -> sql::CompoundSelect {
+    let base = sql::Select::new().select("id").from("nodes").where_clause("id = 1");
+    let step = sql::Select::new().select("n.id").from("nodes n, tree t");
+    sql::Select::recursive(base, step)
+  }
+
+  #[test]
+  fn method_with_recursive_search_should_add_the_search_clause_after_the_query_body() {
+    let query = sql::Select::new()
+      .with("tree", tree())
+      .with_recursive_search("tree", "DEPTH FIRST BY id SET ordercol")
+      .select("*")
+      .from("tree")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE tree AS (\
+        SELECT id FROM nodes WHERE id = 1 \
+        UNION ALL \
+        SELECT n.id FROM nodes n, tree t\
+      ) SEARCH DEPTH FIRST BY id SET ordercol \
+      SELECT * \
+      FROM tree\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_cycle_should_add_the_cycle_clause_after_the_query_body() {
+    let query = sql::Select::new()
+      .with("tree", tree())
+      .with_recursive_cycle("tree", "id SET is_cycle USING path")
+      .select("*")
+      .from("tree")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE tree AS (\
+        SELECT id FROM nodes WHERE id = 1 \
+        UNION ALL \
+        SELECT n.id FROM nodes n, tree t\
+      ) CYCLE id SET is_cycle USING path \
+      SELECT * \
+      FROM tree\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_search_should_be_rendered_before_the_cycle_clause() {
+    let query = sql::Select::new()
+      .with_recursive_cycle("tree", "id SET is_cycle USING path")
+      .with_recursive_search("tree", "BREADTH FIRST BY id SET ordercol")
+      .with("tree", tree())
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE tree AS (\
+        SELECT id FROM nodes WHERE id = 1 \
+        UNION ALL \
+        SELECT n.id FROM nodes n, tree t\
+      ) SEARCH BREADTH FIRST BY id SET ordercol CYCLE id SET is_cycle USING path\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_search_should_only_affect_the_query_with_the_same_name() {
+    let query = sql::Select::new()
+      .with("active", sql::Select::new().select("id").from("users"))
+      .with("tree", tree())
+      .with_recursive_search("  tree  ", "  DEPTH FIRST BY id SET ordercol  ")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE active AS (SELECT id FROM users), \
+      tree AS (\
+        SELECT id FROM nodes WHERE id = 1 \
+        UNION ALL \
+        SELECT n.id FROM nodes n, tree t\
+      ) SEARCH DEPTH FIRST BY id SET ordercol\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_search_should_not_mark_the_with_clause_as_recursive_when_the_argument_is_empty() {
+    let query = sql::Select::new()
+      .with("active", sql::Select::new().select("id").from("users"))
+      .with_recursive_search("active", "")
+      .with_recursive_cycle("", "id SET is_cycle USING path")
+      .as_string();
+    let expected_query = "WITH active AS (SELECT id FROM users)";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -434,6 +765,33 @@ mod update_command {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_with_raw_should_add_the_with_clause() {
+    let query = sql::Update::new()
+      .with_raw("user_ids", "select id from users")
+      .update("orders")
+      .as_string();
+    let expected_query = "WITH user_ids AS (select id from users) UPDATE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_recursive_should_add_the_with_recursive_clause() {
+    let base = sql::Select::new().select("1 as n");
+    let step = sql::Select::new().select("n + 1").from("t").where_clause("n < 10");
+    let query = sql::Update::new()
+      .with_recursive("t", sql::Select::recursive(base, step))
+      .update("numbers")
+      .as_string();
+    let expected_query = "\
+      WITH RECURSIVE t AS (SELECT 1 as n UNION ALL SELECT n + 1 FROM t WHERE n < 10) \
+      UPDATE numbers\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_with_should_accept_inline_argument() {
     let query = sql::Update::new()
@@ -543,6 +901,29 @@ mod update_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn clause_update_should_be_after_multiples_with_queries() {
+    let paid = sql::Select::new().select("order_id").from("payments");
+    let shipped = sql::Select::new().select("order_id").from("shipments");
+    let query = sql::Update::new()
+      .with("paid", paid)
+      .with("shipped", shipped)
+      .update("orders")
+      .set("status = 'done'")
+      .where_clause("id in (select order_id from paid)")
+      .where_clause("id in (select order_id from shipped)")
+      .as_string();
+    let expected_query = "\
+      WITH paid AS (SELECT order_id FROM payments), \
+           shipped AS (SELECT order_id FROM shipments) \
+      UPDATE orders \
+      SET status = 'done' \
+      WHERE id in (select order_id from paid) AND id in (select order_id from shipped)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -561,4 +942,37 @@ mod values_command {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_row_should_render_a_values_relation_in_the_with_clause_of_the_select() {
+    let query = sql::Select::new()
+      .with("t (id, name)", sql::Values::new().row("(1, 'a')").row("(2, 'b')"))
+      .select("name")
+      .from("t")
+      .as_string();
+    let expected_query = "\
+      WITH t (id, name) AS (VALUES (1, 'a'), (2, 'b')) \
+      SELECT name \
+      FROM t\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_row_should_render_a_values_relation_in_the_with_clause_of_the_insert() {
+    let query = sql::Insert::new()
+      .with("new_users (login)", sql::Values::new().row("('foo')").row("('bar')"))
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("new_users"))
+      .as_string();
+    let expected_query = "\
+      WITH new_users (login) AS (VALUES ('foo'), ('bar')) \
+      INSERT INTO users (login) \
+      SELECT login \
+      FROM new_users\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
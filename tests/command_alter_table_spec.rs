@@ -246,6 +246,45 @@ mod builder_methods {
     assert_eq!(expected_query, query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::AlterTable::new()
+      .raw_append("/* end */")
+      .alter_table("users")
+      .drop("legacy_column")
+      .as_string();
+    let expected_query = "ALTER TABLE users DROP legacy_column /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .drop("legacy_column")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "ALTER TABLE users DROP legacy_column /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::AlterTable::new()
+      .raw("/* raw */")
+      .alter_table("users")
+      .drop("legacy_column")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ ALTER TABLE users DROP legacy_column";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::AlterTable::new()
@@ -346,6 +385,53 @@ mod method_add {
   }
 }
 
+mod method_add_column {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_column_should_define_a_add_column_action() {
+    let query = sql::AlterTable::new()
+      .add_column("login varchar(40) not null")
+      .as_string();
+    let expected_query = "ADD COLUMN login varchar(40) not null";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_column_should_preserve_the_order_with_the_add_and_drop_actions() {
+    let query = sql::AlterTable::new()
+      .add_column("login varchar(40) not null")
+      .drop_column("name")
+      .add("CONSTRAINT login_key UNIQUE (login)")
+      .as_string();
+    let expected_query = "\
+      ADD COLUMN login varchar(40) not null, \
+      DROP COLUMN name, \
+      ADD CONSTRAINT login_key UNIQUE (login)\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_column_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::AlterTable::new().add_column("").add_column("  ").as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_column_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new().add_column("  age int  ").as_string();
+    let expected_query = "ADD COLUMN age int";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 #[cfg(any(feature = "postgresql"))]
 mod method_alter {
   use pretty_assertions::assert_eq;
@@ -426,6 +512,31 @@ mod method_alter {
   }
 }
 
+#[cfg(any(feature = "postgresql"))]
+mod method_alter_column {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_alter_column_should_define_a_alter_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_column("login TYPE varchar(100)")
+      .alter_column("created_at SET DEFAULT now()")
+      .as_string();
+    let expected_query = "ALTER COLUMN login TYPE varchar(100), ALTER COLUMN created_at SET DEFAULT now()";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_alter_column_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::AlterTable::new().alter_column("").as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 mod method_alter_table {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
@@ -534,6 +645,31 @@ mod method_drop {
   }
 }
 
+mod method_drop_column {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_column_should_define_a_drop_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .drop_column("login")
+      .drop_column("name")
+      .as_string();
+    let expected_query = "ALTER TABLE users DROP COLUMN login, DROP COLUMN name";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_column_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::AlterTable::new().drop_column("").as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod method_rename {
   use pretty_assertions::assert_eq;
@@ -612,6 +748,127 @@ mod method_rename {
   }
 }
 
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod method_rename_column {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_rename_column_should_define_a_rename_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("  address TO city  ")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME COLUMN address TO city";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_override_the_current_value() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address TO city")
+      .rename_column("login TO user_login")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME COLUMN login TO user_login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_not_empty_the_current_value_when_expression_is_empty() {
+    let query = sql::AlterTable::new()
+      .rename_column("address TO city")
+      .rename_column("  ")
+      .as_string();
+    let expected_query = "RENAME COLUMN address TO city";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::AlterTable::new().rename_column("").as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_replace_the_previous_rename_actions() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename("COLUMN address TO city")
+      .rename("TO accounts")
+      .rename_column("login TO user_login")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME COLUMN login TO user_login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_replace_the_previous_rename_to_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_to("accounts")
+      .rename_column("login TO user_login")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME COLUMN login TO user_login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_should_replace_the_previous_rename_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("login TO user_login")
+      .rename("TO accounts")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME TO accounts";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_to_should_replace_the_previous_rename_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("login TO user_login")
+      .rename_to("accounts")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME TO accounts";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_keep_the_other_actions() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add("COLUMN age int")
+      .rename_column("login TO user_login")
+      .drop("COLUMN address")
+      .as_string();
+    let expected_query = "ALTER TABLE users ADD COLUMN age int, RENAME COLUMN login TO user_login, DROP COLUMN address";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_rename_column_should_render_the_keywords_with_the_keyword_case_of_the_formatter() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("login to user_login")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "alter table users rename column login to user_login";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod method_rename_to {
   use pretty_assertions::assert_eq;
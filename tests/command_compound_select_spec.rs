@@ -0,0 +1,361 @@
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn compound_select_builder_should_be_displayable() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let compound = sql::CompoundSelect::new(users).union(admins);
+
+    println!("{}", compound);
+
+    let query = compound.as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn compound_select_builder_should_be_debuggable() {
+    let users = sql::Select::new().select("login").from("users");
+    let compound = sql::CompoundSelect::new(users).order_by("login");
+
+    println!("{:?}", compound);
+
+    let query = compound.as_string();
+    let expected_query = "SELECT login FROM users ORDER BY login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn compound_select_builder_should_be_created_from_a_select() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::from(users).as_string();
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn compound_select_builder_should_be_used_as_a_with_query() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let logins = sql::CompoundSelect::new(users).union(admins);
+    let query = sql::Select::new()
+      .with("logins", logins)
+      .select("count(*)")
+      .from("logins")
+      .as_string();
+    let expected_query = "\
+      WITH logins AS (SELECT login FROM users UNION SELECT login FROM admins) \
+      SELECT count(*) \
+      FROM logins\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn all_clauses_concatenated_in_order() {
+    let active_users = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("active = true");
+    let recent = sql::Select::new()
+      .select("login")
+      .from("active_users")
+      .where_clause("created_at > $1");
+    let admins = sql::Select::new()
+      .select("login")
+      .from("active_users")
+      .where_clause("admin = true");
+    let query = sql::CompoundSelect::new(recent)
+      .offset("20")
+      .limit("10")
+      .order_by("login")
+      .union(admins)
+      .with("active_users", active_users)
+      .as_string();
+    let expected_query = "\
+      WITH active_users AS (SELECT login FROM users WHERE active = true) \
+      SELECT login FROM active_users WHERE created_at > $1 \
+      UNION \
+      SELECT login FROM active_users WHERE admin = true \
+      ORDER BY login \
+      LIMIT 10 \
+      OFFSET 20\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_render_only_the_select_when_no_set_operation_was_added() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users).as_string();
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_use_the_formatter_passed_as_argument() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .limit("10")
+      .as_string_with(&sql::fmt::multiline());
+    let expected_query = "SELECT login \nFROM users \nUNION \nSELECT login \nFROM admins \nLIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users).debug().as_string();
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users).print().as_string();
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_operations_should_accumulate_in_the_order_of_the_calls() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let guests = sql::Select::new().select("login").from("guests");
+    let banned = sql::Select::new().select("login").from("banned");
+    let staff = sql::Select::new().select("login").from("staff");
+    let query = sql::CompoundSelect::new(users)
+      .union_all(admins)
+      .intersect(guests)
+      .except(banned)
+      .union(staff)
+      .as_string();
+    let expected_query = "\
+      SELECT login FROM users \
+      UNION ALL SELECT login FROM admins \
+      INTERSECT SELECT login FROM guests \
+      EXCEPT SELECT login FROM banned \
+      UNION SELECT login FROM staff\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_should_be_applied_to_the_compound_instead_of_the_branch() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .order_by("login desc")
+      .order_by("login desc")
+      .as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins ORDER BY login desc";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_limit_and_offset_should_override_the_previous_value() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .limit("5")
+      .limit("10")
+      .offset("5")
+      .offset("20")
+      .as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins LIMIT 10 OFFSET 20";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_and_limit_should_apply_to_the_whole_compound() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .order_by("1")
+      .limit("10")
+      .as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins ORDER BY 1 LIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn set_operations_should_wrap_the_branches_with_their_own_order_by_or_limit() {
+    let users = sql::Select::new()
+      .select("login")
+      .from("users")
+      .order_by("created_at")
+      .limit("5");
+    let admins = sql::Select::new().select("login").from("admins");
+    let guests = sql::Select::new().select("login").from("guests").offset("10");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .union(guests)
+      .order_by("1")
+      .as_string();
+    let expected_query = "\
+      (SELECT login FROM users ORDER BY created_at LIMIT 5) \
+      UNION SELECT login FROM admins \
+      UNION (SELECT login FROM guests OFFSET 10) \
+      ORDER BY 1\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[allow(deprecated)]
+  fn set_operations_should_wrap_the_branches_with_their_own_set_operations() {
+    let guests = sql::Select::new().select("login").from("guests");
+    let users = sql::Select::new().select("login").from("users").union(guests);
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(admins).intersect(users).as_string();
+    let expected_query = "\
+      SELECT login FROM admins \
+      INTERSECT ((SELECT login FROM users) UNION (SELECT login FROM guests))\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn set_operations_should_wrap_the_branches_with_raw_sql() {
+    let users = sql::Select::new().raw("select login from users order by login limit 5");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(admins).union(users).as_string();
+    let expected_query = "SELECT login FROM admins UNION (select login from users order by login limit 5)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_and_raw_after_should_be_rendered_around_the_clause() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .order_by("login")
+      .limit("10")
+      .raw_before(sql::CompoundSelectClause::Select, "/* before select */")
+      .raw_after(sql::CompoundSelectClause::Select, "/* after select */")
+      .raw_before(sql::CompoundSelectClause::Combinator, "/* before combinator */")
+      .raw_after(sql::CompoundSelectClause::Combinator, "/* after combinator */")
+      .raw_before(sql::CompoundSelectClause::Limit, "/* before limit */")
+      .as_string();
+    let expected_query = "\
+      /* before select */ SELECT login FROM users /* after select */ \
+      /* before combinator */ UNION SELECT login FROM admins /* after combinator */ \
+      ORDER BY login \
+      /* before limit */ LIMIT 10\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_be_rendered_when_the_clause_is_not_defined() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users)
+      .raw_before(sql::CompoundSelectClause::With, "/* no with */")
+      .as_string();
+    let expected_query = "/* no with */ SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_the_raw_sql() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users)
+      .raw_after(sql::CompoundSelectClause::Select, "  /* end */  ")
+      .as_string();
+    let expected_query = "SELECT login FROM users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_clause_and_its_raw_sql() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .order_by("login")
+      .raw_before(sql::CompoundSelectClause::OrderBy, "/* sorted */")
+      .raw_after(sql::CompoundSelectClause::Combinator, "/* all logins */")
+      .clear(sql::CompoundSelectClause::OrderBy)
+      .as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins /* all logins */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_keep_the_first_query_when_the_combinator_clause_is_cleared() {
+    let users = sql::Select::new().select("login").from("users");
+    let admins = sql::Select::new().select("login").from("admins");
+    let query = sql::CompoundSelect::new(users)
+      .union(admins)
+      .intersect(sql::Select::new().select("login").from("guests"))
+      .limit("10")
+      .clear(sql::CompoundSelectClause::Combinator)
+      .as_string();
+    let expected_query = "SELECT login FROM users LIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_with_limit_and_offset_clauses() {
+    let users = sql::Select::new().select("login").from("active_users");
+    let query = sql::CompoundSelect::new(users)
+      .with_raw("active_users", "select login from users where active = true")
+      .limit("10")
+      .offset("20")
+      .clear(sql::CompoundSelectClause::With)
+      .clear(sql::CompoundSelectClause::Limit)
+      .clear(sql::CompoundSelectClause::Offset)
+      .as_string();
+    let expected_query = "SELECT login FROM active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_not_change_the_query_when_the_clause_was_never_defined() {
+    let users = sql::Select::new().select("login").from("users");
+    let query = sql::CompoundSelect::new(users)
+      .clear(sql::CompoundSelectClause::OrderBy)
+      .as_string();
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -266,6 +266,48 @@ mod builder_methods {
     assert_eq!(expected_query, query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::CreateIndex::new()
+      .raw_append("/* end */")
+      .create_index("users_name_idx")
+      .on("users")
+      .column("name")
+      .as_string();
+    let expected_query = "CREATE INDEX users_name_idx ON users (name) /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_name_idx")
+      .on("users")
+      .column("name")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "CREATE INDEX users_name_idx ON users (name) /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::CreateIndex::new()
+      .raw("/* raw */")
+      .create_index("users_name_idx")
+      .on("users")
+      .column("name")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ CREATE INDEX users_name_idx ON users (name)";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::CreateIndex::new()
@@ -562,6 +604,37 @@ mod method_create_index_if_not_exists {
   }
 }
 
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod method_if_not_exists {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_if_not_exists_should_add_the_if_not_exists_modifier() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .if_not_exists()
+      .as_string();
+    let expected_query = "CREATE INDEX IF NOT EXISTS users_login_idx";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_if_not_exists_should_be_independent_of_the_order_of_the_calls() {
+    let query = sql::CreateIndex::new()
+      .unique()
+      .if_not_exists()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .as_string();
+    let expected_query = "CREATE UNIQUE INDEX IF NOT EXISTS users_login_idx ON users (login)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 mod method_on {
   use pretty_assertions::assert_eq;
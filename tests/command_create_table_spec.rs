@@ -252,6 +252,45 @@ mod builder_methods {
     assert_eq!(expected_query, query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::CreateTable::new()
+      .raw_append("/* end */")
+      .create_table("users")
+      .column("id serial primary key")
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial primary key) /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial primary key")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial primary key) /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::CreateTable::new()
+      .raw("/* raw */")
+      .create_table("users")
+      .column("id serial primary key")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ CREATE TABLE users (id serial primary key)";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::CreateTable::new()
@@ -611,6 +650,56 @@ mod method_foreign_key {
   }
 }
 
+mod method_if_not_exists {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_if_not_exists_should_add_the_if_not_exists_modifier() {
+    let query = sql::CreateTable::new()
+      .create_table("films")
+      .if_not_exists()
+      .as_string();
+    let expected_query = "CREATE TABLE IF NOT EXISTS films";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_if_not_exists_should_be_independent_of_the_order_of_the_calls() {
+    let query = sql::CreateTable::new()
+      .if_not_exists()
+      .create_table("films")
+      .column("id serial")
+      .as_string();
+    let expected_query = "CREATE TABLE IF NOT EXISTS films (id serial)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_if_not_exists_should_not_duplicate_the_modifier_of_the_create_table_if_not_exists_method() {
+    let query = sql::CreateTable::new()
+      .create_table_if_not_exists("films")
+      .if_not_exists()
+      .as_string();
+    let expected_query = "CREATE TABLE IF NOT EXISTS films";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_if_not_exists_should_be_rendered_with_the_keyword_case_of_the_formatter() {
+    let query = sql::CreateTable::new()
+      .create_table("films")
+      .if_not_exists()
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "create table if not exists films";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
 mod method_primary_key {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
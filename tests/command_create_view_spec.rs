@@ -0,0 +1,327 @@
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn create_view_builder_should_be_displayable() {
+    let create_view = sql::CreateView::new().create_view("user_logins");
+
+    println!("{}", create_view);
+
+    let query = create_view.as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_be_debuggable() {
+    let select = sql::Select::new().select("login").from("users");
+    let create_view = sql::CreateView::new().create_view("user_logins").as_select(select);
+
+    println!("{:?}", create_view);
+
+    let expected_query = "CREATE VIEW user_logins AS SELECT login FROM users";
+    let query = create_view.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_be_cloneable() {
+    let create_view = sql::CreateView::new().create_view("user_logins");
+    let create_view_with_select = create_view
+      .clone()
+      .as_select(sql::Select::new().select("login").from("users"));
+
+    let expected_create_view = "CREATE VIEW user_logins";
+    assert_eq!(expected_create_view, create_view.as_string());
+
+    let expected_create_view_with_select = "CREATE VIEW user_logins AS SELECT login FROM users";
+    assert_eq!(expected_create_view_with_select, create_view_with_select.as_string());
+  }
+
+  #[test]
+  fn create_view_builder_should_be_composable() {
+    fn add_comment(create_view: sql::CreateView) -> sql::CreateView {
+      create_view.raw("/* create view command */")
+    }
+
+    fn create_user_logins(create_view: sql::CreateView) -> sql::CreateView {
+      create_view.create_view("user_logins")
+    }
+
+    fn as_string(create_view: sql::CreateView) -> String {
+      create_view.as_string()
+    }
+
+    let query = Some(sql::CreateView::new())
+      .map(add_comment)
+      .map(create_user_logins)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* create view command */ CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_use_the_formatter_in_the_nested_select() {
+    let select = sql::Select::new().select("login").from("users");
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .as_select(select)
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "create view user_logins as select login from users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateView::new().create_view("user_logins").debug().as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateView::new().create_view("user_logins").print().as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateView::new().raw("create view user_logins").as_string();
+    let expected_query = "create view user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .raw("/* create view command */")
+      .as_string();
+    let expected_query = "/* create view command */ CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateView::new()
+      .raw("create view user_logins")
+      .raw("create view user_logins")
+      .as_string();
+    let expected_query = "create view user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::CreateView::new()
+      .raw_append("/* end */")
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::CreateView::new()
+      .raw("/* raw */")
+      .create_view("user_logins")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ CREATE VIEW user_logins";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod method_create_view {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_view_should_add_the_create_view_signature() {
+    let query = sql::CreateView::new().create_view("user_logins").as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_create_view_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateView::new()
+      .create_view("users_view")
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_create_view_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new().create_view("  user_logins  ").as_string();
+    let expected_query = "CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_create_view() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewParams::CreateView, "/* create view */")
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "/* create view */ CREATE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_create_view() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .raw_after(sql::CreateViewParams::CreateView, "(login)")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (login)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_as_select {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_select_should_embed_the_select_query() {
+    let select = sql::Select::new()
+      .select("login, name")
+      .from("users")
+      .where_clause("active = true");
+    let query = sql::CreateView::new().as_select(select).as_string();
+    let expected_query = "AS SELECT login, name FROM users WHERE active = true";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_as_select_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("login").from("users"))
+      .as_select(sql::Select::new().select("name").from("users"))
+      .as_string();
+    let expected_query = "AS SELECT name FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn clause_as_select_should_be_after_create_view_clause() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("login").from("users"))
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins AS SELECT login FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_as_select() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .raw_before(sql::CreateViewParams::AsSelect, "(login)")
+      .as_select(sql::Select::new().select("login").from("users"))
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (login) AS SELECT login FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_as_select() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("login").from("users"))
+      .raw_after(sql::CreateViewParams::AsSelect, "with check option")
+      .as_string();
+    let expected_query = "AS SELECT login FROM users with check option";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_as_select_should_be_wrapped_in_parentheses_when_the_formatter_enables_paren_subqueries() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .as_select(sql::Select::new().select("login").from("users"))
+      .as_string_with(&sql::fmt::one_line().with_paren_subqueries(true));
+    let expected_query = "CREATE VIEW user_logins AS (SELECT login FROM users)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "mysql"))]
+mod method_or_replace {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_or_replace_should_add_the_or_replace_modifier() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .or_replace()
+      .as_string();
+    let expected_query = "CREATE OR REPLACE VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_or_replace_should_not_render_without_the_view_name() {
+    let query = sql::CreateView::new().or_replace().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+}
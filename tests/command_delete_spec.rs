@@ -29,6 +29,35 @@ mod builder_features {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn delete_builder_should_be_equal_to_a_builder_that_renders_the_same_sql() {
+    let delete_a = sql::Delete::new().delete_from("users").where_clause("login = 'foo'");
+    let delete_b = sql::Delete::new().where_clause("login = 'foo'").delete_from("users");
+    let delete_c = sql::Delete::new().delete_from("orders");
+
+    assert_eq!(delete_a, delete_b);
+    assert_ne!(delete_a, delete_c);
+  }
+
+  #[test]
+  fn delete_builder_should_be_usable_as_a_hash_map_key() {
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(
+      sql::Delete::new().delete_from("users").where_clause("login = 'foo'"),
+      "plan_a",
+    );
+    cache.insert(
+      sql::Delete::new().where_clause("login = 'foo'").delete_from("users"),
+      "plan_b",
+    );
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+      cache.get(&sql::Delete::new().where_clause("login = 'foo'").delete_from("users")),
+      Some(&"plan_b")
+    );
+  }
+
   #[test]
   fn delete_builder_should_be_cloneable() {
     let delete_foo = sql::Delete::new()
@@ -130,6 +159,27 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_clear_should_remove_the_where_clause_entirely() {
+    let query = sql::Delete::new()
+      .delete_from("sessions")
+      .where_clause("expired = true")
+      .raw_after(sql::DeleteClause::Where, "/* expired */")
+      .clear(sql::DeleteClause::Where)
+      .as_string();
+    let expected_query = "DELETE FROM sessions";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_append_a_semicolon_to_the_query() {
+    let query = sql::Delete::new().delete_from("users").terminated().as_string();
+    let expected_query = "DELETE FROM users;";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_as_string_should_convert_the_current_state_into_string() {
     let query = sql::Delete::new().as_string();
@@ -146,6 +196,64 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_the_delete_from_clause_is_defined() {
+    let delete = sql::Delete::new().delete_from("users").where_clause("id = $1");
+    let expected_query = Ok("DELETE FROM users WHERE id = $1".to_string());
+
+    assert_eq!(delete.try_as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_delete_from_clause_is_missing() {
+    let delete = sql::Delete::new().where_clause("id = $1");
+    let expected_error = Err(sql::BuilderError::MissingClause("delete_from"));
+
+    assert_eq!(delete.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_not_verify_builders_with_raw_sql() {
+    let delete = sql::Delete::new().raw("delete from users");
+
+    assert!(delete.try_as_string().is_ok());
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_where_clause_is_missing() {
+    let delete = sql::Delete::new().delete_from("users");
+    let expected_error = Err(sql::BuilderError::UnsafeDelete);
+
+    assert_eq!(delete.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_where_clause_is_cleared() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = $1")
+      .clear(sql::DeleteClause::Where);
+    let expected_error = Err(sql::BuilderError::UnsafeDelete);
+
+    assert_eq!(delete.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_allow_full_table_should_allow_a_delete_without_the_where_clause() {
+    let delete = sql::Delete::new().delete_from("users").allow_full_table();
+    let expected_query = Ok("DELETE FROM users".to_string());
+
+    assert_eq!(delete.try_as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_as_string_should_render_a_delete_without_the_where_clause() {
+    let query = sql::Delete::new().delete_from("users").as_string();
+    let expected_query = "DELETE FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Delete::new().as_string();
@@ -223,6 +331,64 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Delete::new()
+      .raw_append("/* end */")
+      .delete_from("users")
+      .as_string();
+    let expected_query = "DELETE FROM users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "DELETE FROM users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Delete::new()
+      .raw("/* raw */")
+      .delete_from("users")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ DELETE FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_raw_prepend_and_raw_append_should_wrap_the_with_and_returning_clauses() {
+    let query = sql::Delete::new()
+      .with("expired", sql::Select::new().select("id").from("sessions"))
+      .delete_from("sessions")
+      .where_clause("id in (select id from expired)")
+      .returning("id")
+      .raw_prepend("/* start */")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "\
+      /* start */ \
+      WITH expired AS (SELECT id FROM sessions) \
+      DELETE FROM sessions \
+      WHERE id in (select id from expired) \
+      RETURNING id /* end */\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::Delete::new()
@@ -243,3 +409,49 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 }
+
+#[cfg(feature = "serde")]
+mod serde_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn round_trip(delete: &sql::Delete) -> sql::Delete {
+    let json = serde_json::to_string(delete).unwrap();
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn delete_builder_should_render_the_same_query_after_a_round_trip() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("login = 'foo'")
+      .where_or("login = 'bar'")
+      .raw_before(sql::DeleteClause::Where, "/* where */");
+
+    let query = round_trip(&delete).as_string();
+    let expected_query = delete.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn delete_builder_should_render_the_with_clause_after_a_round_trip() {
+    let delete = sql::Delete::new()
+      .with(
+        "inactive",
+        sql::Select::new()
+          .select("id")
+          .from("users")
+          .where_clause("active = false"),
+      )
+      .delete_from("users")
+      .where_clause("id in (select id from inactive)")
+      .returning("id");
+
+    let query = round_trip(&delete).as_string();
+    let expected_query = delete.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -215,6 +215,42 @@ mod builder_methods {
     assert_eq!(expected_query, query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::DropIndex::new()
+      .raw_append("/* end */")
+      .drop_index("users_name_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_name_idx /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_name_idx")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "DROP INDEX users_name_idx /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::DropIndex::new()
+      .raw("/* raw */")
+      .drop_index("users_name_idx")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ DROP INDEX users_name_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::DropIndex::new()
@@ -0,0 +1,253 @@
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn drop_view_builder_should_be_displayable() {
+    let drop_view = sql::DropView::new().drop_view("user_logins");
+
+    println!("{}", drop_view);
+
+    let query = drop_view.as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_debuggable() {
+    let drop_view = sql::DropView::new().drop_view("user_logins");
+
+    println!("{:?}", drop_view);
+
+    let expected_query = "DROP VIEW user_logins";
+    let query = drop_view.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_composable() {
+    fn add_comment(drop_view: sql::DropView) -> sql::DropView {
+      drop_view.raw("/* drop command */")
+    }
+
+    fn drop_user_logins(drop_view: sql::DropView) -> sql::DropView {
+      drop_view.drop_view("user_logins")
+    }
+
+    fn as_string(drop_view: sql::DropView) -> String {
+      drop_view.as_string()
+    }
+
+    let query = Some(sql::DropView::new())
+      .map(add_comment)
+      .map(drop_user_logins)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* drop command */ DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropView::new().drop_view("user_logins").debug().as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropView::new().drop_view("user_logins").print().as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropView::new().raw("drop view user_logins").as_string();
+    let expected_query = "drop view user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropView::new()
+      .raw("drop view user_logins")
+      .raw("drop view user_logins")
+      .as_string();
+    let expected_query = "drop view user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::DropView::new()
+      .raw_append("/* end */")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "DROP VIEW user_logins /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::DropView::new()
+      .drop_view("user_logins")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "DROP VIEW user_logins /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::DropView::new()
+      .raw("/* raw */")
+      .drop_view("user_logins")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ DROP VIEW user_logins";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod method_drop_view {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_view_should_add_the_drop_view_signature() {
+    let query = sql::DropView::new().drop_view("user_logins").as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(not(feature = "postgresql"))]
+  #[test]
+  fn method_drop_view_should_overrides_previous_value_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .drop_view("users_view")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_view_should_trim_space_of_the_argument() {
+    let query = sql::DropView::new().drop_view("  user_logins  ").as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_view_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropView::new()
+      .drop_view("user_logins")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_if_exists_should_add_the_if_exists_modifier() {
+    let query = sql::DropView::new().if_exists().drop_view("user_logins").as_string();
+    let expected_query = "DROP VIEW IF EXISTS user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_drop_view() {
+    let query = sql::DropView::new()
+      .raw_before(sql::DropViewParams::DropView, "/* drop view */")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "/* drop view */ DROP VIEW user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_drop_view() {
+    let query = sql::DropView::new()
+      .drop_view("user_logins")
+      .raw_after(sql::DropViewParams::DropView, "/* end drop view */")
+      .as_string();
+    let expected_query = "DROP VIEW user_logins /* end drop view */";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_feature_flag {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_view_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .drop_view("users_view")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "DROP VIEW users_view, user_logins";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_option_after_the_view_names() {
+    let query = sql::DropView::new()
+      .cascade()
+      .if_exists()
+      .drop_view("users_view")
+      .drop_view("user_logins")
+      .as_string();
+    let expected_query = "DROP VIEW IF EXISTS users_view, user_logins CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_cascade_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::DropView::new()
+      .drop_view("user_logins")
+      .if_exists()
+      .cascade()
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "drop view if exists user_logins cascade";
+
+    assert_eq!(expected_query, query);
+  }
+}
@@ -30,6 +30,35 @@ mod builder_features {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn insert_builder_should_be_equal_to_a_builder_that_renders_the_same_sql() {
+    let insert_a = sql::Insert::new().insert_into("users (login)").values("('foo')");
+    let insert_b = sql::Insert::new().values("('foo')").insert_into("users (login)");
+    let insert_c = sql::Insert::new().insert_into("users (login)").values("('bar')");
+
+    assert_eq!(insert_a, insert_b);
+    assert_ne!(insert_a, insert_c);
+  }
+
+  #[test]
+  fn insert_builder_should_be_usable_as_a_hash_map_key() {
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(
+      sql::Insert::new().insert_into("users (login)").values("('foo')"),
+      "plan_a",
+    );
+    cache.insert(
+      sql::Insert::new().values("('foo')").insert_into("users (login)"),
+      "plan_b",
+    );
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+      cache.get(&sql::Insert::new().values("('foo')").insert_into("users (login)")),
+      Some(&"plan_b")
+    );
+  }
+
   #[test]
   fn insert_builder_should_be_cloneable() {
     let insert_foo = sql::Insert::new()
@@ -130,6 +159,124 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_clear_should_remove_the_on_conflict_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_conflict_do_nothing(sql::ConflictTarget::columns(&["login"]))
+      .clear(sql::InsertClause::OnConflict)
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_insert_into_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .clear(sql::InsertClause::InsertInto)
+      .as_string();
+    let expected_query = "VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_append_a_semicolon_to_the_query() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .terminated()
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo');";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_add_a_separated_statement_after_the_insert_command() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .append("ANALYZE users")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo');\nANALYZE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_accumulate_values_on_consecutive_calls_in_the_same_order() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .append("  ANALYZE users  ")
+      .append("")
+      .append("SELECT count(*) FROM users")
+      .as_string();
+    let expected_query = "INSERT INTO users (login);\nANALYZE users;\nSELECT count(*) FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_be_rendered_after_the_raw_after_of_the_last_clause() {
+    let query = sql::Insert::new()
+      .append("ANALYZE users")
+      .values("('foo')")
+      .raw_after(sql::InsertClause::Values, "/* seed */")
+      .as_string();
+    let expected_query = "VALUES ('foo') /* seed */;\nANALYZE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_be_rendered_before_the_terminator() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .append("ANALYZE users")
+      .terminated()
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo');\nANALYZE users;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_separate_the_statements_with_a_line_break_in_the_multiline_formatter() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .append("ANALYZE users")
+      .as_string_with(&sql::fmt::multiline());
+    let expected_query = "INSERT INTO users (login);\nANALYZE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_not_be_rendered_when_the_insert_is_nested() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .append("ANALYZE users");
+    let query = sql::Transaction::new().insert(insert).as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo');";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_append_should_not_add_the_separator_when_the_insert_command_is_empty() {
+    let query = sql::Insert::new().append("ANALYZE users").as_string();
+    let expected_query = "ANALYZE users";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_as_string_should_convert_the_current_state_into_string() {
     let query = sql::Insert::new().as_string();
@@ -138,6 +285,44 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_as_string_with_should_use_the_formatter_passed_as_argument() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')");
+
+    let query = insert.as_string_with(&sql::fmt::multiline_crlf());
+    let expected_query = "INSERT INTO users (login) \r\nVALUES \r\n  ('foo'),\r\n  ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_place_the_rows_one_per_line_with_the_pg_format_style() {
+    let insert = sql::Insert::new()
+      .insert_into("users (id, login)")
+      .values("(1, 'a')")
+      .values("(2, 'b')");
+
+    let query = insert.as_string_with(&sql::fmt::pg_format_style());
+    let expected_query = "INSERT INTO users (id, login) \nVALUES \n    (1, 'a'),\n    (2, 'b')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_render_the_keywords_in_lower_case() {
+    let insert = sql::Insert::new()
+      .insert_into("USERS (LOGIN)")
+      .values("('FOO')")
+      .on_conflict("DO NOTHING");
+    let query = insert.as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "insert into USERS (LOGIN) values ('FOO') on conflict DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_debug_should_print_at_console_in_a_human_readable_format() {
     let query = sql::Insert::new().insert_into("users").debug().as_string();
@@ -146,6 +331,98 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_pretty_should_not_change_the_output_of_as_string() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo'), ('bar', 'Bar'), ('baz', 'Baz')")
+      .pretty(20)
+      .as_string();
+    let expected_query = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar'), ('baz', 'Baz')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_validate_against_generated_should_return_no_warnings_without_generated_columns_in_the_list() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo')");
+
+    assert!(insert.validate_against_generated(&["full_name", ""]).is_empty());
+  }
+
+  #[test]
+  fn method_validate_against_generated_should_warn_for_each_generated_column_in_the_list() {
+    let insert = sql::Insert::new().insert_into("users (login, full_name, search_vector)");
+    let expected_warnings = vec![
+      "the generated column full_name must not be in the column list of the insert",
+      "the generated column search_vector must not be in the column list of the insert",
+    ];
+
+    assert_eq!(
+      insert.validate_against_generated(&["full_name", "search_vector", "id"]),
+      expected_warnings
+    );
+  }
+
+  #[test]
+  fn method_validate_against_generated_should_ignore_the_case_and_the_double_quotes() {
+    let insert = sql::Insert::new().insert_into(r#"users (login, "Full_Name")"#);
+    let expected_warnings = vec!["the generated column full_name must not be in the column list of the insert"];
+
+    assert_eq!(insert.validate_against_generated(&["full_name"]), expected_warnings);
+  }
+
+  #[test]
+  fn method_validate_against_generated_should_return_no_warnings_without_column_list() {
+    let insert = sql::Insert::new().insert_into("users").values("('foo', 'Foo')");
+
+    assert!(insert.validate_against_generated(&["users", "full_name"]).is_empty());
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_the_required_clauses_are_defined() {
+    let insert = sql::Insert::new().insert_into("users (login)").values("('foo')");
+    let expected_query = Ok("INSERT INTO users (login) VALUES ('foo')".to_string());
+
+    assert_eq!(insert.try_as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_accept_the_select_and_default_values_clauses() {
+    let insert_select = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("admins"));
+    let insert_default = sql::Insert::new().insert_into("users").default_values();
+
+    assert!(insert_select.try_as_string().is_ok());
+    assert!(insert_default.try_as_string().is_ok());
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_insert_into_clause_is_missing() {
+    let insert = sql::Insert::new().values("('foo')");
+    let expected_error = Err(sql::BuilderError::MissingClause("insert_into"));
+
+    assert_eq!(insert.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_values_clause_is_missing() {
+    let insert = sql::Insert::new().insert_into("users (login)");
+    let expected_error = Err(sql::BuilderError::MissingClause("values"));
+
+    assert_eq!(insert.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_not_verify_builders_with_raw_sql() {
+    let insert = sql::Insert::new().raw("insert into users (login) values ('foo')");
+
+    assert!(insert.try_as_string().is_ok());
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Insert::new().as_string();
@@ -226,6 +503,91 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Insert::new()
+      .raw_append("/* end */")
+      .insert_into("users (login)")
+      .values("('foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Insert::new()
+      .raw("/* raw */")
+      .insert_into("users (login)")
+      .values("('foo')")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ INSERT INTO users (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_raw_append_should_be_rendered_after_the_returning_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .raw_append("/* end */")
+      .returning("id")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') RETURNING id /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_be_rendered_before_the_appended_statements() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .append("ANALYZE users")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') /* end */;\nANALYZE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_raw_prepend_should_be_rendered_before_the_with_clause() {
+    let query = sql::Insert::new()
+      .with("new_logins", sql::Select::new().select("login").from("signups"))
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("new_logins"))
+      .raw_prepend("/* prepended */")
+      .as_string();
+    let expected_query = "\
+      /* prepended */ \
+      WITH new_logins AS (SELECT login FROM signups) \
+      INSERT INTO users (login) \
+      SELECT login FROM new_logins\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::Insert::new()
@@ -245,6 +607,18 @@ mod builder_methods {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_as_string_with_should_remove_the_redundant_whitespaces_with_the_minified_formatter() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo  Bar')")
+      .values("('bar', 'Bar')")
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "INSERT INTO users (login,name) VALUES ('foo','Foo  Bar'),('bar','Bar')";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 #[cfg(feature = "sqlite")]
@@ -278,4 +652,132 @@ mod insert_variances {
     let expected_query = "INSERT INTO users (login, name)";
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_clear_should_only_remove_the_insert_variance_of_the_clause() {
+    let insert = sql::Insert::new().insert_or("ABORT INTO users (login, name)");
+
+    assert_eq!(
+      insert.clone().clear(sql::InsertClause::InsertInto).as_string(),
+      "INSERT OR ABORT INTO users (login, name)"
+    );
+    assert_eq!(insert.clear(sql::InsertClause::InsertOr).as_string(), "");
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_bind {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_into_parts_should_number_the_placeholders_in_order() {
+    let (query, params) = sql::Insert::new()
+      .insert_into("users (login, active)")
+      .values("(?, ?)")
+      .bind("foo".to_string())
+      .bind(false)
+      .into_parts();
+
+    assert_eq!(query, "INSERT INTO users (login, active) VALUES ($1, $2)");
+    assert_eq!(
+      params,
+      vec![sql::ParamValue::Text("foo".to_string()), sql::ParamValue::Bool(false)]
+    );
+  }
+
+  #[test]
+  fn method_into_parts_should_include_the_params_of_the_select_clause() {
+    let admins = sql::Select::new()
+      .select("login")
+      .from("admins")
+      .where_clause("level = ?")
+      .bind(1);
+    let (query, params) = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(admins)
+      .into_parts();
+
+    assert_eq!(
+      query,
+      "INSERT INTO users (login) SELECT login FROM admins WHERE level = $1"
+    );
+    assert_eq!(params, vec![sql::ParamValue::Int(1)]);
+  }
+
+  #[test]
+  fn method_into_parts_should_order_the_params_of_the_select_before_the_on_conflict_params() {
+    let admins = sql::Select::new()
+      .select("login, level")
+      .from("admins")
+      .where_clause("level = ?")
+      .bind(1);
+    let (query, params) = sql::Insert::new()
+      .insert_into("users (login, level)")
+      .select(admins)
+      .on_conflict("(login) DO UPDATE SET level = ?")
+      .bind(2)
+      .into_parts();
+    let expected_query = "\
+      INSERT INTO users (login, level) \
+      SELECT login, level FROM admins WHERE level = $1 \
+      ON CONFLICT (login) DO UPDATE SET level = $2\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::ParamValue::Int(1), sql::ParamValue::Int(2)]);
+  }
+}
+
+#[cfg(feature = "serde")]
+mod serde_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn round_trip(insert: &sql::Insert) -> sql::Insert {
+    let json = serde_json::to_string(insert).unwrap();
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn insert_builder_should_render_the_same_query_after_a_round_trip() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo')")
+      .values("('bar', 'Bar')")
+      .on_conflict_do_update(sql::ConflictTarget::columns(&["login"]), [("name", "excluded.name")])
+      .raw_after(sql::InsertClause::Values, "/* values */");
+
+    let query = round_trip(&insert).as_string();
+    let expected_query = insert.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn insert_builder_should_render_the_nested_select_after_a_round_trip() {
+    let insert = sql::Insert::new()
+      .insert_into("users_bkp (login)")
+      .select(sql::Select::new().select("login").from("users"));
+
+    let query = round_trip(&insert).as_string();
+    let expected_query = "INSERT INTO users_bkp (login) SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn insert_builder_should_render_the_with_clause_after_a_round_trip() {
+    let insert = sql::Insert::new()
+      .with("active_users", sql::Select::new().select("login").from("users"))
+      .insert_into("users_bkp (login)")
+      .select(sql::Select::new().select("login").from("active_users"))
+      .returning("login");
+
+    let query = round_trip(&insert).as_string();
+    let expected_query = insert.as_string();
+
+    assert_eq!(query, expected_query);
+  }
 }
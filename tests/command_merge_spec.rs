@@ -0,0 +1,460 @@
+#[cfg(feature = "postgresql")]
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn merge_builder_should_be_displayable() {
+    let merge = sql::Merge::new().merge_into("users u").using("new_users n");
+
+    println!("{}", merge);
+
+    let query = merge.as_string();
+    let expected_query = "MERGE INTO users u USING new_users n";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_debuggable() {
+    let merge = sql::Merge::new().merge_into("users u").on("u.id = n.id");
+
+    println!("{:?}", merge);
+
+    let expected_query = "MERGE INTO users u ON u.id = n.id";
+    let query = merge.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_cloneable() {
+    let merge = sql::Merge::new().merge_into("users u").using("new_users n");
+    let merge_with_action = merge.clone().when_matched("DELETE");
+
+    let expected_merge = "MERGE INTO users u USING new_users n";
+    assert_eq!(expected_merge, merge.as_string());
+
+    let expected_merge_with_action = "MERGE INTO users u USING new_users n WHEN MATCHED THEN DELETE";
+    assert_eq!(expected_merge_with_action, merge_with_action.as_string());
+  }
+
+  #[test]
+  fn merge_builder_should_be_composable() {
+    fn add_comment(merge: sql::Merge) -> sql::Merge {
+      merge.raw("/* merge command */")
+    }
+
+    fn merge_users(merge: sql::Merge) -> sql::Merge {
+      merge.merge_into("users u")
+    }
+
+    fn as_string(merge: sql::Merge) -> String {
+      merge.as_string()
+    }
+
+    let query = Some(sql::Merge::new())
+      .map(add_comment)
+      .map(merge_users)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* merge command */ MERGE INTO users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_used_in_a_transaction() {
+    let merge = sql::Merge::new().merge_into("users u").using("new_users n");
+    let query = sql::Transaction::new().merge(merge).as_string();
+    let expected_query = "MERGE INTO users u USING new_users n;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_render_the_clauses_in_the_sql_order() {
+    let query = sql::Merge::new()
+      .when_not_matched("INSERT (login, name) VALUES (n.login, n.name)")
+      .when_matched("UPDATE SET name = n.name")
+      .on("u.login = n.login")
+      .using("new_users n")
+      .merge_into("users u")
+      .as_string();
+    let expected_query = "\
+      MERGE INTO users u \
+      USING new_users n \
+      ON u.login = n.login \
+      WHEN NOT MATCHED THEN INSERT (login, name) VALUES (n.login, n.name) \
+      WHEN MATCHED THEN UPDATE SET name = n.name\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Merge::new()
+      .merge_into("users u")
+      .using("new_users n")
+      .on("u.login = n.login")
+      .when_matched("DELETE")
+      .when_not_matched("DO NOTHING")
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "\
+      merge into users u \
+      using new_users n \
+      on u.login = n.login \
+      when matched then DELETE \
+      when not matched then DO NOTHING\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Merge::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Merge::new().merge_into("users u").debug().as_string();
+    let expected_query = "MERGE INTO users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Merge::new().merge_into("users u").print().as_string();
+    let expected_query = "MERGE INTO users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Merge::new().raw("merge into users u").as_string();
+    let expected_query = "merge into users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Merge::new()
+      .merge_into("users u")
+      .raw("/* merge command */")
+      .as_string();
+    let expected_query = "/* merge command */ MERGE INTO users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new()
+      .raw("merge into users u")
+      .raw("merge into users u")
+      .as_string();
+    let expected_query = "merge into users u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Merge::new()
+      .raw_append("/* end */")
+      .merge_into("users u")
+      .as_string();
+    let expected_query = "MERGE INTO users u /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Merge::new()
+      .merge_into("users u")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "MERGE INTO users u /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Merge::new()
+      .raw("/* raw */")
+      .merge_into("users u")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ MERGE INTO users u";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_merge_into {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_merge_into_should_add_the_merge_into_clause() {
+    let query = sql::Merge::new().merge_into("users").as_string();
+    let expected_query = "MERGE INTO users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_merge_into_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new().merge_into("orders").merge_into("users").as_string();
+    let expected_query = "MERGE INTO users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_merge_into_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().merge_into("  users  ").as_string();
+    let expected_query = "MERGE INTO users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_merge_into_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::MergeInto, "/* merge */")
+      .merge_into("users")
+      .as_string();
+    let expected_query = "/* merge */ MERGE INTO users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_merge_into_clause() {
+    let query = sql::Merge::new()
+      .merge_into("users")
+      .raw_after(sql::MergeClause::MergeInto, "AS u")
+      .as_string();
+    let expected_query = "MERGE INTO users AS u";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_using {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_add_the_using_clause() {
+    let query = sql::Merge::new().using("new_users n").as_string();
+    let expected_query = "USING new_users n";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_using_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new().using("orders").using("  new_users n  ").as_string();
+    let expected_query = "USING new_users n";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_using_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::Using, "merge into users u")
+      .using("new_users n")
+      .as_string();
+    let expected_query = "merge into users u USING new_users n";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_using_clause() {
+    let query = sql::Merge::new()
+      .using("new_users n")
+      .raw_after(sql::MergeClause::Using, "on u.id = n.id")
+      .as_string();
+    let expected_query = "USING new_users n on u.id = n.id";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_on {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_add_the_on_clause() {
+    let query = sql::Merge::new().on("u.id = n.id").as_string();
+    let expected_query = "ON u.id = n.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_on_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .on("u.id = n.id")
+      .on("  u.login = n.login  ")
+      .as_string();
+    let expected_query = "ON u.login = n.login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_on_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::On, "using new_users n")
+      .on("u.id = n.id")
+      .as_string();
+    let expected_query = "using new_users n ON u.id = n.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_on_clause() {
+    let query = sql::Merge::new()
+      .on("u.id = n.id")
+      .raw_after(sql::MergeClause::On, "when matched then delete")
+      .as_string();
+    let expected_query = "ON u.id = n.id when matched then delete";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_when_matched {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_when_matched_should_add_the_when_matched_clause() {
+    let query = sql::Merge::new().when_matched("UPDATE SET name = n.name").as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = n.name";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_matched_should_accumulate_values_in_the_order_of_the_calls() {
+    let query = sql::Merge::new()
+      .when_matched("DELETE")
+      .when_not_matched("INSERT (id) VALUES (n.id)")
+      .when_matched("UPDATE SET name = n.name")
+      .as_string();
+    let expected_query = "\
+      WHEN MATCHED THEN DELETE \
+      WHEN NOT MATCHED THEN INSERT (id) VALUES (n.id) \
+      WHEN MATCHED THEN UPDATE SET name = n.name\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_matched_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::Merge::new().when_matched("").when_matched("  ").as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_matched_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new()
+      .when_matched("DELETE")
+      .when_matched("  DELETE  ")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_when_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::When, "on u.id = n.id")
+      .when_matched("DELETE")
+      .as_string();
+    let expected_query = "on u.id = n.id WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_when_clause() {
+    let query = sql::Merge::new()
+      .when_matched("DELETE")
+      .raw_after(sql::MergeClause::When, "when not matched then do nothing")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE when not matched then do nothing";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_when_not_matched {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_when_not_matched_should_add_the_when_not_matched_clause() {
+    let query = sql::Merge::new()
+      .when_not_matched("INSERT (id) VALUES (n.id)")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT (id) VALUES (n.id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_not_matched_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().when_not_matched("  DO NOTHING  ").as_string();
+    let expected_query = "WHEN NOT MATCHED THEN DO NOTHING";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_not_matched_should_not_be_deduplicated_with_when_matched() {
+    let query = sql::Merge::new()
+      .when_matched("DO NOTHING")
+      .when_not_matched("DO NOTHING")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DO NOTHING WHEN NOT MATCHED THEN DO NOTHING";
+
+    assert_eq!(expected_query, query);
+  }
+}
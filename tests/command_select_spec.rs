@@ -26,6 +26,52 @@ mod builder_features {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn select_builder_should_be_equal_to_a_builder_that_renders_the_same_sql() {
+    let select_a = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true");
+    let select_b = sql::Select::new()
+      .where_clause("active = true")
+      .from("users")
+      .select("id");
+    let select_c = sql::Select::new().select("id").from("orders");
+
+    assert_eq!(select_a, select_b);
+    assert_ne!(select_a, select_c);
+  }
+
+  #[test]
+  fn select_builder_should_be_usable_as_a_hash_map_key() {
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(
+      sql::Select::new()
+        .select("id")
+        .from("users")
+        .where_clause("active = true"),
+      "plan_a",
+    );
+    cache.insert(
+      sql::Select::new()
+        .where_clause("active = true")
+        .from("users")
+        .select("id"),
+      "plan_b",
+    );
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+      cache.get(
+        &sql::Select::new()
+          .where_clause("active = true")
+          .from("users")
+          .select("id")
+      ),
+      Some(&"plan_b")
+    );
+  }
+
   #[test]
   fn select_builder_should_be_cloneable() {
     let select_zipcode = sql::Select::new()
@@ -150,6 +196,154 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_clear_should_remove_the_where_clause_entirely() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true")
+      .where_or("login = 'foo'")
+      .clear(sql::SelectClause::Where)
+      .as_string();
+    let expected_query = "SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_allow_the_clause_to_be_defined_again() {
+    let query = sql::Select::new()
+      .from("users")
+      .where_clause("active = true")
+      .clear(sql::SelectClause::Where)
+      .where_clause("login = 'foo'")
+      .as_string();
+    let expected_query = "FROM users WHERE login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_raw_sql_bound_to_the_clause() {
+    let query = sql::Select::new()
+      .from("users")
+      .order_by("id")
+      .raw_before(sql::SelectClause::OrderBy, "/* sorted */")
+      .raw_after(sql::SelectClause::From, "u")
+      .clear(sql::SelectClause::OrderBy)
+      .as_string();
+    let expected_query = "FROM users u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_be_a_no_op_for_a_clause_never_defined() {
+    let select = sql::Select::new().select("id").from("users");
+    let expected_query = select.as_string();
+
+    assert_eq!(select.clear(sql::SelectClause::Where).as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_append_a_semicolon_to_the_query() {
+    let query = sql::Select::new().select("id").from("users").terminated().as_string();
+    let expected_query = "SELECT id FROM users;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_not_terminate_an_empty_query() {
+    let query = sql::Select::new().terminated().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_place_the_semicolon_after_the_last_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .order_by("id")
+      .raw_after(sql::SelectClause::OrderBy, "/* end */")
+      .terminated()
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id /* end */;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_not_terminate_the_nested_builders() {
+    let admins = sql::Select::new().select("login").from("admins").terminated();
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(admins)
+      .terminated()
+      .as_string();
+    let expected_query = "INSERT INTO users (login) SELECT login FROM admins;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_terminated_should_not_terminate_the_queries_of_the_with_clause() {
+    let query = sql::Select::new()
+      .with("admins", sql::Select::new().select("id").from("users").terminated())
+      .select("*")
+      .from("admins")
+      .terminated()
+      .as_string();
+    let expected_query = "WITH admins AS (SELECT id FROM users) SELECT * FROM admins;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_not_duplicate_the_terminator_of_the_transaction_commands() {
+    let query = sql::Transaction::new()
+      .select(sql::Select::new().select("id").from("users").terminated())
+      .as_string();
+    let expected_query = sql::Transaction::new()
+      .select(sql::Select::new().select("id").from("users"))
+      .as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_the_select_clause_is_defined() {
+    let select = sql::Select::new().select("id").from("users");
+    let expected_query = Ok("SELECT id FROM users".to_string());
+
+    assert_eq!(select.try_as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_select_clause_is_missing() {
+    let select = sql::Select::new().from("users").where_clause("id = $1");
+    let expected_error = Err(sql::BuilderError::MissingClause("select"));
+
+    assert_eq!(select.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_not_verify_builders_with_raw_sql() {
+    let select = sql::Select::new().raw("select id").from("users");
+
+    assert!(select.try_as_string().is_ok());
+  }
+
+  #[test]
+  fn builder_error_should_be_displayable() {
+    let error = sql::BuilderError::MissingClause("select");
+
+    assert_eq!(error.to_string(), "missing the required select clause");
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Select::new().as_string();
@@ -166,6 +360,450 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_as_string_with_should_use_the_formatter_passed_as_argument() {
+    let select = sql::Select::new().select("id").from("users").where_clause("login = $1");
+
+    let query_one_line = select.as_string_with(&sql::fmt::one_line());
+    let query_multiline = select.as_string_with(&sql::fmt::multiline());
+    let query_multiline_crlf = select.as_string_with(&sql::fmt::multiline_crlf());
+
+    assert_eq!(query_one_line, select.as_string());
+    assert_eq!(query_multiline, "SELECT id \nFROM users \nWHERE\n  login = $1");
+    assert_eq!(
+      query_multiline_crlf,
+      "SELECT id \r\nFROM users \r\nWHERE\r\n  login = $1"
+    );
+  }
+
+  #[test]
+  fn method_as_string_with_should_accept_the_multiline_formatter_with_a_custom_indent() {
+    let select = sql::Select::new().select("id").from("users").where_clause("login = $1");
+
+    let query_tab = select.as_string_with(&sql::fmt::multiline_with_indent("\t"));
+    let query_four_spaces = select.as_string_with(&sql::fmt::multiline_with_indent("    "));
+
+    assert_eq!(query_tab, "SELECT id \nFROM users \nWHERE\n\tlogin = $1");
+    assert_eq!(query_four_spaces, "SELECT id \nFROM users \nWHERE\n    login = $1");
+  }
+
+  #[test]
+  fn method_as_string_with_should_accept_the_pg_format_style_formatter() {
+    let select = sql::Select::new()
+      .select("id")
+      .select("login")
+      .from("users")
+      .where_clause("login = $1")
+      .where_clause("active = true");
+    let query = select.as_string_with(&sql::fmt::pg_format_style());
+    let expected_query = "SELECT id,\n    login \nFROM users \nWHERE\n    login = $1 \n    AND active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_render_the_keywords_in_lower_case() {
+    let select = sql::Select::new()
+      .raw("/* RAW SQL */")
+      .select("COUNT(id)")
+      .from("USERS u")
+      .inner_join("ORDERS o ON o.USER_ID = u.ID")
+      .where_clause("u.ACTIVE = true")
+      .where_or("u.ADMIN = true")
+      .group_by("u.LOGIN")
+      .having("COUNT(id) > 1")
+      .having("SUM(total) > 10")
+      .order_by("u.LOGIN DESC");
+    let query = select.as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "\
+      /* RAW SQL */ \
+      select COUNT(id) \
+      from USERS u \
+      inner join ORDERS o ON o.USER_ID = u.ID \
+      where u.ACTIVE = true or u.ADMIN = true \
+      group by u.LOGIN \
+      having COUNT(id) > 1 and SUM(total) > 10 \
+      order by u.LOGIN DESC\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_accept_the_keyword_case_of_the_formatter() {
+    let select = sql::Select::new().select("id").from("users").where_clause("id = 1");
+    let fmts_preserve = sql::fmt::one_line().with_keyword_case(sql::fmt::KeywordCase::Preserve);
+    let fmts_lower = sql::fmt::multiline().with_keyword_case(sql::fmt::KeywordCase::Lower);
+
+    assert_eq!(
+      select.as_string_with(&fmts_preserve),
+      "SELECT id FROM users WHERE id = 1"
+    );
+    assert_eq!(
+      select.as_string_with(&fmts_lower),
+      "select id \nfrom users \nwhere\n  id = 1"
+    );
+  }
+
+  #[test]
+  fn method_render_clause_should_render_only_the_where_clause() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("a = 1")
+      .where_clause("b = 2")
+      .order_by("id");
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::Where, &sql::fmt::one_line()),
+      "WHERE a = 1 AND b = 2"
+    );
+  }
+
+  #[test]
+  fn method_render_clause_should_render_only_the_order_by_clause() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("a = 1")
+      .order_by("created_at desc")
+      .order_by("id")
+      .raw("/* raw */")
+      .comment("traced");
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::OrderBy, &sql::fmt::one_line()),
+      "ORDER BY created_at desc, id"
+    );
+  }
+
+  #[test]
+  fn method_render_clause_should_include_the_raw_sql_bound_to_the_clause() {
+    let select = sql::Select::new()
+      .from("users")
+      .where_clause("a = 1")
+      .raw_before(sql::SelectClause::Where, "/* filter */")
+      .raw_after(sql::SelectClause::From, "u");
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::Where, &sql::fmt::one_line()),
+      "/* filter */ WHERE a = 1"
+    );
+  }
+
+  #[test]
+  fn method_render_clause_should_use_the_formatter_passed_as_argument() {
+    let select = sql::Select::new().from("users").where_clause("a = 1").where_or("b = 2");
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::Where, &sql::fmt::multiline()),
+      "WHERE\n  a = 1 \n  OR b = 2"
+    );
+  }
+
+  #[test]
+  fn method_render_clause_should_return_an_empty_string_when_the_clause_is_not_defined() {
+    let select = sql::Select::new().select("id").from("users");
+
+    assert_eq!(
+      select.render_clause(sql::SelectClause::Where, &sql::fmt::one_line()),
+      ""
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_keep_the_question_placeholders_by_default() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .where_clause("status = ?");
+
+    assert_eq!(
+      select.prepare(&sql::fmt::one_line()),
+      ("SELECT id FROM users WHERE login = ? AND status = ?".to_string(), 2)
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_number_the_placeholders_with_the_numbered_style() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .where_clause("status = ?");
+    let fmts = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+
+    assert_eq!(
+      select.prepare(&fmts),
+      ("SELECT id FROM users WHERE login = $1 AND status = $2".to_string(), 2)
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_name_the_placeholders_with_the_named_style() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .where_clause("status = ?");
+    let fmts = sql::fmt::multiline().with_placeholder_style(sql::fmt::PlaceholderStyle::Named);
+
+    assert_eq!(
+      select.prepare(&fmts),
+      (
+        "SELECT id \nFROM users \nWHERE\n  login = :p1 \n  AND status = :p2".to_string(),
+        2
+      )
+    );
+  }
+
+  #[test]
+  fn method_as_string_with_should_remove_the_redundant_whitespaces_with_the_minified_formatter() {
+    let query = sql::Select::new()
+      .select("id ,  login")
+      .select("count( orders.id )   AS total")
+      .from("users")
+      .where_clause("login   IN ( $1,  $2 )")
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "SELECT id,login,count(orders.id) AS total FROM users WHERE login IN ($1,$2)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_preserve_the_whitespaces_of_the_literals_with_the_minified_formatter() {
+    let query = sql::Select::new()
+      .select("'a  b' AS label, \"full  name\"")
+      .from("users")
+      .where_clause("note = ' , ( x ) '")
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "SELECT 'a  b' AS label,\"full  name\" FROM users WHERE note = ' , ( x ) '";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_preserve_the_escaped_quotes_with_the_minified_formatter() {
+    let query = sql::Select::new()
+      .select("id")
+      .where_clause("note = 'it''s  ok'  AND  active")
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "SELECT id WHERE note = 'it''s  ok' AND active";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_keep_the_line_break_of_a_line_comment_with_the_minified_formatter() {
+    let query = sql::Select::new()
+      .raw("-- list  users\n   SELECT   id")
+      .comment("keeps  /*  spaces")
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "/* keeps  / *  spaces */ -- list  users\nSELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_with_should_minify_the_nested_queries_with_the_minified_formatter() {
+    let orders = sql::Select::new()
+      .select("1")
+      .from("orders o")
+      .where_clause("o.user_id = u.id");
+    let query = sql::Select::new()
+      .select("u.id")
+      .from("users u")
+      .where_exists(orders)
+      .terminated()
+      .as_string_with(&sql::fmt::minified());
+    let expected_query = "SELECT u.id FROM users u WHERE EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id);";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_write_to_should_append_the_queries_into_the_same_buffer() {
+    let fmts = sql::fmt::one_line();
+    let mut script = String::from("BEGIN;");
+
+    sql::Select::new()
+      .select("id")
+      .from("users")
+      .terminated()
+      .write_to(&mut script, &fmts)
+      .unwrap();
+    sql::Select::new()
+      .select("id")
+      .from("orders")
+      .terminated()
+      .write_to(&mut script, &fmts)
+      .unwrap();
+
+    let expected_script = "BEGIN;SELECT id FROM users;SELECT id FROM orders;";
+
+    assert_eq!(script, expected_script);
+  }
+
+  #[test]
+  fn method_write_to_should_use_the_formatter_passed_as_argument() {
+    let select = sql::Select::new().select("id").from("users");
+    let fmts = sql::fmt::multiline();
+    let mut buffer = String::new();
+
+    select.write_to(&mut buffer, &fmts).unwrap();
+
+    assert_eq!(buffer, select.as_string_with(&fmts));
+  }
+
+  #[test]
+  fn method_write_to_should_not_write_the_trailing_whitespaces_of_the_last_clause() {
+    let select = sql::Select::new()
+      .raw("/* report */")
+      .select("id")
+      .from("users")
+      .raw_after(sql::SelectClause::From, "  ")
+      .where_clause("active = true")
+      .order_by("id")
+      .terminated();
+    let fmts = sql::fmt::multiline();
+    let mut buffer = String::new();
+
+    select.write_to(&mut buffer, &fmts).unwrap();
+
+    assert_eq!(buffer, select.as_string_with(&fmts));
+    assert!(buffer.ends_with("ORDER BY id;"));
+  }
+
+  #[test]
+  fn method_write_to_should_not_write_the_terminator_of_an_empty_query() {
+    let mut buffer = String::new();
+
+    sql::Select::new()
+      .terminated()
+      .write_to(&mut buffer, &sql::fmt::one_line())
+      .unwrap();
+
+    assert_eq!(buffer, "");
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  #[allow(deprecated)]
+  fn method_write_to_should_write_the_set_operations_as_the_as_string_method() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .union(sql::Select::new().select("id").from("admins"));
+    let fmts = sql::fmt::one_line();
+    let mut buffer = String::new();
+
+    select.write_to(&mut buffer, &fmts).unwrap();
+
+    assert_eq!(buffer, select.as_string_with(&fmts));
+  }
+
+  #[test]
+  fn display_trait_should_render_the_same_output_of_the_as_string_method() {
+    let select = sql::Select::new().select("id").from("users").terminated();
+
+    assert_eq!(format!("{select}"), select.as_string());
+  }
+
+  #[test]
+  fn method_prepare_should_not_rewrite_the_question_marks_inside_quotes() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("note = 'why?'")
+      .where_clause(r#""why?" = ?"#);
+    let fmts = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+
+    assert_eq!(
+      select.prepare(&fmts),
+      (
+        r#"SELECT id FROM users WHERE note = 'why?' AND "why?" = $1"#.to_string(),
+        1
+      )
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_not_rewrite_the_question_marks_inside_comments() {
+    let select = sql::Select::new()
+      .comment("why?")
+      .select("id")
+      .from("users")
+      .where_clause("login = ? /* or ? */")
+      .where_clause("age > ?");
+    let fmts = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+
+    assert_eq!(
+      select.prepare(&fmts),
+      (
+        "/* why? */ SELECT id FROM users WHERE login = $1 /* or ? */ AND age > $2".to_string(),
+        2
+      )
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_render_the_escaped_question_marks_as_the_jsonb_operators() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("settings ?? 'theme'")
+      .where_clause("tags ??| array['a', 'b']")
+      .where_clause("tags ??& array['c']")
+      .where_clause("login = ?");
+    let fmts = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+
+    assert_eq!(
+      select.prepare(&fmts),
+      (
+        "SELECT id FROM users WHERE settings ? 'theme' AND tags ?| array['a', 'b'] AND tags ?& array['c'] AND login = $1"
+          .to_string(),
+        1
+      )
+    );
+  }
+
+  #[test]
+  fn method_prepare_should_keep_the_escaped_question_marks_with_the_question_style() {
+    let select = sql::Select::new()
+      .where_clause("settings ?? 'theme'")
+      .where_clause("login = ?");
+
+    assert_eq!(
+      select.prepare(&sql::fmt::one_line()),
+      ("WHERE settings ?? 'theme' AND login = ?".to_string(), 1)
+    );
+  }
+
+  #[test]
+  fn method_as_string_with_should_rewrite_the_placeholders_with_the_placeholder_style_of_the_formatter() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .where_clause("settings ?? 'theme'");
+    let numbered = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Numbered);
+    let named = sql::fmt::one_line().with_placeholder_style(sql::fmt::PlaceholderStyle::Named);
+
+    assert_eq!(
+      select.as_string_with(&numbered),
+      "SELECT id FROM users WHERE login = $1 AND settings ? 'theme'"
+    );
+    assert_eq!(
+      select.as_string_with(&named),
+      "SELECT id FROM users WHERE login = :p1 AND settings ? 'theme'"
+    );
+    assert_eq!(
+      select.as_string(),
+      "SELECT id FROM users WHERE login = ? AND settings ?? 'theme'"
+    );
+  }
+
   #[test]
   fn method_debug_should_print_at_console_in_a_human_readable_format() {
     let query = sql::Select::new()
@@ -196,6 +834,18 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_pretty_should_not_change_the_output_of_as_string() {
+    let query = sql::Select::new()
+      .select("id, login, name, email, created_at")
+      .from("users")
+      .pretty(25)
+      .as_string();
+    let expected_query = "SELECT id, login, name, email, created_at FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_print_should_print_in_one_line_the_current_state_of_builder() {
     let query = sql::Select::new().select("1 + 2").print().as_string();
@@ -260,6 +910,103 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Select::new()
+      .raw_append("/* end */")
+      .select("id")
+      .from("users")
+      .as_string();
+    let expected_query = "SELECT id FROM users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "SELECT id FROM users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Select::new()
+      .raw("/* raw */")
+      .select("id")
+      .from("users")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_be_rendered_before_the_comment() {
+    let query = sql::Select::new()
+      .comment("traced")
+      .raw_prepend("/* first */")
+      .select("id")
+      .as_string();
+    let expected_query = "/* first */ /* traced */ SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_raw_prepend_should_be_rendered_before_the_with_clause() {
+    let query = sql::Select::new()
+      .with("active_users", sql::Select::new().select("id").from("users"))
+      .select("id")
+      .from("active_users")
+      .raw("/* raw */")
+      .raw_prepend("/* prepended */")
+      .as_string();
+    let expected_query = "\
+      /* prepended */ /* raw */ \
+      WITH active_users AS (SELECT id FROM users) \
+      SELECT id FROM active_users\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  #[allow(deprecated)]
+  fn method_raw_append_should_be_rendered_after_the_set_operations() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .union(sql::Select::new().select("id").from("admins"))
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "(SELECT id FROM users) UNION (SELECT id FROM admins) /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_be_rendered_before_the_terminator() {
+    let query = sql::Select::new()
+      .select("id")
+      .raw_append("FOR UPDATE")
+      .terminated()
+      .as_string();
+    let expected_query = "SELECT id FOR UPDATE;";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::Select::new()
@@ -279,4 +1026,429 @@ mod builder_methods {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_validate_should_return_no_warnings_for_a_valid_select() {
+    let select = sql::Select::new()
+      .select("DISTINCT status")
+      .from("orders")
+      .order_by("status");
+
+    assert!(select.validate().is_empty());
+  }
+
+  #[test]
+  fn method_validate_should_warn_when_distinct_and_group_by_are_defined() {
+    let select = sql::Select::new()
+      .select("distinct status")
+      .from("orders")
+      .group_by("status");
+    let expected_warnings = vec!["distinct is redundant with the group by clause"];
+
+    assert_eq!(select.validate(), expected_warnings);
+  }
+
+  #[test]
+  fn method_validate_should_warn_when_distinct_on_does_not_match_the_leftmost_order_by() {
+    let select = sql::Select::new()
+      .select("DISTINCT ON (customer_id) customer_id, total")
+      .from("orders")
+      .order_by("created_at desc, customer_id");
+    let expected_warnings = vec!["distinct on expressions must match the leftmost order by expressions"];
+
+    assert_eq!(select.validate(), expected_warnings);
+  }
+
+  #[test]
+  fn method_validate_should_accept_distinct_on_matching_the_leftmost_order_by_in_any_order() {
+    let select = sql::Select::new()
+      .select("DISTINCT ON (customer_id, store_id) customer_id, store_id, total")
+      .from("orders")
+      .order_by("store_id")
+      .order_by("customer_id asc, created_at desc");
+
+    assert!(select.validate().is_empty());
+  }
+
+  #[test]
+  fn method_has_clause_should_return_true_when_the_clause_is_defined() {
+    let select = sql::Select::new().select("id").from("users").where_clause("id = 1");
+
+    assert_eq!(select.has_clause(sql::SelectClause::Select), true);
+    assert_eq!(select.has_clause(sql::SelectClause::From), true);
+    assert_eq!(select.has_clause(sql::SelectClause::Where), true);
+  }
+
+  #[test]
+  fn method_has_clause_should_return_false_when_the_clause_is_not_defined() {
+    let select = sql::Select::new()
+      .select("id")
+      .raw_after(sql::SelectClause::Select, "from users");
+
+    assert_eq!(select.has_clause(sql::SelectClause::From), false);
+    assert_eq!(select.has_clause(sql::SelectClause::OrderBy), false);
+  }
+
+  #[test]
+  fn method_has_clause_should_ignore_empty_arguments() {
+    let select = sql::Select::new().from("").where_clause("");
+
+    assert_eq!(select.has_clause(sql::SelectClause::From), false);
+    assert_eq!(select.has_clause(sql::SelectClause::Where), false);
+  }
+
+  #[test]
+  fn method_clauses_should_return_the_defined_clauses_in_the_rendering_order() {
+    let select = sql::Select::new()
+      .order_by("login")
+      .where_clause("active = true")
+      .from("users")
+      .select("id, login")
+      .fetch("10");
+
+    let clauses = select.clauses();
+    let expected_clauses = vec![
+      sql::SelectClause::Select,
+      sql::SelectClause::From,
+      sql::SelectClause::Where,
+      sql::SelectClause::OrderBy,
+      sql::SelectClause::Fetch,
+    ];
+
+    assert!(clauses == expected_clauses);
+  }
+
+  #[test]
+  fn method_clauses_should_return_an_empty_list_when_the_builder_is_empty() {
+    let select = sql::Select::new().raw("select 1");
+
+    assert_eq!(select.clauses().is_empty(), true);
+  }
+
+  #[test]
+  fn method_validate_should_not_affect_the_rendered_query() {
+    let select = sql::Select::new().select("DISTINCT status").group_by("status");
+    select.validate();
+    let expected_query = "SELECT DISTINCT status GROUP BY status";
+
+    assert_eq!(select.as_string(), expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_bind {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_into_parts_should_number_the_placeholders_in_order() {
+    let (query, params) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("login = ?")
+      .bind("foo")
+      .where_clause("age > ?")
+      .bind(18)
+      .into_parts();
+
+    assert_eq!(query, "SELECT * FROM users WHERE login = $1 AND age > $2");
+    assert_eq!(
+      params,
+      vec![sql::ParamValue::Text("foo".to_string()), sql::ParamValue::Int(18)]
+    );
+  }
+
+  #[test]
+  fn method_into_parts_should_preserve_the_placeholders_inside_quotes() {
+    let (query, _) = sql::Select::new()
+      .select(r#"'?' as "what?""#)
+      .where_clause("data ->> 'key' = ?")
+      .bind("value")
+      .into_parts();
+
+    assert_eq!(query, r#"SELECT '?' as "what?" WHERE data ->> 'key' = $1"#);
+  }
+
+  #[test]
+  fn method_into_parts_should_render_the_escaped_question_marks_as_the_jsonb_operators() {
+    let (query, params) = sql::Select::new()
+      .where_clause("data ?? 'key'")
+      .where_clause("data ??| array['a', 'b']")
+      .where_clause("data ??& array['c']")
+      .where_clause("id = ?")
+      .bind(1)
+      .into_parts();
+
+    assert_eq!(
+      query,
+      "WHERE data ? 'key' AND data ?| array['a', 'b'] AND data ?& array['c'] AND id = $1"
+    );
+    assert_eq!(params, vec![sql::ParamValue::Int(1)]);
+  }
+
+  #[test]
+  fn method_into_parts_should_continue_the_numbering_of_the_with_queries() {
+    let active_users = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = ?")
+      .bind(true);
+    let (query, params) = sql::Select::new()
+      .with("active_users", active_users)
+      .select("*")
+      .from("active_users")
+      .where_clause("id = ?")
+      .bind(10)
+      .into_parts();
+    let expected_query = "\
+      WITH active_users AS (SELECT id FROM users WHERE active = $1) \
+      SELECT * FROM active_users WHERE id = $2\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::ParamValue::Bool(true), sql::ParamValue::Int(10)]);
+  }
+
+  #[test]
+  #[allow(deprecated)]
+  fn method_into_parts_should_continue_the_numbering_of_the_set_operations() {
+    let admins = sql::Select::new()
+      .select("login")
+      .from("admins")
+      .where_clause("level > ?")
+      .bind(2.5);
+    let (query, params) = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("name = ?")
+      .bind(None::<String>)
+      .union(admins)
+      .into_parts();
+    let expected_query = "\
+      (SELECT login FROM users WHERE name = $1) \
+      UNION \
+      (SELECT login FROM admins WHERE level > $2)\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::ParamValue::Null, sql::ParamValue::Float(2.5)]);
+  }
+
+  #[test]
+  fn method_into_parts_should_order_the_params_of_the_where_subqueries_and_the_raw_sql() {
+    let orders = sql::Select::new()
+      .select("1")
+      .from("orders")
+      .where_clause("orders.total > ?")
+      .bind(100);
+    let (query, params) = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("status = ?")
+      .bind("active")
+      .where_exists(orders)
+      .where_clause("age > ?")
+      .bind(18)
+      .raw_after(sql::SelectClause::Where, "AND country = ?")
+      .bind("BR")
+      .order_by("id")
+      .raw_after(sql::SelectClause::OrderBy, "LIMIT ?")
+      .bind(10)
+      .into_parts();
+    let expected_query = "\
+      SELECT id FROM users \
+      WHERE status = $1 AND EXISTS (SELECT 1 FROM orders WHERE orders.total > $2) AND age > $3 AND country = $4 \
+      ORDER BY id LIMIT $5\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      params,
+      vec![
+        sql::ParamValue::Text("active".to_string()),
+        sql::ParamValue::Int(100),
+        sql::ParamValue::Int(18),
+        sql::ParamValue::Text("BR".to_string()),
+        sql::ParamValue::Int(10),
+      ]
+    );
+  }
+
+  #[test]
+  fn method_into_parts_should_order_the_params_as_the_placeholders_are_rendered() {
+    let orders = sql::Select::new()
+      .select("user_id")
+      .from("orders")
+      .where_clause("total = ?")
+      .bind(2);
+    let (query, params) = sql::Select::new()
+      .select("?::int AS x")
+      .bind(1)
+      .from_subquery(orders, "o")
+      .where_clause("o.user_id = ?")
+      .bind(3)
+      .into_parts();
+    let expected_query = "\
+      SELECT $1::int AS x \
+      FROM (SELECT user_id FROM orders WHERE total = $2) AS o \
+      WHERE o.user_id = $3\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      params,
+      vec![
+        sql::ParamValue::Int(1),
+        sql::ParamValue::Int(2),
+        sql::ParamValue::Int(3)
+      ]
+    );
+  }
+
+  #[test]
+  fn method_into_parts_should_include_the_params_of_the_from_item_subquery() {
+    let users = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("age > ?")
+      .bind(7);
+    let (query, params) = sql::Select::new()
+      .select("u.id")
+      .from_item(sql::FromItem::subquery(users).alias("u"))
+      .inner_join("orders o ON u.id = o.user_id")
+      .where_clause("o.total = ?")
+      .bind(2)
+      .into_parts();
+    let expected_query = "\
+      SELECT u.id \
+      FROM (SELECT id FROM users WHERE age > $1) AS u \
+      INNER JOIN orders o ON u.id = o.user_id \
+      WHERE o.total = $2\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::ParamValue::Int(7), sql::ParamValue::Int(2)]);
+  }
+
+  #[test]
+  fn method_into_parts_should_not_number_the_question_marks_inside_comments() {
+    let (query, params) = sql::Select::new()
+      .comment("why?")
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .bind("foo")
+      .into_parts();
+
+    assert_eq!(query, "/* why? */ SELECT id FROM users WHERE login = $1");
+    assert_eq!(params, vec![sql::ParamValue::Text("foo".to_string())]);
+  }
+
+  #[test]
+  fn method_bind_should_not_change_the_output_of_as_string() {
+    let query = sql::Select::new().where_clause("login = ?").bind("foo").as_string();
+    let expected_query = "WHERE login = ?";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "serde")]
+mod serde_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn round_trip(select: &sql::Select) -> sql::Select {
+    let json = serde_json::to_string(select).unwrap();
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn select_builder_should_render_the_same_query_after_a_round_trip() {
+    let select = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .inner_join("orders ON orders.owner_id = users.id")
+      .where_clause("login = 'foo'")
+      .where_or("login = 'bar'")
+      .group_by("id")
+      .order_by("login")
+      .raw_before(sql::SelectClause::Where, "/* where */");
+
+    let query = round_trip(&select).as_string();
+    let expected_query = select.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn select_builder_should_keep_building_after_a_round_trip() {
+    let select = sql::Select::new().select("id").from("users");
+
+    let query = round_trip(&select).where_clause("id = 1").as_string();
+    let expected_query = "SELECT id FROM users WHERE id = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn select_builder_should_render_the_with_clause_after_a_round_trip() {
+    let select = sql::Select::new()
+      .with("active_users", sql::Select::new().select("id").from("users"))
+      .select("*")
+      .from("active_users")
+      .limit("10");
+
+    let query = round_trip(&select).as_string();
+    let expected_query = "\
+      WITH active_users AS (SELECT id FROM users) \
+      SELECT * \
+      FROM active_users \
+      LIMIT 10\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn select_builder_should_render_the_raw_queries_of_the_with_clause_after_a_round_trip() {
+    let select = sql::Select::new()
+      .with_raw("active_users", "select id from users where active = true")
+      .select("*")
+      .from("active_users");
+
+    let query = round_trip(&select).as_string();
+    let expected_query = "\
+      WITH active_users AS (select id from users where active = true) \
+      SELECT * \
+      FROM active_users\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn select_builder_should_keep_the_bound_values_after_a_round_trip() {
+    let select = sql::Select::new()
+      .with(
+        "admins",
+        sql::Select::new()
+          .select("id")
+          .from("users")
+          .where_clause("role = ?")
+          .bind("admin"),
+      )
+      .select("*")
+      .from("admins")
+      .where_clause("id = ?")
+      .bind(1);
+
+    let parts = round_trip(&select).into_parts();
+    let expected_parts = select.into_parts();
+
+    assert_eq!(parts, expected_parts);
+  }
 }
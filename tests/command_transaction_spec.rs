@@ -153,6 +153,31 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_add_should_render_the_commands_in_the_order_they_were_added() {
+    let query = sql::Transaction::new()
+      .add(sql::Update::new().update("users").set("active = false"))
+      .add(sql::Delete::new().delete_from("sessions"))
+      .add(sql::Select::new().select("count(*)").from("users"))
+      .as_string();
+    let expected_query = "\
+      UPDATE users SET active = false; \
+      DELETE FROM sessions; \
+      SELECT count(*) FROM users;\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_add_should_produce_the_same_output_of_the_command_methods() {
+    let insert = sql::Insert::new().insert_into("users (login)").values("('foo')");
+    let query = sql::Transaction::new().add(insert.clone()).as_string();
+    let expected_query = sql::Transaction::new().insert(insert).as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Transaction::new().as_string();
@@ -309,6 +334,39 @@ mod builder_methods {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Transaction::new().raw_append("/* end */").commit("").as_string();
+    let expected_query = "COMMIT; /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Transaction::new()
+      .commit("")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "COMMIT; /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Transaction::new()
+      .raw("/* raw */")
+      .commit("")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 mod alter_table_method {
@@ -0,0 +1,360 @@
+#[cfg(feature = "postgresql")]
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn truncate_builder_should_be_displayable() {
+    let truncate = sql::Truncate::new().truncate("users");
+
+    println!("{}", truncate);
+
+    let query = truncate.as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn truncate_builder_should_be_debuggable() {
+    let truncate = sql::Truncate::new().truncate("users").cascade();
+
+    println!("{:?}", truncate);
+
+    let expected_query = "TRUNCATE TABLE users CASCADE";
+    let query = truncate.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn truncate_builder_should_be_cloneable() {
+    let truncate_users = sql::Truncate::new().truncate("users");
+    let truncate_users_and_orders = truncate_users.clone().truncate("orders");
+
+    let expected_truncate_users = "TRUNCATE TABLE users";
+    assert_eq!(expected_truncate_users, truncate_users.as_string());
+
+    let expected_truncate_users_and_orders = "TRUNCATE TABLE users, orders";
+    assert_eq!(
+      expected_truncate_users_and_orders,
+      truncate_users_and_orders.as_string()
+    );
+  }
+
+  #[test]
+  fn truncate_builder_should_be_composable() {
+    fn add_comment(truncate: sql::Truncate) -> sql::Truncate {
+      truncate.raw("/* truncate command */")
+    }
+
+    fn truncate_users(truncate: sql::Truncate) -> sql::Truncate {
+      truncate.truncate("users")
+    }
+
+    fn as_string(truncate: sql::Truncate) -> String {
+      truncate.as_string()
+    }
+
+    let query = Some(sql::Truncate::new())
+      .map(add_comment)
+      .map(truncate_users)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* truncate command */ TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn truncate_builder_should_be_used_in_a_transaction() {
+    let truncate = sql::Truncate::new().truncate("users").restart_identity();
+    let query = sql::Transaction::new().truncate(truncate).as_string();
+    let expected_query = "TRUNCATE TABLE users RESTART IDENTITY;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn truncate_builder_should_use_the_keyword_case_of_the_formatter() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .restart_identity()
+      .cascade()
+      .as_string_with(&sql::fmt::one_line_lowercase());
+    let expected_query = "truncate table users restart identity cascade";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Truncate::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Truncate::new().truncate("users").debug().as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Truncate::new().truncate("users").print().as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Truncate::new().raw("truncate users").as_string();
+    let expected_query = "truncate users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .raw("/* truncate command */")
+      .as_string();
+    let expected_query = "/* truncate command */ TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Truncate::new()
+      .raw("truncate users")
+      .raw("truncate users")
+      .as_string();
+    let expected_query = "truncate users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Truncate::new()
+      .raw_append("/* end */")
+      .truncate("users")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Truncate::new()
+      .raw("/* raw */")
+      .truncate("users")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .raw_after(sql::TruncateParams::Truncate, "  /* end */  ")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users /* end */";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new()
+      .raw_before(sql::TruncateParams::Truncate, "  /* start */  ")
+      .truncate("users")
+      .as_string();
+    let expected_query = "/* start */ TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod method_truncate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_truncate_should_add_the_truncate_signature() {
+    let query = sql::Truncate::new().truncate("users").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_truncate_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .truncate("orders")
+      .truncate("addresses")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users, orders, addresses";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_truncate_should_not_accumulate_values_when_table_name_is_empty() {
+    let query = sql::Truncate::new()
+      .truncate("")
+      .truncate("users")
+      .truncate("  ")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_truncate_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new().truncate("  users  ").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_truncate_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Truncate::new().truncate("users").truncate("users").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_truncate() {
+    let query = sql::Truncate::new()
+      .raw_before(sql::TruncateParams::Truncate, "/* truncate users */")
+      .truncate("users")
+      .as_string();
+    let expected_query = "/* truncate users */ TRUNCATE TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_truncate() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .cascade()
+      .raw_after(sql::TruncateParams::Truncate, "/* end truncate */")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users CASCADE /* end truncate */";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod truncate_options {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_restart_identity_should_add_the_restart_identity_option() {
+    let query = sql::Truncate::new().truncate("users").restart_identity().as_string();
+    let expected_query = "TRUNCATE TABLE users RESTART IDENTITY";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_continue_identity_should_override_the_restart_identity_option() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .restart_identity()
+      .continue_identity()
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users CONTINUE IDENTITY";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_restart_identity_should_override_the_continue_identity_option() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .continue_identity()
+      .restart_identity()
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users RESTART IDENTITY";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_restrict_should_override_the_cascade_option() {
+    let query = sql::Truncate::new().truncate("users").cascade().restrict().as_string();
+    let expected_query = "TRUNCATE TABLE users RESTRICT";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_cascade_should_override_the_restrict_option() {
+    let query = sql::Truncate::new().truncate("users").restrict().cascade().as_string();
+    let expected_query = "TRUNCATE TABLE users CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn options_should_be_rendered_after_the_table_list_in_the_sql_order() {
+    let query = sql::Truncate::new()
+      .cascade()
+      .restart_identity()
+      .truncate("users")
+      .truncate("orders")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users, orders RESTART IDENTITY CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn options_should_not_be_rendered_without_tables() {
+    let query = sql::Truncate::new().restart_identity().cascade().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+}
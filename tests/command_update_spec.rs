@@ -29,6 +29,52 @@ mod builder_features {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn update_builder_should_be_equal_to_a_builder_that_renders_the_same_sql() {
+    let update_a = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause("login = 'foo'");
+    let update_b = sql::Update::new()
+      .where_clause("login = 'foo'")
+      .set("active = false")
+      .update("users");
+    let update_c = sql::Update::new().update("users").set("active = true");
+
+    assert_eq!(update_a, update_b);
+    assert_ne!(update_a, update_c);
+  }
+
+  #[test]
+  fn update_builder_should_be_usable_as_a_hash_map_key() {
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(
+      sql::Update::new()
+        .update("users")
+        .set("active = false")
+        .where_clause("login = 'foo'"),
+      "plan_a",
+    );
+    cache.insert(
+      sql::Update::new()
+        .where_clause("login = 'foo'")
+        .set("active = false")
+        .update("users"),
+      "plan_b",
+    );
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+      cache.get(
+        &sql::Update::new()
+          .where_clause("login = 'foo'")
+          .set("active = false")
+          .update("users")
+      ),
+      Some(&"plan_b")
+    );
+  }
+
   #[test]
   fn update_builder_should_be_cloneable() {
     let update_foo = sql::Update::new()
@@ -139,6 +185,31 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_clear_should_remove_the_where_clause_entirely() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause("login = 'foo'")
+      .clear(sql::UpdateClause::Where)
+      .as_string();
+    let expected_query = "UPDATE users SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_terminated_should_append_a_semicolon_to_the_query() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("name = 'Bar'")
+      .terminated()
+      .as_string();
+    let expected_query = "UPDATE users SET name = 'Bar';";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_as_string_should_convert_the_current_state_into_string() {
     let query = sql::Update::new().as_string();
@@ -155,6 +226,37 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_the_required_clauses_are_defined() {
+    let update = sql::Update::new().update("users").set("login = 'foo'");
+    let expected_query = Ok("UPDATE users SET login = 'foo'".to_string());
+
+    assert_eq!(update.try_as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_update_clause_is_missing() {
+    let update = sql::Update::new().set("login = 'foo'");
+    let expected_error = Err(sql::BuilderError::MissingClause("update"));
+
+    assert_eq!(update.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_set_clause_is_missing() {
+    let update = sql::Update::new().update("users").where_clause("id = $1");
+    let expected_error = Err(sql::BuilderError::MissingClause("set"));
+
+    assert_eq!(update.try_as_string(), expected_error);
+  }
+
+  #[test]
+  fn method_try_as_string_should_not_verify_builders_with_raw_sql() {
+    let update = sql::Update::new().raw("update users set login = 'foo'");
+
+    assert!(update.try_as_string().is_ok());
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Update::new().as_string();
@@ -225,6 +327,69 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Update::new()
+      .raw_append("/* end */")
+      .update("users")
+      .set("active = false")
+      .as_string();
+    let expected_query = "UPDATE users SET active = false /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "UPDATE users SET active = false /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Update::new()
+      .raw("/* raw */")
+      .update("users")
+      .set("active = false")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ UPDATE users SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn method_raw_prepend_and_raw_append_should_wrap_the_with_and_returning_clauses() {
+    let query = sql::Update::new()
+      .raw_append("/* end */")
+      .with("banned", sql::Select::new().select("id").from("bans"))
+      .update("users")
+      .set("active = false")
+      .where_clause("id in (select id from banned)")
+      .returning("id")
+      .raw_prepend("/* start */")
+      .explain()
+      .as_string();
+    let expected_query = "\
+      /* start */ EXPLAIN \
+      WITH banned AS (SELECT id FROM bans) \
+      UPDATE users SET active = false \
+      WHERE id in (select id from banned) \
+      RETURNING id /* end */\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::Update::new()
@@ -245,3 +410,98 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 }
+
+#[cfg(feature = "postgresql")]
+mod method_bind {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_into_parts_should_number_the_placeholders_in_order() {
+    let (query, params) = sql::Update::new()
+      .update("users")
+      .set("name = ?")
+      .bind("Foo")
+      .where_clause("login = ?")
+      .bind("foo")
+      .into_parts();
+
+    assert_eq!(query, "UPDATE users SET name = $1 WHERE login = $2");
+    assert_eq!(
+      params,
+      vec![
+        sql::ParamValue::Text("Foo".to_string()),
+        sql::ParamValue::Text("foo".to_string())
+      ]
+    );
+  }
+
+  #[test]
+  fn method_into_parts_should_continue_the_numbering_of_the_with_queries() {
+    let admins = sql::Select::new()
+      .select("id")
+      .from("admins")
+      .where_clause("level = ?")
+      .bind(3);
+    let (query, params) = sql::Update::new()
+      .with("admin_ids", admins)
+      .update("users")
+      .set("role = ?")
+      .bind("admin")
+      .where_clause("id in (select id from admin_ids)")
+      .into_parts();
+    let expected_query = "\
+      WITH admin_ids AS (SELECT id FROM admins WHERE level = $1) \
+      UPDATE users SET role = $2 \
+      WHERE id in (select id from admin_ids)\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      params,
+      vec![sql::ParamValue::Int(3), sql::ParamValue::Text("admin".to_string())]
+    );
+  }
+}
+
+#[cfg(feature = "serde")]
+mod serde_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn round_trip(update: &sql::Update) -> sql::Update {
+    let json = serde_json::to_string(update).unwrap();
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn update_builder_should_render_the_same_query_after_a_round_trip() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("name = 'Foo'")
+      .where_clause("login = 'foo'")
+      .raw_after(sql::UpdateClause::Set, "/* set */");
+
+    let query = round_trip(&update).as_string();
+    let expected_query = update.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  #[test]
+  fn update_builder_should_render_the_with_clause_after_a_round_trip() {
+    let update = sql::Update::new()
+      .with("admins", sql::Select::new().select("id").from("users"))
+      .update("orders")
+      .set("priority = 1")
+      .from("admins")
+      .where_clause("orders.owner_id = admins.id")
+      .returning("id");
+
+    let query = round_trip(&update).as_string();
+    let expected_query = update.as_string();
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -102,6 +102,14 @@ mod builder_methods {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[test]
+  fn method_terminated_should_append_a_semicolon_to_the_query() {
+    let query = sql::Values::new().values("('foo')").terminated().as_string();
+    let expected_query = "VALUES ('foo');";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_new_should_initialize_as_empty_string() {
     let query = sql::Values::new().as_string();
@@ -199,6 +207,42 @@ mod builder_methods {
     assert_eq!(query, expected_query);
   }
 
+  #[test]
+  fn method_raw_append_should_add_raw_sql_at_the_end_of_the_query() {
+    let query = sql::Values::new()
+      .raw_append("/* end */")
+      .values("(1, 'one')")
+      .as_string();
+    let expected_query = "VALUES (1, 'one') /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_append_should_not_accumulate_empty_or_duplicated_values() {
+    let query = sql::Values::new()
+      .values("(1, 'one')")
+      .raw_append("  ")
+      .raw_append("  /* end */  ")
+      .raw_append("/* end */")
+      .as_string();
+    let expected_query = "VALUES (1, 'one') /* end */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_prepend_should_add_raw_sql_before_the_raw_method() {
+    let query = sql::Values::new()
+      .raw("/* raw */")
+      .values("(1, 'one')")
+      .raw_prepend("/* start */")
+      .as_string();
+    let expected_query = "/* start */ /* raw */ VALUES (1, 'one')";
+
+    assert_eq!(query, expected_query);
+  }
+
   #[test]
   fn method_raw_after_should_trim_space_of_the_argument() {
     let query = sql::Values::new()
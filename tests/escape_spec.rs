@@ -0,0 +1,95 @@
+mod quote_literal {
+  use pretty_assertions::assert_eq;
+  use sql::escape::quote_literal;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn quote_literal_should_wrap_the_value_in_single_quotes() {
+    assert_eq!(quote_literal("foo"), "'foo'");
+  }
+
+  #[test]
+  fn quote_literal_should_wrap_an_empty_value() {
+    assert_eq!(quote_literal(""), "''");
+  }
+
+  #[test]
+  fn quote_literal_should_double_the_single_quotes() {
+    assert_eq!(quote_literal("o'reilly"), "'o''reilly'");
+    assert_eq!(quote_literal("'; drop table users; --"), "'''; drop table users; --'");
+  }
+
+  #[test]
+  fn quote_literal_should_keep_the_double_quotes() {
+    assert_eq!(quote_literal(r#"say "hi""#), r#"'say "hi"'"#);
+  }
+
+  #[test]
+  fn quote_literal_should_double_the_backslashes_and_use_the_escape_string_syntax() {
+    assert_eq!(quote_literal(r"C:\tmp"), r"E'C:\\tmp'");
+    assert_eq!(quote_literal(r"\'"), r"E'\\'''");
+  }
+
+  #[test]
+  fn quote_literal_should_remove_the_nul_characters() {
+    assert_eq!(quote_literal("foo\0bar"), "'foobar'");
+  }
+
+  #[test]
+  fn quote_literal_should_keep_the_unicode_characters() {
+    assert_eq!(quote_literal("Ünïcødé ✓ l'été"), "'Ünïcødé ✓ l''été'");
+  }
+
+  #[test]
+  fn quote_literal_should_be_usable_as_argument_of_the_builders() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values(&format!("({})", quote_literal("o'reilly")))
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('o''reilly')";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod quote_ident {
+  use pretty_assertions::assert_eq;
+  use sql::escape::quote_ident;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn quote_ident_should_wrap_the_value_in_double_quotes() {
+    assert_eq!(quote_ident("users"), r#""users""#);
+  }
+
+  #[test]
+  fn quote_ident_should_double_the_double_quotes() {
+    assert_eq!(quote_ident(r#"my "table""#), r#""my ""table""""#);
+  }
+
+  #[test]
+  fn quote_ident_should_keep_the_single_quotes_and_backslashes() {
+    assert_eq!(quote_ident(r"it's\here"), r#""it's\here""#);
+  }
+
+  #[test]
+  fn quote_ident_should_remove_the_nul_characters() {
+    assert_eq!(quote_ident("us\0ers"), r#""users""#);
+  }
+
+  #[test]
+  fn quote_ident_should_keep_the_unicode_characters() {
+    assert_eq!(quote_ident("usuários"), r#""usuários""#);
+  }
+
+  #[test]
+  fn quote_ident_should_be_usable_as_argument_of_the_builders() {
+    let query = sql::Select::new()
+      .select(&quote_ident("order"))
+      .from(&quote_ident("Users"))
+      .as_string();
+    let expected_query = r#"SELECT "order" FROM "Users""#;
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,106 @@
+mod wrap {
+  use pretty_assertions::assert_eq;
+  use sql::fmt::wrap;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn wrap_should_keep_the_lines_that_fit_the_width() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT id, login\nFROM users";
+
+    assert_eq!(wrap(query, 16, &fmts), query);
+  }
+
+  #[test]
+  fn wrap_should_break_the_long_line_after_the_commas_and_indent_the_continuation_lines() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT id, login, name, email, created_at, updated_at\nFROM users";
+    let expected_query = "\
+      SELECT id, login, name,\n  \
+      email, created_at,\n  \
+      updated_at\n\
+      FROM users\
+    ";
+
+    assert_eq!(wrap(query, 25, &fmts), expected_query);
+  }
+
+  #[test]
+  fn wrap_should_not_break_the_line_at_the_commas_inside_parentheses_and_quotes() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT coalesce(name, login), 'a, b', id";
+    let expected_query = "\
+      SELECT coalesce(name, login),\n  \
+      'a, b', id\
+    ";
+
+    assert_eq!(wrap(query, 30, &fmts), expected_query);
+  }
+
+  #[test]
+  fn wrap_should_keep_the_item_longer_than_the_width_whole_in_its_own_line() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT id, concat_ws(' ', first_name, middle_name, last_name), login";
+    let expected_query = "\
+      SELECT id,\n  \
+      concat_ws(' ', first_name, middle_name, last_name),\n  \
+      login\
+    ";
+
+    assert_eq!(wrap(query, 20, &fmts), expected_query);
+  }
+
+  #[test]
+  fn wrap_should_keep_the_line_without_commas_when_it_is_longer_than_the_width() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT concat_ws(' ', first_name, last_name)";
+
+    assert_eq!(wrap(query, 10, &fmts), query);
+  }
+
+  #[test]
+  fn wrap_should_place_each_item_in_its_own_line_when_the_width_is_zero() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT id, login, name\nFROM users";
+    let expected_query = "\
+      SELECT id,\n  \
+      login,\n  \
+      name\n\
+      FROM users\
+    ";
+
+    assert_eq!(wrap(query, 0, &fmts), expected_query);
+  }
+
+  #[test]
+  fn wrap_should_place_each_item_in_its_own_line_when_the_width_is_one() {
+    let fmts = sql::fmt::multiline();
+    let query = "SELECT a, b\nFROM t";
+    let expected_query = "\
+      SELECT a,\n  \
+      b\n\
+      FROM t\
+    ";
+
+    assert_eq!(wrap(query, 1, &fmts), expected_query);
+  }
+
+  #[test]
+  fn wrap_should_keep_the_empty_query() {
+    let fmts = sql::fmt::multiline();
+
+    assert_eq!(wrap("", 0, &fmts), "");
+  }
+
+  #[test]
+  fn wrap_should_use_the_line_break_and_the_indent_of_the_formatter() {
+    let fmts = sql::fmt::multiline_with_indent("    ");
+    let query = "SELECT id, login, name";
+    let expected_query = "\
+      SELECT id, login,\n    \
+      name\
+    ";
+
+    assert_eq!(wrap(query, 17, &fmts), expected_query);
+  }
+}